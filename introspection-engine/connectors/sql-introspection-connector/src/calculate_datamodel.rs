@@ -12,12 +12,13 @@ use quaint::connector::SqlFamily;
 use sql_schema_describer::*;
 use tracing::debug;
 
-/// Calculate a data model from a database schema.
+/// Calculate a data model from a database schema. The migrations table is left out.
 pub fn calculate_datamodel(
     schema: &SqlSchema,
     family: &SqlFamily,
     previous_data_model: &Datamodel,
     native_types: bool,
+    migrations_table_name: &str,
 ) -> SqlIntrospectionResult<IntrospectionResult> {
     debug!("Calculating data model.");
 
@@ -25,7 +26,14 @@ pub fn calculate_datamodel(
     let mut data_model = Datamodel::new();
 
     // 1to1 translation of the sql schema
-    introspect(schema, &mut version_check, &mut data_model, *family, native_types)?;
+    introspect(
+        schema,
+        &mut version_check,
+        &mut data_model,
+        *family,
+        native_types,
+        migrations_table_name,
+    )?;
 
     // our opinionation about valid names
     sanitize_datamodel_names(&mut data_model, family);
@@ -58,6 +66,7 @@ pub fn calculate_datamodel(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::DEFAULT_MIGRATIONS_TABLE_NAME;
     use datamodel::{
         dml, Datamodel, DefaultValue as DMLDefault, Field, FieldArity, FieldType, IndexDefinition, Model,
         OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
@@ -148,17 +157,39 @@ mod tests {
                         ),
                         default: None,
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     })
                     .collect(),
                 indices: vec![],
                 primary_key: None,
                 foreign_keys: vec![],
+                ddl: None,
+                collation: None,
+                check_constraints: Vec::new(),
+                policies: Vec::new(),
+                partitioning: None,
+                row_level_security: false,
+                without_rowid: false,
             }],
             enums: vec![],
             sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: None,
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(
+            &schema,
+            &SqlFamily::Postgres,
+            &Datamodel::new(),
+            false,
+            DEFAULT_MIGRATIONS_TABLE_NAME,
+        )
+        .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -213,18 +244,39 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "required".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: true,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "list".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List),
                         default: None,
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                 ],
                 indices: vec![],
@@ -234,12 +286,27 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                ddl: None,
+                collation: None,
+                check_constraints: Vec::new(),
+                policies: Vec::new(),
+                partitioning: None,
+                row_level_security: false,
+                without_rowid: false,
             }],
             enums: vec![],
             sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: None,
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(
+            &schema,
+            &SqlFamily::Postgres,
+            &Datamodel::new(),
+            false,
+            DEFAULT_MIGRATIONS_TABLE_NAME,
+        )
+        .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -332,45 +399,102 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "int_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: Some(DefaultValue::VALUE(PrismaValue::Int(1))),
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "bool_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Boolean, ColumnArity::Nullable),
                         default: Some(DefaultValue::VALUE(PrismaValue::Boolean(true))),
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "float_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Nullable),
                         default: Some(DefaultValue::VALUE(PrismaValue::new_float(1.0))),
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "string_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Nullable),
                         default: Some(DefaultValue::VALUE(PrismaValue::String("default".to_string()))),
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "unique".to_string(),
                     columns: vec!["no_default".into(), "int_default".into()],
                     tpe: IndexType::Unique,
+                    storage_parameters: Vec::new(),
+                    expressions: Vec::new(),
+                    algorithm: None,
+                    invisible: false,
+                    comment: None,
+                    nulls_not_distinct: false,
+                    opclasses: Vec::new(),
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                ddl: None,
+                collation: None,
+                check_constraints: Vec::new(),
+                policies: Vec::new(),
+                partitioning: None,
+                row_level_security: false,
+                without_rowid: false,
             }],
             enums: vec![],
             sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: None,
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(
+            &schema,
+            &SqlFamily::Postgres,
+            &Datamodel::new(),
+            false,
+            DEFAULT_MIGRATIONS_TABLE_NAME,
+        )
+        .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -471,6 +595,13 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -479,6 +610,13 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    ddl: None,
+                    collation: None,
+                    check_constraints: Vec::new(),
+                    policies: Vec::new(),
+                    partitioning: None,
+                    row_level_security: false,
+                    without_rowid: false,
                 },
                 Table {
                     name: "Table2".to_string(),
@@ -494,6 +632,13 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -502,6 +647,13 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    ddl: None,
+                    collation: None,
+                    check_constraints: Vec::new(),
+                    policies: Vec::new(),
+                    partitioning: None,
+                    row_level_security: false,
+                    without_rowid: false,
                 },
                 Table {
                     name: "Table3".to_string(),
@@ -518,6 +670,13 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -526,17 +685,33 @@ mod tests {
                             name: "sequence".to_string(),
                             initial_value: 1,
                             allocation_size: 1,
+                            owned: true,
                         }),
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    ddl: None,
+                    collation: None,
+                    check_constraints: Vec::new(),
+                    policies: Vec::new(),
+                    partitioning: None,
+                    row_level_security: false,
+                    without_rowid: false,
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: None,
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(
+            &schema,
+            &SqlFamily::Postgres,
+            &Datamodel::new(),
+            false,
+            DEFAULT_MIGRATIONS_TABLE_NAME,
+        )
+        .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -586,27 +761,63 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "unique".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "unique".to_string(),
                     columns: vec!["unique".to_string()],
                     tpe: IndexType::Unique,
+                    storage_parameters: Vec::new(),
+                    expressions: Vec::new(),
+                    algorithm: None,
+                    invisible: false,
+                    comment: None,
+                    nulls_not_distinct: false,
+                    opclasses: Vec::new(),
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                ddl: None,
+                collation: None,
+                check_constraints: Vec::new(),
+                policies: Vec::new(),
+                partitioning: None,
+                row_level_security: false,
+                without_rowid: false,
             }],
             enums: vec![],
             sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: None,
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(
+            &schema,
+            &SqlFamily::Postgres,
+            &Datamodel::new(),
+            false,
+            DEFAULT_MIGRATIONS_TABLE_NAME,
+        )
+        .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -649,6 +860,7 @@ mod tests {
                                 references: vec![],
                                 name: "CityToUser".to_string(),
                                 on_delete: OnDeleteStrategy::None,
+                                foreign_key_name: None,
                             },
                         )),
                     ],
@@ -711,6 +923,7 @@ mod tests {
                                 fields: vec!["city_id".to_string(), "city_name".to_string()],
                                 references: vec!["id".to_string(), "name".to_string()],
                                 on_delete: OnDeleteStrategy::None,
+                                foreign_key_name: None,
                             },
                         )),
                     ],
@@ -740,6 +953,13 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            identity: None,
+                            identity_options: None,
+                            invisible: false,
+                            on_update_current_timestamp: false,
+                            default_constraint_name: None,
+                            collation: None,
+                            storage: None,
                         },
                         Column {
                             name: "name".to_string(),
@@ -754,6 +974,13 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            identity: None,
+                            identity_options: None,
+                            invisible: false,
+                            on_update_current_timestamp: false,
+                            default_constraint_name: None,
+                            collation: None,
+                            storage: None,
                         },
                     ],
                     indices: vec![],
@@ -763,6 +990,13 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    ddl: None,
+                    collation: None,
+                    check_constraints: Vec::new(),
+                    policies: Vec::new(),
+                    partitioning: None,
+                    row_level_security: false,
+                    without_rowid: false,
                 },
                 Table {
                     name: "User".to_string(),
@@ -780,6 +1014,13 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            identity: None,
+                            identity_options: None,
+                            invisible: false,
+                            on_update_current_timestamp: false,
+                            default_constraint_name: None,
+                            collation: None,
+                            storage: None,
                         },
                         Column {
                             name: "city-id".to_string(),
@@ -794,6 +1035,13 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            identity: None,
+                            identity_options: None,
+                            invisible: false,
+                            on_update_current_timestamp: false,
+                            default_constraint_name: None,
+                            collation: None,
+                            storage: None,
                         },
                         Column {
                             name: "city-name".to_string(),
@@ -808,6 +1056,13 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            identity: None,
+                            identity_options: None,
+                            invisible: false,
+                            on_update_current_timestamp: false,
+                            default_constraint_name: None,
+                            collation: None,
+                            storage: None,
                         },
                     ],
                     indices: vec![],
@@ -824,14 +1079,32 @@ mod tests {
                         on_delete_action: ForeignKeyAction::NoAction,
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
+                        deferrable: false,
+                        initially_deferred: false,
+                        match_full: false,
                     }],
+                    ddl: None,
+                    collation: None,
+                    check_constraints: Vec::new(),
+                    policies: Vec::new(),
+                    partitioning: None,
+                    row_level_security: false,
+                    without_rowid: false,
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: None,
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(
+            &schema,
+            &SqlFamily::Postgres,
+            &Datamodel::new(),
+            false,
+            DEFAULT_MIGRATIONS_TABLE_NAME,
+        )
+        .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, expected_data_model);
     }
@@ -898,6 +1171,13 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -912,6 +1192,13 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "lastname".to_string(),
@@ -926,12 +1213,26 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "name_last_name_unique".to_string(),
                     columns: vec!["name".to_string(), "lastname".to_string()],
                     tpe: IndexType::Unique,
+                    storage_parameters: Vec::new(),
+                    expressions: Vec::new(),
+                    algorithm: None,
+                    invisible: false,
+                    comment: None,
+                    nulls_not_distinct: false,
+                    opclasses: Vec::new(),
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
@@ -939,12 +1240,27 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                ddl: None,
+                collation: None,
+                check_constraints: Vec::new(),
+                policies: Vec::new(),
+                partitioning: None,
+                row_level_security: false,
+                without_rowid: false,
             }],
             enums: vec![],
             sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: None,
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(
+            &schema,
+            &SqlFamily::Postgres,
+            &Datamodel::new(),
+            false,
+            DEFAULT_MIGRATIONS_TABLE_NAME,
+        )
+        .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -987,6 +1303,7 @@ mod tests {
                                 references: vec![],
                                 name: "CityToUser".to_string(),
                                 on_delete: OnDeleteStrategy::None,
+                                foreign_key_name: None,
                             },
                         )),
                     ],
@@ -1028,6 +1345,7 @@ mod tests {
                                 fields: vec!["city_id".to_string()],
                                 references: vec!["id".to_string()],
                                 on_delete: OnDeleteStrategy::None,
+                                foreign_key_name: None,
                             },
                         )),
                     ],
@@ -1057,6 +1375,13 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            identity: None,
+                            identity_options: None,
+                            invisible: false,
+                            on_update_current_timestamp: false,
+                            default_constraint_name: None,
+                            collation: None,
+                            storage: None,
                         },
                         Column {
                             name: "name".to_string(),
@@ -1071,6 +1396,13 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            identity: None,
+                            identity_options: None,
+                            invisible: false,
+                            on_update_current_timestamp: false,
+                            default_constraint_name: None,
+                            collation: None,
+                            storage: None,
                         },
                     ],
                     indices: vec![],
@@ -1080,6 +1412,13 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    ddl: None,
+                    collation: None,
+                    check_constraints: Vec::new(),
+                    policies: Vec::new(),
+                    partitioning: None,
+                    row_level_security: false,
+                    without_rowid: false,
                 },
                 Table {
                     name: "User".to_string(),
@@ -1097,6 +1436,13 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            identity: None,
+                            identity_options: None,
+                            invisible: false,
+                            on_update_current_timestamp: false,
+                            default_constraint_name: None,
+                            collation: None,
+                            storage: None,
                         },
                         Column {
                             name: "city_id".to_string(),
@@ -1111,6 +1457,13 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            identity: None,
+                            identity_options: None,
+                            invisible: false,
+                            on_update_current_timestamp: false,
+                            default_constraint_name: None,
+                            collation: None,
+                            storage: None,
                         },
                     ],
                     indices: vec![],
@@ -1126,14 +1479,32 @@ mod tests {
                         on_delete_action: ForeignKeyAction::NoAction,
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string()],
+                        deferrable: false,
+                        initially_deferred: false,
+                        match_full: false,
                     }],
+                    ddl: None,
+                    collation: None,
+                    check_constraints: Vec::new(),
+                    policies: Vec::new(),
+                    partitioning: None,
+                    row_level_security: false,
+                    without_rowid: false,
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: None,
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(
+            &schema,
+            &SqlFamily::Postgres,
+            &Datamodel::new(),
+            false,
+            DEFAULT_MIGRATIONS_TABLE_NAME,
+        )
+        .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -1172,10 +1543,88 @@ mod tests {
                 values: enum_values,
             }],
             sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: None,
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(
+            &schema,
+            &SqlFamily::Postgres,
+            &Datamodel::new(),
+            false,
+            DEFAULT_MIGRATIONS_TABLE_NAME,
+        )
+        .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
+
+    #[test]
+    fn the_migrations_table_is_left_out_whatever_its_name() {
+        let column = |name: &str| Column {
+            name: name.to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+            default: None,
+            auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
+        };
+
+        let schema = SqlSchema {
+            tables: vec![Table {
+                name: "_custom_migrations".to_owned(),
+                columns: vec![
+                    "id",
+                    "checksum",
+                    "finished_at",
+                    "migration_name",
+                    "logs",
+                    "rolled_back_at",
+                    "started_at",
+                    "applied_steps_count",
+                    "script",
+                ]
+                .into_iter()
+                .map(column)
+                .collect(),
+                indices: vec![],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["id".to_string()],
+                    sequence: None,
+                    constraint_name: None,
+                }),
+                foreign_keys: vec![],
+                ddl: None,
+                collation: None,
+                check_constraints: Vec::new(),
+                policies: Vec::new(),
+                partitioning: None,
+                row_level_security: false,
+                without_rowid: false,
+            }],
+            enums: vec![],
+            sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: None,
+        };
+
+        let introspect = |migrations_table_name| {
+            calculate_datamodel(
+                &schema,
+                &SqlFamily::Postgres,
+                &Datamodel::new(),
+                false,
+                migrations_table_name,
+            )
+            .expect("calculate data model")
+            .data_model
+        };
+
+        assert!(introspect("_custom_migrations").models.is_empty());
+        assert_eq!(introspect(DEFAULT_MIGRATIONS_TABLE_NAME).models.len(), 1);
+    }
 }
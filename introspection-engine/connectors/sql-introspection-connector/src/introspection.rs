@@ -17,12 +17,13 @@ pub fn introspect(
     data_model: &mut Datamodel,
     sql_family: SqlFamily,
     native_types: bool,
+    migrations_table_name: &str,
 ) -> Result<(), SqlError> {
     for table in schema
         .tables
         .iter()
         .filter(|table| !is_old_migration_table(&table))
-        .filter(|table| !is_new_migration_table(&table))
+        .filter(|table| !is_new_migration_table(&table, migrations_table_name))
         .filter(|table| !is_prisma_1_point_1_or_2_join_table(&table))
         .filter(|table| !is_prisma_1_point_0_join_table(&table))
         .filter(|table| !is_relay_table(&table))
@@ -57,6 +58,8 @@ pub fn introspect(
         for index in table
             .indices
             .iter()
+            // Indexes on expressions cannot be represented in the datamodel.
+            .filter(|i| !i.has_expressions())
             .filter(|i| !(i.columns.len() == 1 && i.is_unique()))
         {
             model.add_index(calculate_index(index));
@@ -28,8 +28,8 @@ pub fn is_old_migration_table(table: &Table) -> bool {
         && table.columns.iter().any(|c| c.name == "finished_at")
 }
 
-pub fn is_new_migration_table(table: &Table) -> bool {
-    table.name == "_prisma_migrations"
+pub fn is_new_migration_table(table: &Table, migrations_table_name: &str) -> bool {
+    table.name == migrations_table_name
         && table.columns.iter().any(|c| c.name == "id")
         && table.columns.iter().any(|c| c.name == "checksum")
         && table.columns.iter().any(|c| c.name == "finished_at")
@@ -110,6 +110,7 @@ pub fn calculate_many_to_many_field(
         to: opposite_foreign_key.referenced_table.clone(),
         references: opposite_foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        foreign_key_name: None,
     };
 
     let basename = opposite_foreign_key.referenced_table.clone();
@@ -176,6 +177,14 @@ pub(crate) fn calculate_scalar_field(
 
     let is_id = is_id(&column, &table);
     let is_unique = table.is_column_unique(&column.name) && !is_id;
+    let unique_index_name = table
+        .indices
+        .iter()
+        .filter(|_| is_unique)
+        .find(|index| {
+            index.tpe == IndexType::Unique && !index.has_expressions() && index.columns == [column.name.as_str()]
+        })
+        .map(|index| index.name.clone());
 
     ScalarField {
         name: column.name.clone(),
@@ -184,6 +193,7 @@ pub(crate) fn calculate_scalar_field(
         database_name: None,
         default_value,
         is_unique,
+        unique_index_name,
         is_id,
         documentation,
         is_generated: false,
@@ -205,6 +215,7 @@ pub(crate) fn calculate_relation_field(
         to: foreign_key.referenced_table.clone(),
         references: foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        foreign_key_name: foreign_key.constraint_name.clone(),
     };
 
     let columns: Vec<&Column> = foreign_key
@@ -239,6 +250,7 @@ pub(crate) fn calculate_backrelation_field(
                 fields: vec![],
                 references: vec![],
                 on_delete: OnDeleteStrategy::None,
+                foreign_key_name: None,
             };
 
             // unique or id
@@ -16,15 +16,28 @@ use introspection_connector::{
     ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResult,
 };
 use quaint::prelude::ConnectionInfo;
-use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
-use std::future::Future;
+use sql_schema_describer::{
+    connection_string_param, describe_timeout_from_connection_string, SqlSchema, SqlSchemaDescriberBackend,
+};
+use std::{future::Future, time::Duration};
 use tracing_futures::Instrument;
 
 pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 
+/// The name of the migrations table of the migration engine, which is not introspected.
+pub const DEFAULT_MIGRATIONS_TABLE_NAME: &str = "_prisma_migrations";
+
+/// The connection string parameter setting another name for the migrations table, like the
+/// migration engine's.
+pub const MIGRATIONS_TABLE_PARAM: &str = "migrations_table";
+
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    /// See `describe_timeout_from_connection_string()`.
+    describe_timeout: Option<Duration>,
+    /// See `MIGRATIONS_TABLE_PARAM`.
+    migrations_table_name: String,
 }
 
 impl SqlIntrospectionConnector {
@@ -43,6 +56,9 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            describe_timeout: describe_timeout_from_connection_string(url),
+            migrations_table_name: connection_string_param(url, MIGRATIONS_TABLE_PARAM)
+                .unwrap_or_else(|| DEFAULT_MIGRATIONS_TABLE_NAME.to_owned()),
         })
     }
 
@@ -65,7 +81,10 @@ impl SqlIntrospectionConnector {
     }
 
     async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
-        Ok(self.describer.describe(self.connection_info.schema_name()).await?)
+        Ok(self
+            .describer
+            .describe_with_timeout(self.connection_info.schema_name(), self.describe_timeout)
+            .await?)
     }
 
     async fn version(&self) -> SqlIntrospectionResult<String> {
@@ -110,11 +129,14 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
 
         let family = self.connection_info.sql_family();
 
-        let introspection_result =
-            calculate_datamodel::calculate_datamodel(&sql_schema, &family, &previous_data_model, native_types)
-                .map_err(|sql_introspection_error| {
-                    sql_introspection_error.into_connector_error(&self.connection_info)
-                })?;
+        let introspection_result = calculate_datamodel::calculate_datamodel(
+            &sql_schema,
+            &family,
+            &previous_data_model,
+            native_types,
+            &self.migrations_table_name,
+        )
+        .map_err(|sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info))?;
 
         tracing::debug!("Calculating datamodel is done: {:?}", introspection_result.data_model);
 
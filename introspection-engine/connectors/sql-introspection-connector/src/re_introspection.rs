@@ -138,6 +138,35 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
         }
     }
 
+    // `map` on @unique and @relation: the names of the described constraints are only kept when
+    // the previous data model set them, since the other ones are generated by the database or by
+    // Prisma and would clutter the schema.
+    {
+        for model in new_data_model.models_mut() {
+            let old_model = old_data_model.find_model(&model.name);
+
+            for field in model.scalar_fields_mut() {
+                let old_name = old_model
+                    .and_then(|old_model| old_model.find_scalar_field(&field.name))
+                    .and_then(|old_field| old_field.unique_index_name.as_ref());
+
+                if field.unique_index_name.as_ref() != old_name {
+                    field.unique_index_name = None;
+                }
+            }
+
+            for field in model.relation_fields_mut() {
+                let old_name = old_model
+                    .and_then(|old_model| old_model.find_relation_field(&field.name))
+                    .and_then(|old_field| old_field.relation_info.foreign_key_name.as_ref());
+
+                if field.relation_info.foreign_key_name.as_ref() != old_name {
+                    field.relation_info.foreign_key_name = None;
+                }
+            }
+        }
+    }
+
     //keep old virtual relation names on non M:N relations
     // M:N relations cannot be uniquely identified without ignoring the relationname and their relationnames cant
     // be changed without necessitation db changes since RelationName -> Join table name
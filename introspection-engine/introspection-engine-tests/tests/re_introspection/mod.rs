@@ -1567,3 +1567,68 @@ async fn schemas_with_required_virtual_relation_fields_must_be_handlded_graceful
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn constraint_names_set_with_map_are_kept(api: &TestApi) -> crate::TestResult {
+    let sql = format!(
+        r#"
+        CREATE TABLE "{schema}"."User" (
+            id SERIAL PRIMARY KEY,
+            email TEXT NOT NULL,
+            CONSTRAINT "custom_email_key" UNIQUE (email)
+        );
+
+        CREATE TABLE "{schema}"."Post" (
+            id SERIAL PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            CONSTRAINT "custom_user_fkey" FOREIGN KEY (user_id) REFERENCES "{schema}"."User"(id)
+        );
+
+        CREATE TABLE "{schema}"."Unrelated" (
+            id SERIAL PRIMARY KEY,
+            name TEXT NOT NULL UNIQUE
+        );
+        "#,
+        schema = api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id               Int         @id @default(autoincrement())
+            email            String      @unique(map: "custom_email_key")
+            Post             Post[]
+        }
+
+        model Post {
+            id               Int         @id @default(autoincrement())
+            user_id          Int
+            User             User        @relation(fields: [user_id], references: [id], map: "custom_user_fkey")
+        }
+    "#};
+
+    // The generated names of the constraints of the new model are left out.
+    let final_dm = indoc! {r#"
+        model User {
+            id               Int         @id @default(autoincrement())
+            email            String      @unique(map: "custom_email_key")
+            Post             Post[]
+        }
+
+        model Post {
+            id               Int         @id @default(autoincrement())
+            user_id          Int
+            User             User        @relation(fields: [user_id], references: [id], map: "custom_user_fkey")
+        }
+
+        model Unrelated {
+            id               Int         @id @default(autoincrement())
+            name             String      @unique
+        }
+    "#};
+
+    assert_eq_datamodels!(final_dm, &api.re_introspect(input_dm).await?);
+
+    Ok(())
+}
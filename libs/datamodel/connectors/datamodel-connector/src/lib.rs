@@ -110,6 +110,8 @@ pub enum ConnectorCapability {
     RelationFieldsInArbitraryOrder,
     // start of Query Engine Capabilities
     InsensitiveFilters,
+    /// The driver reports affected row counts as 64-bit integers, so batch counts can be exposed as BigInt.
+    BigIntAffectedRowCounts,
 }
 
 /// Contains all capabilities that the connector is able to serve.
@@ -281,6 +281,9 @@ pub struct ScalarField {
     /// Indicates if the field is unique.
     pub is_unique: bool,
 
+    /// The name of the unique index in the database, if set with `@unique(map: ...)`.
+    pub unique_index_name: Option<String>,
+
     /// true if this field marked with @id.
     pub is_id: bool,
 
@@ -308,6 +311,7 @@ impl ScalarField {
             database_name: None,
             default_value: None,
             is_unique: false,
+            unique_index_name: None,
             is_id: false,
             documentation: None,
             is_generated: false,
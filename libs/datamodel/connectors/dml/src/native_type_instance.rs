@@ -32,6 +32,15 @@ impl NativeTypeInstance {
         serde_json::from_value(self.serialized_native_type.clone()).expect(&error_msg)
     }
 
+    /// Like `deserialize_native_type`, but returns `None` instead of panicking when the native
+    /// type is not a variant of `T`.
+    pub fn try_deserialize_native_type<T>(&self) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        serde_json::from_value(self.serialized_native_type.clone()).ok()
+    }
+
     pub fn render(&self) -> String {
         if self.args.len() == 0 {
             self.name.to_string()
@@ -12,6 +12,8 @@ pub struct RelationInfo {
     /// A strategy indicating what happens when
     /// a related node is deleted.
     pub on_delete: OnDeleteStrategy,
+    /// The name of the foreign key constraint in the database, if set with `map`.
+    pub foreign_key_name: Option<String>,
 }
 
 impl PartialEq for RelationInfo {
@@ -34,6 +36,7 @@ impl RelationInfo {
             references: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            foreign_key_name: None,
         }
     }
 }
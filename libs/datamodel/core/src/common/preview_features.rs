@@ -6,6 +6,7 @@ const TRANSACTION_API: &str = "transactionApi";
 const NATIVE_TYPES: &str = "nativeTypes";
 const SQL_SERVER: &str = "microsoftSqlServer";
 const UNCHECKED_SCALAR_INPUTS: &str = "uncheckedScalarInputs";
+const BIG_INT_BATCH_COUNTS: &str = "bigIntBatchCounts";
 
 // deprecated preview features
 const ATOMIC_NUMBER_OPERATIONS: &str = "atomicNumberOperations";
@@ -15,7 +16,8 @@ const DISTINCT: &str = "distinct";
 
 pub const DATASOURCE_PREVIEW_FEATURES: &[&'static str] = &[];
 
-pub const GENERATOR_PREVIEW_FEATURES: &[&'static str] = &[NATIVE_TYPES, SQL_SERVER, UNCHECKED_SCALAR_INPUTS];
+pub const GENERATOR_PREVIEW_FEATURES: &[&'static str] =
+    &[NATIVE_TYPES, SQL_SERVER, UNCHECKED_SCALAR_INPUTS, BIG_INT_BATCH_COUNTS];
 
 pub const DEPRECATED_GENERATOR_PREVIEW_FEATURES: &[&'static str] = &[
     ATOMIC_NUMBER_OPERATIONS,
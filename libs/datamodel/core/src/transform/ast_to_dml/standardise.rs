@@ -227,6 +227,7 @@ impl Standardiser {
                         references: vec![],
                         name: rel_info.name.clone(),
                         on_delete: OnDeleteStrategy::None,
+                        foreign_key_name: None,
                     };
                     let mut back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
                     back_relation_field.arity = dml::FieldArity::List;
@@ -298,6 +299,7 @@ impl Standardiser {
                         references: unique_criteria_field_names,
                         name: rel_info.name.clone(),
                         on_delete: OnDeleteStrategy::None,
+                        foreign_key_name: None,
                     };
 
                     let back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
@@ -31,6 +31,17 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 rf.relation_info.fields = base_fields.as_array().to_literal_vec()?;
             }
 
+            if let Some(map_arg) = args.optional_arg("map") {
+                if rf.relation_info.fields.is_empty() {
+                    return self.new_attribute_validation_error(
+                        "The foreign key name can only be set with `map` on the side of the relation that defines `fields`.",
+                        map_arg.span(),
+                    );
+                }
+
+                rf.relation_info.foreign_key_name = Some(map_arg.as_str()?);
+            }
+
             // TODO: bring `onDelete` back once `prisma migrate` is a thing
             //            if let Ok(on_delete) = args.arg("onDelete") {
             //                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
@@ -99,6 +110,10 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 }
             }
 
+            if let Some(foreign_key_name) = &relation_info.foreign_key_name {
+                args.push(ast::Argument::new_string("map", foreign_key_name));
+            }
+
             if relation_info.on_delete != dml::OnDeleteStrategy::None {
                 args.push(ast::Argument::new_constant(
                     "onDelete",
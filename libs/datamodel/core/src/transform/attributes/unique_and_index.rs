@@ -44,6 +44,10 @@ impl AttributeValidator<dml::Field> for FieldLevelUniqueAttributeValidator {
                 );
             } else {
                 sf.is_unique = true;
+                sf.unique_index_name = match args.optional_arg("map") {
+                    Some(map_arg) => Some(map_arg.as_str()?),
+                    None => None,
+                };
             }
         }
         Ok(())
@@ -56,7 +60,12 @@ impl AttributeValidator<dml::Field> for FieldLevelUniqueAttributeValidator {
     ) -> Result<Vec<ast::Attribute>, DatamodelError> {
         if let dml::Field::ScalarField(sf) = field {
             if sf.is_unique {
-                return Ok(vec![ast::Attribute::new(self.attribute_name(), vec![])]);
+                let args = match &sf.unique_index_name {
+                    Some(name) => vec![ast::Argument::new_string("map", name)],
+                    None => vec![],
+                };
+
+                return Ok(vec![ast::Attribute::new(self.attribute_name(), args)]);
             }
         }
         Ok(vec![])
@@ -194,6 +194,10 @@ impl<'a> ScalarFieldWalker<'a> {
     pub fn name(&self) -> &'a str {
         &self.field.name
     }
+
+    pub fn unique_index_name(&self) -> Option<&'a str> {
+        self.field.unique_index_name.as_deref()
+    }
 }
 
 #[derive(Debug)]
@@ -233,6 +237,10 @@ impl<'a> RelationFieldWalker<'a> {
         self.field.arity
     }
 
+    pub fn foreign_key_name(&self) -> Option<&'a str> {
+        self.field.relation_info.foreign_key_name.as_deref()
+    }
+
     pub fn is_one_to_one(&self) -> bool {
         self.field.is_singular() && self.opposite_side().field.is_singular()
     }
@@ -228,3 +228,27 @@ fn should_fail_on_conflicting_generated_back_relation_fields() {
         Span::new(98, 152),
     ));
 }
+
+#[test]
+fn should_fail_on_map_on_the_side_of_the_relation_without_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(map: "custom_user_fkey")
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The foreign key name can only be set with `map` on the side of the relation that defines `fields`.",
+        "relation",
+        Span::new(79, 97),
+    ));
+}
@@ -120,3 +120,30 @@ fn must_generate_back_relation_fields_for_named_relation_fields() {
         .assert_relation_name("AssignedTodos")
         .assert_relation_to("Todo");
 }
+
+#[test]
+fn the_name_of_the_foreign_key_can_be_set_with_map() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], map: "custom_user_fkey")
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    let user_field = schema.assert_has_model("Post").assert_has_relation_field("user");
+    assert_eq!(
+        user_field.relation_info.foreign_key_name.as_deref(),
+        Some("custom_user_fkey")
+    );
+
+    let posts_field = schema.assert_has_model("User").assert_has_relation_field("posts");
+    assert_eq!(posts_field.relation_info.foreign_key_name, None);
+}
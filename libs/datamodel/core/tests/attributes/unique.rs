@@ -274,3 +274,25 @@ fn unique_attributes_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema).unwrap()).is_ok());
 }
+
+#[test]
+fn the_name_of_a_field_level_unique_index_can_be_set_with_map() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        email String @unique(map: "custom_email_key")
+        name  String @unique
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    let email = user_model.assert_has_scalar_field("email");
+    email.assert_is_unique(true);
+    assert_eq!(email.unique_index_name.as_deref(), Some("custom_email_key"));
+
+    let name = user_model.assert_has_scalar_field("name");
+    name.assert_is_unique(true);
+    assert_eq!(name.unique_index_name, None);
+}
@@ -78,3 +78,26 @@ fn test_render_relation_name_on_self_relations() {
 
     assert_eq!(rendered, input);
 }
+
+#[test]
+fn test_render_constraint_names_set_with_map() {
+    let input = r#"model Post {
+  id     Int    @id
+  slug   String @unique(map: "custom_slug_key")
+  userId Int
+  user   User   @relation(fields: [userId], references: [id], map: "custom_user_fkey")
+}
+
+model User {
+  id    Int    @id
+  posts Post[]
+}
+"#;
+
+    let expected = input;
+
+    let dml = parse(input);
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    assert_eq!(rendered, expected);
+}
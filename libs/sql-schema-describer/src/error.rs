@@ -1,8 +1,10 @@
 #![deny(missing_docs)]
 
+use quaint::prelude::SqlFamily;
 use std::{
     error::Error,
     fmt::{self, Display},
+    time::Duration,
 };
 use tracing_error::SpanTrace;
 
@@ -26,6 +28,43 @@ impl DescriberError {
     pub fn span_trace(&self) -> SpanTrace {
         self.context.clone()
     }
+
+    /// Wrap the error of a query on `system_view`. Errors caused by the
+    /// database user lacking the permission to read the view become
+    /// `DescriberErrorKind::PermissionDenied`.
+    pub(crate) fn from_system_view_query(
+        sql_family: SqlFamily,
+        system_view: &'static str,
+        err: quaint::error::Error,
+    ) -> Self {
+        let is_permission_denied = err
+            .original_code()
+            .map(|code| is_permission_denied_code(sql_family, code))
+            .unwrap_or(false);
+
+        if is_permission_denied {
+            DescriberErrorKind::PermissionDenied {
+                system_view,
+                source: err,
+            }
+            .into()
+        } else {
+            err.into()
+        }
+    }
+}
+
+/// Whether a database error code is for a denied read: `insufficient_privilege` on Postgres, the
+/// table, column and database access errors on MySQL, and the object and column permission errors
+/// on SQL Server. The codes are only compared on their own database, since the same numbers mean
+/// different things elsewhere.
+fn is_permission_denied_code(sql_family: SqlFamily, code: &str) -> bool {
+    match sql_family {
+        SqlFamily::Postgres => code == "42501",
+        SqlFamily::Mysql => matches!(code, "1142" | "1143" | "1044"),
+        SqlFamily::Mssql => matches!(code, "229" | "230"),
+        SqlFamily::Sqlite => false,
+    }
 }
 
 /// Variants of DescriberError.
@@ -33,23 +72,75 @@ impl DescriberError {
 pub enum DescriberErrorKind {
     /// An error originating from Quaint or the database.
     QuaintError(quaint::error::Error),
+    /// The database returned the columns of an index with missing or duplicate
+    /// positions.
+    InvalidIndexColumns {
+        /// The name of the table the index is defined on.
+        table_name: String,
+        /// The name of the index.
+        index_name: String,
+    },
+    /// The database user is not allowed to read a system view the describer
+    /// queries.
+    PermissionDenied {
+        /// The name of the inaccessible system view.
+        system_view: &'static str,
+        /// The error returned by the database.
+        source: quaint::error::Error,
+    },
+    /// Describing the schema took longer than the configured timeout.
+    Timeout {
+        /// The timeout that was exceeded.
+        timeout: Duration,
+    },
 }
 
-impl Display for DescriberError {
+impl Display for DescriberErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.kind {
-            DescriberErrorKind::QuaintError(err) => {
-                err.fmt(f)?;
-                self.context.fmt(f)
-            }
+        match self {
+            DescriberErrorKind::QuaintError(err) => err.fmt(f),
+            DescriberErrorKind::InvalidIndexColumns { table_name, index_name } => write!(
+                f,
+                "The columns of the index `{}` on the `{}` table could not be described: the column positions are not contiguous.",
+                index_name, table_name
+            ),
+            DescriberErrorKind::PermissionDenied { system_view, source } => write!(
+                f,
+                "The database user is not allowed to read `{}`, which is needed to describe the database schema: {}",
+                system_view, source
+            ),
+            DescriberErrorKind::Timeout { timeout } => write!(
+                f,
+                "Describing the database schema timed out after {}ms.",
+                timeout.as_millis()
+            ),
         }
     }
 }
 
+impl Display for DescriberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind.fmt(f)?;
+        self.context.fmt(f)
+    }
+}
+
 impl Error for DescriberError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match &self.kind {
             DescriberErrorKind::QuaintError(err) => Some(err),
+            DescriberErrorKind::InvalidIndexColumns { .. } => None,
+            DescriberErrorKind::PermissionDenied { source, .. } => Some(source),
+            DescriberErrorKind::Timeout { .. } => None,
+        }
+    }
+}
+
+impl From<DescriberErrorKind> for DescriberError {
+    fn from(kind: DescriberErrorKind) -> Self {
+        DescriberError {
+            kind,
+            context: SpanTrace::capture(),
         }
     }
 }
@@ -62,3 +153,71 @@ impl From<quaint::error::Error> for DescriberError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::error::{Error as QuaintError, ErrorKind as QuaintKind};
+
+    fn quaint_error(code: &str) -> QuaintError {
+        QuaintError::builder(QuaintKind::QueryError("query failed".into()))
+            .set_original_code(code)
+            .build()
+    }
+
+    #[test]
+    fn permission_errors_on_system_views_are_mapped_to_permission_denied() {
+        let cases = &[
+            (SqlFamily::Postgres, "42501"),
+            (SqlFamily::Mysql, "1142"),
+            (SqlFamily::Mssql, "229"),
+        ];
+
+        for (sql_family, code) in cases {
+            let err =
+                DescriberError::from_system_view_query(*sql_family, "information_schema.columns", quaint_error(code));
+
+            match err.into_kind() {
+                DescriberErrorKind::PermissionDenied { system_view, .. } => {
+                    assert_eq!(system_view, "information_schema.columns")
+                }
+                other => panic!("Expected a permission error for code {}, got {:?}", code, other),
+            }
+        }
+    }
+
+    #[test]
+    fn other_errors_on_system_views_are_kept_as_quaint_errors() {
+        let cases = &[
+            (SqlFamily::Postgres, "42P01"),
+            // The permission error codes of the other databases.
+            (SqlFamily::Postgres, "1142"),
+            (SqlFamily::Mysql, "229"),
+            (SqlFamily::Mssql, "1044"),
+            (SqlFamily::Sqlite, "42501"),
+        ];
+
+        for (sql_family, code) in cases {
+            let err = DescriberError::from_system_view_query(*sql_family, "sys.indexes", quaint_error(code));
+
+            assert!(
+                matches!(err.into_kind(), DescriberErrorKind::QuaintError(_)),
+                "{:?} {}",
+                sql_family,
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn permission_denied_errors_name_the_system_view() {
+        let kind = DescriberErrorKind::PermissionDenied {
+            system_view: "sys.indexes",
+            source: quaint_error("229"),
+        };
+
+        assert!(kind.to_string().starts_with(
+            "The database user is not allowed to read `sys.indexes`, which is needed to describe the database schema"
+        ));
+    }
+}
@@ -3,11 +3,15 @@ use crate::getters::Getter;
 use native_types::NativeType;
 use native_types::{MsSqlType, MsSqlTypeParameter};
 use once_cell::sync::Lazy;
-use quaint::{prelude::Queryable, single::Quaint};
+use quaint::{
+    prelude::{Queryable, SqlFamily},
+    single::Quaint,
+    Value,
+};
 use regex::Regex;
 use std::borrow::Cow;
 use std::{
-    collections::{BTreeMap, HashMap, HashSet},
+    collections::{BTreeMap, HashMap},
     convert::TryInto,
 };
 use tracing::trace;
@@ -29,14 +33,20 @@ use tracing::trace;
 /// ```
 static DEFAULT_NON_STRING: Lazy<Regex> = Lazy::new(|| Regex::new(r"\(\((.*)\)\)").unwrap());
 
-/// Matches a default value in the schema, that is a string.
+/// Matches a default value in the schema, that is a string. The string can
+/// be wrapped in one or more layers of parentheses, and have the `N` unicode
+/// prefix.
 ///
-/// Example:
+/// Examples:
 ///
 /// ```ignore
 /// ('this is a test')
 /// ```
-static DEFAULT_STRING: Lazy<Regex> = Lazy::new(|| Regex::new(r"\('(.*)'\)").unwrap());
+///
+/// ```ignore
+/// ((N'this is a test'))
+/// ```
+static DEFAULT_STRING: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)^\(+N?'(.*)'\)+$").unwrap());
 
 /// Matches a database-generated value in the schema.
 ///
@@ -47,9 +57,24 @@ static DEFAULT_STRING: Lazy<Regex> = Lazy::new(|| Regex::new(r"\('(.*)'\)").unwr
 /// ```
 static DEFAULT_DB_GEN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\((.*)\)").unwrap());
 
+/// The `@P2, @P3, ...` placeholders for table names, following the schema parameter.
+fn table_name_placeholders(table_names: &[String]) -> String {
+    (0..table_names.len())
+        .map(|idx| format!("@P{}", idx + 2))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn query_params<'a>(schema: &'a str, table_names: &'a [String]) -> Vec<Value<'a>> {
+    std::iter::once(schema.into())
+        .chain(table_names.iter().map(|name| name.as_str().into()))
+        .collect()
+}
+
 #[derive(Debug)]
 pub struct SqlSchemaDescriber {
     conn: Quaint,
+    include_synonyms: bool,
 }
 
 #[async_trait::async_trait]
@@ -69,26 +94,102 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     }
 
     #[tracing::instrument]
-    async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
-        let mut columns = self.get_all_columns(schema).await?;
-        let mut indexes = self.get_all_indices(schema).await?;
-        let mut foreign_keys = self.get_foreign_keys(schema).await?;
-
-        let table_names = self.get_table_names(schema).await?;
-        let mut tables = Vec::with_capacity(table_names.len());
-
-        for table_name in table_names {
-            let table = self.get_table(&table_name, &mut columns, &mut indexes, &mut foreign_keys);
-            tables.push(table);
-        }
+    async fn describe_filtered(
+        &self,
+        schema: &str,
+        table_name_filter: Option<&[String]>,
+    ) -> DescriberResult<SqlSchema> {
+        let tables = describe_tables_in_pages(self, schema, DEFAULT_PAGE_SIZE, table_name_filter)
+            .try_collect()
+            .await?;
+
+        let synonyms = if self.include_synonyms {
+            self.get_synonyms(schema).await?
+        } else {
+            Vec::new()
+        };
 
         Ok(SqlSchema {
             tables,
             enums: vec![],
             sequences: vec![],
+            synonyms,
+            flavour: Some(SqlSchemaFlavour::Mssql),
         })
     }
 
+    async fn list_tables(&self, schema: &str) -> DescriberResult<Vec<String>> {
+        self.get_table_names(schema).await
+    }
+
+    #[tracing::instrument]
+    async fn take_snapshot(&self) -> DescriberResult<Option<SchemaSnapshot>> {
+        // ISO 8601, in the local time of the server, like `sys.tables.modify_date`.
+        let sql = "SELECT CONVERT(NVARCHAR(27), SYSDATETIME(), 126) AS timestamp";
+        let row = self.conn.query_raw(sql, &[]).await?.into_single()?;
+
+        Ok(Some(SchemaSnapshot {
+            timestamp: row.get_expect_string("timestamp"),
+        }))
+    }
+
+    #[tracing::instrument]
+    async fn describe_changed_since(
+        &self,
+        schema: &str,
+        snapshot: &SchemaSnapshot,
+    ) -> DescriberResult<Option<Vec<Table>>> {
+        // `modify_date` is a DATETIME, rounded to increments of .000, .003 or .007 seconds. The
+        // snapshot is rounded the same way, so no change made after it can compare lower.
+        let sql = r#"
+            SELECT st.name AS table_name
+            FROM sys.tables st
+            WHERE SCHEMA_NAME(st.schema_id) = @P1
+            AND st.is_ms_shipped = 0
+            AND st.modify_date >= CONVERT(DATETIME, @P2, 126)
+            ORDER BY st.name ASC
+        "#;
+
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), snapshot.timestamp.as_str().into()])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Mssql, "sys.tables", err))?;
+
+        let table_names: Vec<String> = rows
+            .into_iter()
+            .map(|row| row.get_expect_string("table_name"))
+            .collect();
+
+        trace!("Found tables changed since {}: {:?}", snapshot.timestamp, table_names);
+
+        // Described in pages, since every table name is a query parameter, and SQL Server accepts
+        // at most 2100 parameters per query.
+        let tables = describe_tables_in_pages(self, schema, DEFAULT_PAGE_SIZE, Some(&table_names))
+            .try_collect()
+            .await?;
+
+        Ok(Some(tables))
+    }
+
+    #[tracing::instrument(skip(table_names))]
+    async fn describe_tables(&self, schema: &str, table_names: &[String]) -> DescriberResult<(Vec<Table>, Vec<Enum>)> {
+        if table_names.is_empty() {
+            return Ok((Vec::new(), Vec::new()));
+        }
+
+        let mut columns = self.get_all_columns(schema, table_names).await?;
+        let mut indexes = self.get_all_indices(schema, table_names).await?;
+        let mut foreign_keys = self.get_foreign_keys(schema, table_names).await?;
+
+        let tables = table_names
+            .iter()
+            .map(|table_name| self.get_table(&table_name, &mut columns, &mut indexes, &mut foreign_keys))
+            .collect();
+
+        Ok((tables, Vec::new()))
+    }
+
     #[tracing::instrument]
     async fn version(&self, schema: &str) -> DescriberResult<Option<String>> {
         Ok(self.conn.version().await?)
@@ -97,13 +198,26 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 
 impl SqlSchemaDescriber {
     pub fn new(conn: Quaint) -> Self {
-        Self { conn }
+        Self {
+            conn,
+            include_synonyms: false,
+        }
+    }
+
+    /// Populate the `synonyms` of the described schema, resolving the ones aliasing its tables.
+    pub fn include_synonyms(mut self, include_synonyms: bool) -> Self {
+        self.include_synonyms = include_synonyms;
+        self
     }
 
     #[tracing::instrument]
     async fn get_databases(&self) -> DescriberResult<Vec<String>> {
         let sql = "SELECT name FROM sys.schemas";
-        let rows = self.conn.query_raw(sql, &[]).await?;
+        let rows = self
+            .conn
+            .query_raw(sql, &[])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Mssql, "sys.schemas", err))?;
 
         let names = rows.into_iter().map(|row| row.get_expect_string("name")).collect();
 
@@ -126,7 +240,11 @@ impl SqlSchemaDescriber {
             ORDER BY table_name ASC
         "#;
 
-        let rows = self.conn.query_raw(select, &[schema.into()]).await?;
+        let rows = self
+            .conn
+            .query_raw(select, &[schema.into()])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Mssql, "sys.tables", err))?;
 
         let names = rows
             .into_iter()
@@ -138,6 +256,49 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument]
+    async fn get_synonyms(&self, schema: &str) -> DescriberResult<Vec<Synonym>> {
+        // `OBJECT_ID` resolves the base object name the same way queries using the synonym do,
+        // relative to the current database.
+        let select = r#"
+            SELECT syn.name AS synonym_name,
+                   syn.base_object_name AS base_object_name,
+                   obj.name AS base_table_name,
+                   SCHEMA_NAME(obj.schema_id) AS base_table_schema
+            FROM sys.synonyms syn
+            LEFT JOIN sys.objects obj
+                ON obj.object_id = OBJECT_ID(syn.base_object_name)
+                AND obj.type = 'U'
+            WHERE SCHEMA_NAME(syn.schema_id) = @P1
+            ORDER BY syn.name ASC
+        "#;
+
+        let rows = self
+            .conn
+            .query_raw(select, &[schema.into()])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Mssql, "sys.synonyms", err))?;
+
+        let synonyms = rows
+            .into_iter()
+            .map(|row| {
+                let base_table = row
+                    .get_string("base_table_name")
+                    .filter(|_| row.get_string("base_table_schema").as_deref() == Some(schema));
+
+                Synonym {
+                    name: row.get_expect_string("synonym_name"),
+                    base_object_name: row.get_expect_string("base_object_name"),
+                    base_table,
+                }
+            })
+            .collect();
+
+        trace!("Found synonyms: {:?}", synonyms);
+
+        Ok(synonyms)
+    }
+
     #[tracing::instrument]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql = r#"
@@ -157,7 +318,11 @@ impl SqlSchemaDescriber {
                 size DESC;
         "#;
 
-        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Mssql, "sys.allocation_units", err))?;
 
         let size: i64 = rows
             .into_single()
@@ -181,17 +346,33 @@ impl SqlSchemaDescriber {
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
 
-        Table {
+        let mut table = Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
-        }
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
+        };
+
+        table.sort_indexes_and_foreign_keys();
+
+        table
     }
 
-    async fn get_all_columns(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<Column>>> {
-        let sql = r#"
+    async fn get_all_columns(
+        &self,
+        schema: &str,
+        table_names: &[String],
+    ) -> DescriberResult<HashMap<String, Vec<Column>>> {
+        let sql = format!(
+            r#"
             SELECT
                 column_name,
                 data_type,
@@ -201,18 +382,31 @@ impl SqlSchemaDescriber {
                 columnproperty(object_id(@P1 + '.' + table_name), column_name, 'IsIdentity') is_identity,
                 table_name,
                 numeric_precision,
-                numeric_scale
+                numeric_scale,
+                dc.name AS default_constraint_name
             FROM information_schema.columns c
             INNER JOIN sys.tables t
             ON c.TABLE_NAME = t.name AND SCHEMA_ID(c.TABLE_SCHEMA) = t.schema_id
+            LEFT OUTER JOIN sys.default_constraints dc
+            ON dc.parent_object_id = t.object_id
+            AND dc.parent_column_id = COLUMNPROPERTY(t.object_id, c.column_name, 'ColumnId')
             WHERE table_schema = @P1
             AND t.is_ms_shipped = 'false'
+            AND c.table_name IN ({})
             ORDER BY ordinal_position
-        "#;
+        "#,
+            table_name_placeholders(table_names)
+        );
 
         let mut map = HashMap::new();
 
-        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let rows = self
+            .conn
+            .query_raw(&sql, &query_params(schema, table_names))
+            .await
+            .map_err(|err| {
+                DescriberError::from_system_view_query(SqlFamily::Mssql, "information_schema.columns", err)
+            })?;
 
         for col in rows {
             debug!("Got column: {:?}", col);
@@ -256,10 +450,12 @@ impl SqlSchemaDescriber {
                     None => None,
                     Some(x) if x == "(NULL)" => None,
                     Some(default_string) => {
-                        let default_string = DEFAULT_NON_STRING
+                        // String defaults come first, the other regexes would
+                        // keep the quotes and unicode prefix around the value.
+                        let default_string = DEFAULT_STRING
                             .captures_iter(&default_string)
                             .next()
-                            .or_else(|| DEFAULT_STRING.captures_iter(&default_string).next())
+                            .or_else(|| DEFAULT_NON_STRING.captures_iter(&default_string).next())
                             .or_else(|| DEFAULT_DB_GEN.captures_iter(&default_string).next())
                             .map(|cap| cap[1].to_string())
                             .expect(&format!("Couldn't parse default value: `{}`", default_string));
@@ -277,7 +473,7 @@ impl SqlSchemaDescriber {
                                 Some(float_value) => DefaultValue::VALUE(float_value),
                                 None => DefaultValue::DBGENERATED(default_string),
                             },
-                            ColumnTypeFamily::Decimal => match parse_float(&default_string) {
+                            ColumnTypeFamily::Decimal => match parse_decimal_default(&default_string) {
                                 Some(float_value) => DefaultValue::VALUE(float_value),
                                 None => DefaultValue::DBGENERATED(default_string),
                             },
@@ -307,6 +503,13 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                identity: None,
+                identity_options: None,
+                invisible: false,
+                on_update_current_timestamp: false,
+                default_constraint_name: col.get_string("default_constraint_name"),
+                collation: None,
+                storage: None,
             });
         }
 
@@ -316,17 +519,18 @@ impl SqlSchemaDescriber {
     async fn get_all_indices(
         &self,
         schema: &str,
+        table_names: &[String],
     ) -> DescriberResult<HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>> {
         let mut map = HashMap::new();
-        let mut indexes_with_expressions: HashSet<(String, String)> = HashSet::new();
 
-        let sql = r#"
+        let sql = format!(
+            r#"
             SELECT DISTINCT
                 ind.name AS index_name,
                 ind.is_unique AS is_unique,
                 ind.is_primary_key AS is_primary_key,
                 col.name AS column_name,
-                ic.index_column_id AS seq_in_index,
+                ic.key_ordinal AS seq_in_index,
                 t.name AS table_name
             FROM
                 sys.indexes ind
@@ -339,11 +543,24 @@ impl SqlSchemaDescriber {
             WHERE SCHEMA_NAME(t.schema_id) = @P1
                 AND t.is_ms_shipped = 0
                 AND ind.filter_definition IS NULL
+                AND ic.key_ordinal > 0
+                AND t.name IN ({})
 
             ORDER BY index_name, seq_in_index
-        "#;
+        "#,
+            table_name_placeholders(table_names)
+        );
 
-        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let rows = self
+            .conn
+            .query_raw(&sql, &query_params(schema, table_names))
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Mssql, "sys.indexes", err))?;
+
+        // Multi-column indices will return more than one row (with different column_name values).
+        // We cannot assume that one row corresponds to one index, nor that the rows of an index
+        // arrive in order.
+        let mut raw_indexes: BTreeMap<(String, String), RawIndex> = BTreeMap::new();
 
         for row in rows {
             trace!("Got index row: {:#?}", row);
@@ -351,87 +568,69 @@ impl SqlSchemaDescriber {
             let table_name = row.get_expect_string("table_name");
             let index_name = row.get_expect_string("index_name");
 
-            match row.get("column_name").and_then(|x| x.to_string()) {
-                Some(column_name) => {
-                    let seq_in_index = row.get_expect_i64("seq_in_index");
-                    let pos = seq_in_index - 1;
-                    let is_unique = row.get_expect_bool("is_unique");
-
-                    // Multi-column indices will return more than one row (with different column_name values).
-                    // We cannot assume that one row corresponds to one index.
-                    let (ref mut indexes_map, ref mut primary_key): &mut (_, Option<PrimaryKey>) = map
-                        .entry(table_name)
-                        .or_insert((BTreeMap::<String, Index>::new(), None));
-
-                    let is_pk = row.get_expect_bool("is_primary_key");
-
-                    if is_pk {
-                        debug!("Column '{}' is part of the primary key", column_name);
-
-                        match primary_key {
-                            Some(pk) => {
-                                if pk.columns.len() < (pos + 1) as usize {
-                                    pk.columns.resize((pos + 1) as usize, "".to_string());
-                                }
-
-                                pk.columns[pos as usize] = column_name;
-
-                                debug!(
-                                    "The primary key has already been created, added column to it: {:?}",
-                                    pk.columns
-                                );
-                            }
-                            None => {
-                                debug!("Instantiating primary key");
-
-                                primary_key.replace(PrimaryKey {
-                                    columns: vec![column_name],
-                                    sequence: None,
-                                    constraint_name: None,
-                                });
-                            }
-                        };
-                    } else if indexes_map.contains_key(&index_name) {
-                        if let Some(index) = indexes_map.get_mut(&index_name) {
-                            index.columns.push(column_name);
-                        }
-                    } else {
-                        indexes_map.insert(
-                            index_name.clone(),
-                            Index {
-                                name: index_name,
-                                columns: vec![column_name],
-                                tpe: match is_unique {
-                                    true => IndexType::Unique,
-                                    false => IndexType::Normal,
-                                },
-                            },
-                        );
-                    }
-                }
-                None => {
-                    indexes_with_expressions.insert((table_name, index_name));
-                }
-            }
+            // SQL Server has no indexes on expressions (only on computed columns, which have a
+            // name), so every key is a column.
+            let column_name = row.get_expect_string("column_name");
+            let seq_in_index = row.get_expect_i64("seq_in_index");
+
+            let raw_index = raw_indexes.entry((table_name, index_name)).or_insert_with(|| RawIndex {
+                is_primary_key: row.get_expect_bool("is_primary_key"),
+                is_unique: row.get_expect_bool("is_unique"),
+                columns: Vec::new(),
+            });
+
+            raw_index.columns.push((seq_in_index, column_name));
         }
 
-        for (table, (index_map, _)) in &mut map {
-            for (tble, index_name) in &indexes_with_expressions {
-                if tble == table {
-                    index_map.remove(index_name);
-                }
+        for ((table_name, index_name), raw_index) in raw_indexes {
+            let columns = columns_by_position(&table_name, &index_name, raw_index.columns)?;
+
+            let (indexes_map, primary_key) = map
+                .entry(table_name)
+                .or_insert_with(|| (BTreeMap::<String, Index>::new(), None));
+
+            if raw_index.is_primary_key {
+                *primary_key = Some(PrimaryKey {
+                    columns,
+                    sequence: None,
+                    constraint_name: None,
+                });
+            } else {
+                indexes_map.insert(
+                    index_name.clone(),
+                    Index {
+                        name: index_name,
+                        columns,
+                        tpe: match raw_index.is_unique {
+                            true => IndexType::Unique,
+                            false => IndexType::Normal,
+                        },
+                        storage_parameters: Vec::new(),
+                        expressions: Vec::new(),
+                        algorithm: None,
+                        invisible: false,
+                        comment: None,
+                        nulls_not_distinct: false,
+                        opclasses: Vec::new(),
+                    },
+                );
             }
         }
 
         Ok(map)
     }
 
-    async fn get_foreign_keys(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
+    async fn get_foreign_keys(
+        &self,
+        schema: &str,
+        table_names: &[String],
+    ) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
         // Foreign keys covering multiple columns will return multiple rows, which we need to
         // merge.
         let mut map: HashMap<String, HashMap<String, ForeignKey>> = HashMap::new();
 
-        let sql = r#"
+        let sql = format!(
+            r#"
             SELECT
                 OBJECT_NAME(fk.constraint_object_id) AS constraint_name,
                 parent_table.name AS table_name,
@@ -464,11 +663,18 @@ impl SqlSchemaDescriber {
                 AND referenced_column.name IS NOT NULL
             WHERE parent_table.is_ms_shipped = 'false'
             AND referenced_table.is_ms_shipped = 'false'
+            AND parent_table.name IN ({})
             ORDER BY
                 ordinal_position
-        "#;
+        "#,
+            table_name_placeholders(table_names)
+        );
 
-        let result_set = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let result_set = self
+            .conn
+            .query_raw(&sql, &query_params(schema, table_names))
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Mssql, "sys.foreign_key_columns", err))?;
 
         for row in result_set.into_iter() {
             debug!("Got description FK row {:#?}", row);
@@ -523,6 +729,9 @@ impl SqlSchemaDescriber {
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
+                        deferrable: false,
+                        initially_deferred: false,
+                        match_full: false,
                     };
 
                     intermediate_fks.insert(constraint_name, fk);
@@ -582,7 +791,12 @@ impl SqlSchemaDescriber {
 
         let casted_character_maximum_length = character_maximum_length.map(|x| x as u32);
         let type_parameter = parse_type_parameter(character_maximum_length);
-        let unsupported_type = || (Unsupported(full_data_type.clone()), None);
+        let unsupported_type = || {
+            let params =
+                unsupported_type_parameters(data_type, character_maximum_length, numeric_precision, numeric_scale);
+
+            (Unsupported(format!("{}{}", data_type, params)), None)
+        };
 
         let (family, native_type) = match data_type {
             "tinyint" => (Int, Some(MsSqlType::TinyInt)),
@@ -624,6 +838,13 @@ impl SqlSchemaDescriber {
             _ => unsupported_type(),
         };
 
+        // Unsupported types are rendered from their full data type, so it has
+        // to carry the parameters too.
+        let full_data_type = match &family {
+            Unsupported(full_data_type) => full_data_type.clone(),
+            _ => full_data_type,
+        };
+
         ColumnType {
             data_type: data_type.into(),
             full_data_type,
@@ -635,6 +856,54 @@ impl SqlSchemaDescriber {
     }
 }
 
+/// Parse a decimal default, as SQL Server keeps it: the literal as it was
+/// written, possibly in scientific notation or with extra parentheses, e.g.
+/// `1.50`, `1E2` or `-(1.5)`. The value is normalized, so the same number is
+/// always described the same way.
+fn parse_decimal_default(default_string: &str) -> Option<PrismaValue> {
+    let literal: String = default_string
+        .chars()
+        .filter(|c| !matches!(c, '(' | ')') && !c.is_whitespace())
+        .collect();
+
+    let value = BigDecimal::from_str(&literal).ok()?;
+
+    // Displaying the value expands the scientific notation.
+    let mut normalized = value.to_string();
+
+    if normalized.contains('.') {
+        let trimmed_len = normalized.trim_end_matches('0').trim_end_matches('.').len();
+        normalized.truncate(trimmed_len);
+    }
+
+    BigDecimal::from_str(&normalized).ok().map(PrismaValue::Float)
+}
+
+/// System types we do not support that never take parameters, even though the
+/// information schema reports a length for some of them (e.g. `hierarchyid`).
+const PARAMETERLESS_UNSUPPORTED_TYPES: &[&str] = &["geography", "geometry", "hierarchyid", "sql_variant", "timestamp"];
+
+/// The length or precision suffix of an unsupported type, as reported by the
+/// information schema, e.g. `(10)`, `(max)` or `(10,2)`.
+fn unsupported_type_parameters(
+    data_type: &str,
+    character_maximum_length: Option<i64>,
+    numeric_precision: Option<u32>,
+    numeric_scale: Option<u32>,
+) -> String {
+    if PARAMETERLESS_UNSUPPORTED_TYPES.contains(&data_type) {
+        return String::new();
+    }
+
+    match (character_maximum_length, numeric_precision, numeric_scale) {
+        (Some(-1), _, _) => "(max)".to_owned(),
+        (Some(length), _, _) if length > 0 => format!("({})", length),
+        (_, Some(p), Some(s)) => format!("({},{})", p, s),
+        (_, Some(p), None) => format!("({})", p),
+        _ => String::new(),
+    }
+}
+
 fn parse_type_parameter(character_maximum_length: Option<i64>) -> Option<MsSqlTypeParameter> {
     match character_maximum_length {
         Some(-1) => Some(MsSqlTypeParameter::Max),
@@ -642,3 +911,26 @@ fn parse_type_parameter(character_maximum_length: Option<i64>) -> Option<MsSqlTy
         None => None,
     }
 }
+
+/// The rows describing one index, before validation.
+struct RawIndex {
+    is_primary_key: bool,
+    is_unique: bool,
+    /// `(position, column name)` pairs, in arrival order.
+    columns: Vec<(i64, String)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsupported_type_parameters_keep_the_length_and_precision() {
+        assert_eq!(unsupported_type_parameters("mytype", Some(20), None, None), "(20)");
+        assert_eq!(unsupported_type_parameters("mytype", Some(-1), None, None), "(max)");
+        assert_eq!(unsupported_type_parameters("mytype", None, Some(10), Some(2)), "(10,2)");
+        assert_eq!(unsupported_type_parameters("mytype", None, Some(7), None), "(7)");
+        assert_eq!(unsupported_type_parameters("mytype", None, None, None), "");
+        assert_eq!(unsupported_type_parameters("hierarchyid", Some(892), None, None), "");
+    }
+}
@@ -2,7 +2,11 @@ use super::*;
 use crate::getters::Getter;
 use bigdecimal::ToPrimitive;
 use native_types::{MySqlType, NativeType};
-use quaint::{prelude::Queryable, single::Quaint, Value};
+use quaint::{
+    prelude::{Queryable, SqlFamily},
+    single::Quaint,
+    Value,
+};
 use serde_json::from_str;
 use std::{
     borrow::Cow,
@@ -10,12 +14,24 @@ use std::{
 };
 use tracing::trace;
 
-fn is_mariadb(version: &str) -> bool {
+/// Whether a server version string (e.g. `10.5.8-MariaDB-1:10.5.8+maria~focal`) is MariaDB's.
+pub fn is_mariadb(version: &str) -> bool {
     version.contains("MariaDB")
 }
 
+/// Whether a server version string (e.g. `8.0.23-0ubuntu0.20.04.1`) is MySQL 8's.
+pub fn is_mysql_8(version: &str) -> bool {
+    !is_mariadb(version) && version.starts_with("8.")
+}
+
+/// Quote an identifier with backticks, doubling the backticks in it.
+fn quote_ident(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
+}
+
 enum Flavour {
     Mysql,
+    Mysql8 { supports_check_constraints: bool },
     MariaDb,
 }
 
@@ -23,14 +39,66 @@ impl Flavour {
     fn from_version(version_string: &str) -> Self {
         if is_mariadb(version_string) {
             Self::MariaDb
+        } else if is_mysql_8(version_string) {
+            Self::Mysql8 {
+                supports_check_constraints: mysql_8_supports_check_constraints(version_string),
+            }
         } else {
             Self::Mysql
         }
     }
+
+    /// Invisible indexes, and the `is_visible` column of `information_schema.statistics`, were
+    /// introduced in MySQL 8.
+    fn supports_invisible_indexes(&self) -> bool {
+        matches!(self, Flavour::Mysql8 { .. })
+    }
+
+    /// Enforced CHECK constraints, and `information_schema.check_constraints`, were introduced in
+    /// MySQL 8.0.16. Earlier versions parse and ignore them.
+    fn supports_check_constraints(&self) -> bool {
+        matches!(
+            self,
+            Flavour::Mysql8 {
+                supports_check_constraints: true
+            }
+        )
+    }
+}
+
+/// Whether a MySQL 8 version string (e.g. `8.0.23-0ubuntu0.20.04.1`) is 8.0.16 or later.
+fn mysql_8_supports_check_constraints(version_string: &str) -> bool {
+    let mut parts = version_string
+        .split(|c: char| !c.is_ascii_digit())
+        .map(|part| part.parse::<u32>().unwrap_or(0));
+
+    let (_major, minor, patch) = (parts.next(), parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+
+    minor > 0 || patch >= 16
 }
 
 pub struct SqlSchemaDescriber {
     conn: Quaint,
+    include_ddl: bool,
+}
+
+/// The state of `describe_tables_stream()`: the tables left to describe, and the flavour of the
+/// database.
+struct TablePages {
+    table_names: Vec<String>,
+    flavour: Flavour,
+}
+
+fn table_name_placeholders(table_names: &[String]) -> String {
+    vec!["?"; table_names.len()].join(", ")
+}
+
+fn query_params<'a>(schema_params: &[&'a str], table_names: &'a [String]) -> Vec<Value<'a>> {
+    schema_params
+        .iter()
+        .map(|schema| Value::from(*schema))
+        .chain(table_names.iter().map(|name| name.as_str().into()))
+        .collect()
 }
 
 #[async_trait::async_trait]
@@ -50,31 +118,78 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
-        let version = self.conn.version().await.ok().flatten();
-        let flavour = version
-            .as_ref()
-            .map(|s| Flavour::from_version(s))
-            .unwrap_or(Flavour::Mysql);
+    async fn describe_filtered(
+        &self,
+        schema: &str,
+        table_name_filter: Option<&[String]>,
+    ) -> DescriberResult<SqlSchema> {
+        let flavour = self.flavour().await;
 
-        let table_names = self.get_table_names(schema).await?;
-        let mut tables = Vec::with_capacity(table_names.len());
-        let mut columns = get_all_columns(&self.conn, schema, &flavour).await?;
-        let mut indexes = get_all_indexes(&self.conn, schema).await?;
-        let mut fks = get_foreign_keys(&self.conn, schema).await?;
+        let mut table_names = self.get_table_names(schema).await?;
+
+        if let Some(filter) = table_name_filter {
+            table_names.retain(|table_name| filter.contains(table_name));
+        }
 
+        let mut tables = Vec::with_capacity(table_names.len());
         let mut enums = vec![];
-        for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
-            tables.push(table);
-            enums.extend(enms.iter().cloned());
+
+        for page in table_names.chunks(DEFAULT_PAGE_SIZE) {
+            let (page_tables, page_enums) = self.describe_page(schema, page, &flavour).await?;
+            tables.extend(page_tables);
+            enums.extend(page_enums);
         }
 
         Ok(SqlSchema {
             tables,
             enums,
             sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: Some(SqlSchemaFlavour::Mysql),
+        })
+    }
+
+    async fn list_tables(&self, schema: &str) -> DescriberResult<Vec<String>> {
+        self.get_table_names(schema).await
+    }
+
+    #[tracing::instrument(skip(self, table_names))]
+    async fn describe_tables(&self, schema: &str, table_names: &[String]) -> DescriberResult<(Vec<Table>, Vec<Enum>)> {
+        let flavour = self.flavour().await;
+
+        self.describe_page(schema, table_names, &flavour).await
+    }
+
+    /// The flavour is resolved once for the whole stream, instead of once per page.
+    fn describe_tables_stream<'a>(
+        &'a self,
+        schema: &'a str,
+        page_size: usize,
+    ) -> BoxStream<'a, DescriberResult<Table>> {
+        let page_size = page_size.max(1);
+
+        stream::try_unfold(None, move |pages: Option<TablePages>| async move {
+            let mut pages = match pages {
+                Some(pages) => pages,
+                None => TablePages {
+                    table_names: self.get_table_names(schema).await?,
+                    flavour: self.flavour().await,
+                },
+            };
+
+            if pages.table_names.is_empty() {
+                return Ok(None);
+            }
+
+            let rest = pages.table_names.split_off(page_size.min(pages.table_names.len()));
+            let page = std::mem::replace(&mut pages.table_names, rest);
+            let (tables, _enums) = self.describe_page(schema, &page, &pages.flavour).await?;
+
+            Ok(Some((tables, Some(pages))))
         })
+        .map_ok(|tables| stream::iter(tables.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
     }
 
     #[tracing::instrument(skip(self))]
@@ -86,13 +201,73 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 impl SqlSchemaDescriber {
     /// Constructor.
     pub fn new(conn: Quaint) -> SqlSchemaDescriber {
-        SqlSchemaDescriber { conn }
+        SqlSchemaDescriber {
+            conn,
+            include_ddl: false,
+        }
+    }
+
+    /// Populate the `ddl` of the described tables with the output of `SHOW CREATE TABLE`.
+    pub fn include_ddl(mut self, include_ddl: bool) -> Self {
+        self.include_ddl = include_ddl;
+        self
+    }
+
+    async fn flavour(&self) -> Flavour {
+        let version = self.conn.version().await.ok().flatten();
+
+        version
+            .as_ref()
+            .map(|s| Flavour::from_version(s))
+            .unwrap_or(Flavour::Mysql)
+    }
+
+    /// Describe the given tables, along with the enums defined on their columns.
+    async fn describe_page(
+        &self,
+        schema: &str,
+        table_names: &[String],
+        flavour: &Flavour,
+    ) -> DescriberResult<(Vec<Table>, Vec<Enum>)> {
+        if table_names.is_empty() {
+            return Ok((Vec::new(), Vec::new()));
+        }
+
+        let mut tables = Vec::with_capacity(table_names.len());
+        let mut columns = get_all_columns(&self.conn, schema, table_names, flavour).await?;
+        let mut indexes = get_all_indexes(&self.conn, schema, table_names, flavour).await?;
+        let mut fks = get_foreign_keys(&self.conn, schema, table_names).await?;
+        let mut collations = get_table_collations(&self.conn, schema, table_names).await?;
+        let mut check_constraints = if flavour.supports_check_constraints() {
+            get_check_constraints(&self.conn, schema, table_names).await?
+        } else {
+            HashMap::new()
+        };
+
+        let mut enums = vec![];
+        for table_name in table_names {
+            let (mut table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            table.collation = collations.remove(table_name);
+            table.check_constraints = check_constraints.remove(table_name).unwrap_or_default();
+            table.sort_indexes_and_foreign_keys();
+
+            if self.include_ddl {
+                table.ddl = self.get_ddl(schema, table_name).await?;
+            }
+
+            tables.push(table);
+            enums.extend(enms.iter().cloned());
+        }
+
+        Ok((tables, enums))
     }
 
     #[tracing::instrument(skip(self))]
     async fn get_databases(&self) -> DescriberResult<Vec<String>> {
         let sql = "select schema_name as schema_name from information_schema.schemata;";
-        let rows = self.conn.query_raw(sql, &[]).await?;
+        let rows = self.conn.query_raw(sql, &[]).await.map_err(|err| {
+            DescriberError::from_system_view_query(SqlFamily::Mysql, "information_schema.schemata", err)
+        })?;
         let names = rows
             .into_iter()
             .map(|row| row.get_expect_string("schema_name"))
@@ -110,7 +285,9 @@ impl SqlSchemaDescriber {
             -- Views are not supported yet
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
-        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.map_err(|err| {
+            DescriberError::from_system_view_query(SqlFamily::Mysql, "information_schema.tables", err)
+        })?;
         let names = rows
             .into_iter()
             .map(|row| row.get_expect_string("table_name"))
@@ -121,6 +298,14 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn get_ddl(&self, schema: &str, table_name: &str) -> DescriberResult<Option<String>> {
+        let sql = format!("SHOW CREATE TABLE {}.{}", quote_ident(schema), quote_ident(table_name));
+        let rows = self.conn.query_raw(&sql, &[]).await?;
+
+        Ok(rows.into_iter().next().and_then(|row| row.get_string("Create Table")))
+    }
+
     #[tracing::instrument(skip(self))]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql = r#"
@@ -130,7 +315,9 @@ impl SqlSchemaDescriber {
             WHERE table_schema = ?
         "#;
 
-        let result = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let result = self.conn.query_raw(sql, &[schema.into()]).await.map_err(|err| {
+            DescriberError::from_system_view_query(SqlFamily::Mysql, "information_schema.tables", err)
+        })?;
         let size = result
             .first()
             .and_then(|row| {
@@ -165,6 +352,13 @@ impl SqlSchemaDescriber {
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                ddl: None,
+                collation: None,
+                check_constraints: Vec::new(),
+                policies: Vec::new(),
+                partitioning: None,
+                row_level_security: false,
+                without_rowid: false,
             },
             enums,
         )
@@ -174,12 +368,14 @@ impl SqlSchemaDescriber {
 async fn get_all_columns(
     conn: &dyn Queryable,
     schema_name: &str,
+    table_names: &[String],
     flavour: &Flavour,
 ) -> DescriberResult<HashMap<String, (Vec<Column>, Vec<Enum>)>> {
     // We alias all the columns because MySQL column names are case-insensitive in queries, but the
     // information schema column names became upper-case in MySQL 8, causing the code fetching
     // the result values by column name below to fail.
-    let sql = "
+    let sql = format!(
+        "
             SELECT
                 column_name column_name,
                 data_type data_type,
@@ -194,12 +390,18 @@ async fn get_all_columns(
                 table_name table_name
             FROM information_schema.columns
             WHERE table_schema = ?
+            AND table_name IN ({})
             ORDER BY ordinal_position
-        ";
+        ",
+        table_name_placeholders(table_names)
+    );
 
     let mut map = HashMap::new();
 
-    let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
+    let rows = conn
+        .query_raw(&sql, &query_params(&[schema_name], table_names))
+        .await
+        .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Mysql, "information_schema.columns", err))?;
 
     for col in rows {
         trace!("Got column: {:?}", col);
@@ -245,7 +447,11 @@ async fn get_all_columns(
             default_value,
         );
         let extra = col.get_expect_string("extra").to_lowercase();
-        let auto_increment = matches!(extra.as_str(), "auto_increment");
+        // Invisible columns have `INVISIBLE` next to the other attributes, e.g. `auto_increment INVISIBLE`.
+        let auto_increment = extra.split_whitespace().any(|attribute| attribute == "auto_increment");
+        let invisible = extra.split_whitespace().any(|attribute| attribute == "invisible");
+        // As in `DEFAULT_GENERATED on update CURRENT_TIMESTAMP(3)`, or `on update current_timestamp()` on MariaDB.
+        let on_update_current_timestamp = extra.contains("on update current_timestamp");
 
         let entry = map.entry(table_name).or_insert((Vec::new(), Vec::new()));
 
@@ -306,6 +512,13 @@ async fn get_all_columns(
             tpe,
             default,
             auto_increment,
+            identity: None,
+            identity_options: None,
+            invisible,
+            on_update_current_timestamp,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         };
 
         entry.0.push(col);
@@ -314,28 +527,83 @@ async fn get_all_columns(
     Ok(map)
 }
 
+async fn get_table_collations(
+    conn: &dyn Queryable,
+    schema_name: &str,
+    table_names: &[String],
+) -> DescriberResult<HashMap<String, String>> {
+    let sql = format!(
+        "
+            SELECT table_name AS table_name, table_collation AS table_collation
+            FROM INFORMATION_SCHEMA.TABLES
+            WHERE table_schema = ?
+            AND table_name IN ({})
+            ",
+        table_name_placeholders(table_names)
+    );
+    let rows = conn
+        .query_raw(&sql, &query_params(&[schema_name], table_names))
+        .await
+        .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Mysql, "information_schema.tables", err))?;
+
+    let collations: HashMap<String, String> = rows
+        .into_iter()
+        .filter_map(|row| {
+            let table_name = row.get_expect_string("table_name");
+            let collation = row.get_string("table_collation")?;
+
+            Some((table_name, collation))
+        })
+        .collect();
+
+    trace!("Found table collations: {:?}", collations);
+
+    Ok(collations)
+}
+
 async fn get_all_indexes(
     conn: &dyn Queryable,
     schema_name: &str,
+    table_names: &[String],
+    flavour: &Flavour,
 ) -> DescriberResult<HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>> {
     let mut map = HashMap::new();
     let mut indexes_with_expressions: HashSet<(String, String)> = HashSet::new();
+    // The `(position, column name)` pairs of each index, by table and index name. The
+    // positions are validated once all the rows are read.
+    let mut index_columns: HashMap<(String, String), Vec<(i64, String)>> = HashMap::new();
+    let mut primary_key_columns: HashMap<String, (String, Vec<(i64, String)>)> = HashMap::new();
 
     // We alias all the columns because MySQL column names are case-insensitive in queries, but the
     // information schema column names became upper-case in MySQL 8, causing the code fetching
     // the result values by column name below to fail.
-    let sql = "
+    let sql = format!(
+        "
             SELECT DISTINCT
                 index_name AS index_name,
                 non_unique AS non_unique,
                 column_name AS column_name,
                 seq_in_index AS seq_in_index,
-                table_name AS table_name
+                index_comment AS index_comment,
+                table_name AS table_name{is_visible}
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ?
-            ORDER BY index_name, seq_in_index
-            ";
-    let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
+            AND table_name IN ({table_names})
+            ORDER BY table_name, index_name, seq_in_index
+            ",
+        is_visible = if flavour.supports_invisible_indexes() {
+            ",\n                is_visible AS is_visible"
+        } else {
+            ""
+        },
+        table_names = table_name_placeholders(table_names)
+    );
+    let rows = conn
+        .query_raw(&sql, &query_params(&[schema_name], table_names))
+        .await
+        .map_err(|err| {
+            DescriberError::from_system_view_query(SqlFamily::Mysql, "information_schema.statistics", err)
+        })?;
 
     for row in rows {
         trace!("Got index row: {:#?}", row);
@@ -344,55 +612,59 @@ async fn get_all_indexes(
         match row.get_string("column_name") {
             Some(column_name) => {
                 let seq_in_index = row.get_expect_i64("seq_in_index");
-                let pos = seq_in_index - 1;
                 let is_unique = !row.get_expect_bool("non_unique");
+                let invisible = row
+                    .get_string("is_visible")
+                    .map(|is_visible| is_visible == "NO")
+                    .unwrap_or(false);
+                let comment = row.get_string("index_comment").filter(|comment| !comment.is_empty());
 
                 // Multi-column indices will return more than one row (with different column_name values).
                 // We cannot assume that one row corresponds to one index.
                 let (ref mut indexes_map, ref mut primary_key): &mut (_, Option<PrimaryKey>) = map
-                    .entry(table_name)
+                    .entry(table_name.clone())
                     .or_insert((BTreeMap::<String, Index>::new(), None));
 
                 let is_pk = index_name.to_lowercase() == "primary";
                 if is_pk {
                     trace!("Column '{}' is part of the primary key", column_name);
-                    match primary_key {
-                        Some(pk) => {
-                            if pk.columns.len() < (pos + 1) as usize {
-                                pk.columns.resize((pos + 1) as usize, "".to_string());
-                            }
-                            pk.columns[pos as usize] = column_name;
-                            trace!(
-                                "The primary key has already been created, added column to it: {:?}",
-                                pk.columns
-                            );
-                        }
-                        None => {
-                            trace!("Instantiating primary key");
-
-                            primary_key.replace(PrimaryKey {
-                                columns: vec![column_name],
-                                sequence: None,
-                                constraint_name: None,
-                            });
-                        }
-                    };
-                } else if indexes_map.contains_key(&index_name) {
-                    if let Some(index) = indexes_map.get_mut(&index_name) {
-                        index.columns.push(column_name);
+
+                    if primary_key.is_none() {
+                        trace!("Instantiating primary key");
+
+                        primary_key.replace(PrimaryKey {
+                            columns: Vec::new(),
+                            sequence: None,
+                            constraint_name: None,
+                        });
                     }
+
+                    primary_key_columns
+                        .entry(table_name)
+                        .or_insert_with(|| (index_name, Vec::new()))
+                        .1
+                        .push((seq_in_index, column_name));
                 } else {
-                    indexes_map.insert(
-                        index_name.clone(),
-                        Index {
-                            name: index_name,
-                            columns: vec![column_name],
-                            tpe: match is_unique {
-                                true => IndexType::Unique,
-                                false => IndexType::Normal,
-                            },
+                    index_columns
+                        .entry((table_name, index_name.clone()))
+                        .or_default()
+                        .push((seq_in_index, column_name));
+
+                    indexes_map.entry(index_name.clone()).or_insert_with(|| Index {
+                        name: index_name,
+                        columns: Vec::new(),
+                        tpe: match is_unique {
+                            true => IndexType::Unique,
+                            false => IndexType::Normal,
                         },
-                    );
+                        storage_parameters: Vec::new(),
+                        expressions: Vec::new(),
+                        algorithm: None,
+                        invisible,
+                        comment,
+                        nulls_not_distinct: false,
+                        opclasses: Vec::new(),
+                    });
                 }
             }
             None => {
@@ -409,12 +681,29 @@ async fn get_all_indexes(
         }
     }
 
+    // Put the columns at their position in the index, as given by `SEQ_IN_INDEX`, so the order
+    // of the columns does not depend on the order of the rows.
+    for (table_name, (index_map, primary_key)) in &mut map {
+        if let (Some(pk), Some((index_name, columns))) = (primary_key, primary_key_columns.remove(table_name)) {
+            pk.columns = columns_by_position(table_name, &index_name, columns)?;
+        }
+
+        for index in index_map.values_mut() {
+            let columns = index_columns
+                .remove(&(table_name.clone(), index.name.clone()))
+                .unwrap_or_default();
+
+            index.columns = columns_by_position(table_name, &index.name, columns)?;
+        }
+    }
+
     Ok(map)
 }
 
 async fn get_foreign_keys(
     conn: &dyn Queryable,
     schema_name: &str,
+    table_names: &[String],
 ) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
     // Foreign keys covering multiple columns will return multiple rows, which we need to
     // merge.
@@ -428,7 +717,8 @@ async fn get_foreign_keys(
     // We alias all the columns because MySQL column names are case-insensitive in queries, but the
     // information schema column names became upper-case in MySQL 8, causing the code fetching
     // the result values by column name below to fail.
-    let sql = "
+    let sql = format!(
+        "
         SELECT
             kcu.constraint_name constraint_name,
             kcu.column_name column_name,
@@ -445,10 +735,18 @@ async fn get_foreign_keys(
             kcu.table_schema = ?
             AND rc.constraint_schema = ?
             AND referenced_column_name IS NOT NULL
+            AND kcu.table_name IN ({})
         ORDER BY ordinal_position
-    ";
+    ",
+        table_name_placeholders(table_names)
+    );
 
-    let result_set = conn.query_raw(sql, &[schema_name.into(), schema_name.into()]).await?;
+    let result_set = conn
+        .query_raw(&sql, &query_params(&[schema_name, schema_name], table_names))
+        .await
+        .map_err(|err| {
+            DescriberError::from_system_view_query(SqlFamily::Mysql, "information_schema.key_column_usage", err)
+        })?;
 
     for row in result_set.into_iter() {
         trace!("Got description FK row {:#?}", row);
@@ -497,6 +795,9 @@ async fn get_foreign_keys(
                     referenced_columns: vec![referenced_column],
                     on_delete_action,
                     on_update_action,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 };
                 intermediate_fks.insert(constraint_name, fk);
             }
@@ -517,6 +818,81 @@ async fn get_foreign_keys(
     Ok(fks)
 }
 
+/// The enforced CHECK constraints of the tables, by table name. `NOT ENFORCED` constraints are
+/// left out, since recreating them as enforced constraints could reject existing rows.
+async fn get_check_constraints(
+    conn: &dyn Queryable,
+    schema_name: &str,
+    table_names: &[String],
+) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+    let sql = format!(
+        "
+        SELECT
+            tc.table_name AS table_name,
+            cc.constraint_name AS constraint_name,
+            cc.check_clause AS check_clause
+        FROM information_schema.check_constraints AS cc
+        INNER JOIN information_schema.table_constraints AS tc ON
+            tc.constraint_schema = cc.constraint_schema
+            AND tc.constraint_name = cc.constraint_name
+            AND tc.constraint_type = 'CHECK'
+        WHERE
+            cc.constraint_schema = ?
+            AND tc.enforced = 'YES'
+            AND tc.table_name IN ({})
+        ORDER BY tc.table_name, cc.constraint_name
+        ",
+        table_name_placeholders(table_names)
+    );
+
+    let rows = conn
+        .query_raw(&sql, &query_params(&[schema_name], table_names))
+        .await
+        .map_err(|err| {
+            DescriberError::from_system_view_query(SqlFamily::Mysql, "information_schema.check_constraints", err)
+        })?;
+
+    let mut map: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+    for row in rows {
+        let table_name = row.get_expect_string("table_name");
+        let check_clause = row.get_expect_string("check_clause");
+
+        map.entry(table_name).or_default().push(CheckConstraint {
+            name: Some(row.get_expect_string("constraint_name")),
+            expression: strip_enclosing_parentheses(&check_clause).to_owned(),
+        });
+    }
+
+    trace!("Found check constraints: {:?}", map);
+
+    Ok(map)
+}
+
+/// MySQL wraps check clauses in parentheses, e.g. `(`price` > 0)`.
+fn strip_enclosing_parentheses(expression: &str) -> &str {
+    if expression.len() < 2 || !expression.starts_with('(') || !expression.ends_with(')') {
+        return expression;
+    }
+
+    let inner = &expression[1..expression.len() - 1];
+
+    // Make sure the first parenthesis is closed by the last one, and not earlier as in
+    // `(a > 0) AND (b > 0)`.
+    let mut depth: usize = 0;
+
+    for c in inner.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return expression,
+            ')' => depth -= 1,
+            _ => (),
+        }
+    }
+
+    inner
+}
+
 fn get_column_type_and_enum(
     table: &str,
     column_name: &str,
@@ -640,7 +1016,7 @@ fn get_column_type_and_enum(
             tpe,
             Some(Enum {
                 name: name.clone(),
-                values: extract_enum_values(&full_data_type),
+                values: extract_enum_values(full_data_type),
             }),
         ),
         _ => (tpe, None),
@@ -653,10 +1029,37 @@ fn extract_precision(input: &str) -> Option<u32> {
         .and_then(|cap| cap.get(1).map(|precision| from_str::<u32>(precision.as_str()).unwrap()))
 }
 
-fn extract_enum_values(full_data_type: &&str) -> Vec<String> {
-    let len = &full_data_type.len() - 1;
-    let vals = &full_data_type[5..len];
-    vals.split(',').map(|v| unquote_string(v)).collect()
+/// Extract the values of an inline `enum('a','b')` or `set('a','b')` column type. The values are
+/// quoted string literals, which can themselves contain commas and escaped quotes.
+fn extract_enum_values(full_data_type: &str) -> Vec<String> {
+    let start = full_data_type.find('(').map(|idx| idx + 1).unwrap_or(0);
+    let end = full_data_type.rfind(')').unwrap_or_else(|| full_data_type.len());
+    let mut chars = full_data_type[start..end].chars().peekable();
+    let mut values = Vec::new();
+
+    while let Some(c) = chars.next() {
+        if c != '\'' {
+            continue;
+        }
+
+        let mut value = String::new();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' if chars.peek() == Some(&'\'') => {
+                    chars.next();
+                    value.push('\'');
+                }
+                '\'' => break,
+                '\\' => value.extend(chars.next()),
+                c => value.push(c),
+            }
+        }
+
+        values.push(value);
+    }
+
+    values
 }
 
 // See https://dev.mysql.com/doc/refman/8.0/en/string-literals.html
@@ -689,3 +1092,33 @@ fn default_is_current_timestamp(default_str: &str) -> bool {
 
     MYSQL_CURRENT_TIMESTAMP_RE.is_match(default_str)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quote_ident_escapes_backticks() {
+        assert_eq!(quote_ident("Cat"), "`Cat`");
+        assert_eq!(quote_ident("Cat`s toys"), "`Cat``s toys`");
+    }
+
+    #[test]
+    fn check_constraints_are_supported_from_mysql_8_0_16() {
+        assert!(!mysql_8_supports_check_constraints("8.0.15"));
+        assert!(mysql_8_supports_check_constraints("8.0.16"));
+        assert!(mysql_8_supports_check_constraints("8.0.23-0ubuntu0.20.04.1"));
+        assert!(mysql_8_supports_check_constraints("8.1.0"));
+    }
+
+    #[test]
+    fn strip_enclosing_parentheses_only_strips_matching_parentheses() {
+        assert_eq!(strip_enclosing_parentheses("(`price` > 0)"), "`price` > 0");
+        assert_eq!(strip_enclosing_parentheses("((`a` > 0))"), "(`a` > 0)");
+        assert_eq!(
+            strip_enclosing_parentheses("(`a` > 0) and (`b` > 0)"),
+            "(`a` > 0) and (`b` > 0)"
+        );
+        assert_eq!(strip_enclosing_parentheses("`a` > 0"), "`a` > 0");
+    }
+}
@@ -4,15 +4,32 @@ use super::*;
 use crate::getters::Getter;
 use native_types::{NativeType, PostgresType};
 use quaint::connector::ResultRow;
-use quaint::{prelude::Queryable, single::Quaint};
+use quaint::{
+    prelude::{Queryable, SqlFamily},
+    single::Quaint,
+    Value,
+};
 use regex::Regex;
 use serde_json::from_str;
-use std::{borrow::Cow, collections::HashMap, convert::TryInto};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    convert::TryInto,
+};
 use tracing::trace;
 
 #[derive(Debug)]
 pub struct SqlSchemaDescriber {
     conn: Quaint,
+    include_column_storage: bool,
+}
+
+/// The state of `describe_tables_stream()`: the tables left to describe, and the sequences and
+/// enums they can refer to.
+struct TablePages {
+    table_names: Vec<String>,
+    sequences: Vec<Sequence>,
+    enums: Vec<Enum>,
 }
 
 #[async_trait::async_trait]
@@ -32,27 +49,82 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     }
 
     #[tracing::instrument]
-    async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
+    async fn describe_filtered(
+        &self,
+        schema: &str,
+        table_name_filter: Option<&[String]>,
+    ) -> DescriberResult<SqlSchema> {
         let sequences = self.get_sequences(schema).await?;
         let enums = self.get_enums(schema).await?;
-        let mut columns = self.get_columns(schema, &enums).await?;
-        let mut foreign_keys = self.get_foreign_keys(schema).await?;
-        let mut indexes = self.get_indices(schema, &sequences).await?;
+        let mut table_names = self.get_table_names(schema).await?;
+
+        if let Some(filter) = table_name_filter {
+            table_names.retain(|table_name| filter.contains(table_name));
+        }
 
-        let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
 
-        for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+        for page in table_names.chunks(DEFAULT_PAGE_SIZE) {
+            tables.extend(self.describe_page(schema, page, &sequences, &enums).await?);
         }
 
         Ok(SqlSchema {
             enums,
             sequences,
+            synonyms: Vec::new(),
+            flavour: Some(SqlSchemaFlavour::Postgres),
             tables,
         })
     }
 
+    async fn list_tables(&self, schema: &str) -> DescriberResult<Vec<String>> {
+        self.get_table_names(schema).await
+    }
+
+    #[tracing::instrument(skip(table_names))]
+    async fn describe_tables(&self, schema: &str, table_names: &[String]) -> DescriberResult<(Vec<Table>, Vec<Enum>)> {
+        let sequences = self.get_sequences(schema).await?;
+        let enums = self.get_enums(schema).await?;
+        let tables = self.describe_page(schema, table_names, &sequences, &enums).await?;
+
+        Ok((tables, enums))
+    }
+
+    /// The sequences and enums are fetched once for the whole stream, instead of once per page.
+    fn describe_tables_stream<'a>(
+        &'a self,
+        schema: &'a str,
+        page_size: usize,
+    ) -> BoxStream<'a, DescriberResult<Table>> {
+        let page_size = page_size.max(1);
+
+        stream::try_unfold(None, move |pages: Option<TablePages>| async move {
+            let mut pages = match pages {
+                Some(pages) => pages,
+                None => TablePages {
+                    table_names: self.get_table_names(schema).await?,
+                    sequences: self.get_sequences(schema).await?,
+                    enums: self.get_enums(schema).await?,
+                },
+            };
+
+            if pages.table_names.is_empty() {
+                return Ok(None);
+            }
+
+            let rest = pages.table_names.split_off(page_size.min(pages.table_names.len()));
+            let page = std::mem::replace(&mut pages.table_names, rest);
+            let tables = self
+                .describe_page(schema, &page, &pages.sequences, &pages.enums)
+                .await?;
+
+            Ok(Some((tables, Some(pages))))
+        })
+        .map_ok(|tables| stream::iter(tables.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+    }
+
     #[tracing::instrument]
     async fn version(&self, schema: &str) -> crate::DescriberResult<Option<String>> {
         Ok(self.conn.version().await?)
@@ -62,13 +134,68 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 impl SqlSchemaDescriber {
     /// Constructor.
     pub fn new(conn: Quaint) -> SqlSchemaDescriber {
-        SqlSchemaDescriber { conn }
+        SqlSchemaDescriber {
+            conn,
+            include_column_storage: false,
+        }
+    }
+
+    /// Populate the `storage` of the described columns with their `STORAGE` and `COMPRESSION` settings.
+    pub fn include_column_storage(mut self, include_column_storage: bool) -> Self {
+        self.include_column_storage = include_column_storage;
+        self
+    }
+
+    #[tracing::instrument(skip(table_names, sequences, enums))]
+    async fn describe_page(
+        &self,
+        schema: &str,
+        table_names: &[String],
+        sequences: &[Sequence],
+        enums: &[Enum],
+    ) -> DescriberResult<Vec<Table>> {
+        let mut columns = self.get_columns(schema, table_names, enums).await?;
+
+        if self.include_column_storage {
+            let mut storages = self.get_column_storages(schema, table_names).await?;
+
+            for (table_name, table_columns) in columns.iter_mut() {
+                for column in table_columns.iter_mut() {
+                    column.storage = storages.remove(&(table_name.clone(), column.name.clone()));
+                }
+            }
+        }
+
+        let mut foreign_keys = self.get_foreign_keys(schema, table_names).await?;
+        let mut indexes = self.get_indices(schema, table_names, sequences).await?;
+        let mut policies = self.get_policies(schema, table_names).await?;
+        let mut partitionings = self.get_partitionings(schema, table_names).await?;
+        let row_level_security_tables = self.get_row_level_security_tables(schema, table_names).await?;
+
+        let tables = table_names
+            .iter()
+            .map(|table_name| {
+                self.get_table(
+                    &table_name,
+                    &mut columns,
+                    &mut foreign_keys,
+                    &mut indexes,
+                    &mut policies,
+                    &mut partitionings,
+                    &row_level_security_tables,
+                )
+            })
+            .collect();
+
+        Ok(tables)
     }
 
     #[tracing::instrument]
     async fn get_databases(&self) -> DescriberResult<Vec<String>> {
         let sql = "select schema_name from information_schema.schemata;";
-        let rows = self.conn.query_raw(sql, &[]).await?;
+        let rows = self.conn.query_raw(sql, &[]).await.map_err(|err| {
+            DescriberError::from_system_view_query(SqlFamily::Postgres, "information_schema.schemata", err)
+        })?;
         let names = rows
             .into_iter()
             .map(|row| row.get_expect_string("schema_name"))
@@ -87,7 +214,9 @@ impl SqlSchemaDescriber {
             -- Views are not supported yet
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
-        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.map_err(|err| {
+            DescriberError::from_system_view_query(SqlFamily::Postgres, "information_schema.tables", err)
+        })?;
         let names = rows
             .into_iter()
             .map(|row| row.get_expect_string("table_name"))
@@ -104,7 +233,12 @@ impl SqlSchemaDescriber {
             "SELECT SUM(pg_total_relation_size(quote_ident(schemaname) || '.' || quote_ident(tablename)))::BIGINT as size
              FROM pg_tables
              WHERE schemaname = $1::text";
-        let mut result_iter = self.conn.query_raw(sql, &[schema.into()]).await?.into_iter();
+        let mut result_iter = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Postgres, "pg_tables", err))?
+            .into_iter();
         let size: i64 = result_iter.next().and_then(|row| row.get_i64("size")).unwrap_or(0);
 
         trace!("Found db size: {:?}", size);
@@ -112,27 +246,48 @@ impl SqlSchemaDescriber {
         Ok(size.try_into().expect("size is not a valid usize"))
     }
 
-    #[tracing::instrument(skip(columns, foreign_keys, indices))]
+    #[tracing::instrument(skip(columns, foreign_keys, indices, policies, partitionings))]
     fn get_table(
         &self,
         name: &str,
         columns: &mut HashMap<String, Vec<Column>>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
         indices: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+        policies: &mut HashMap<String, Vec<Policy>>,
+        partitionings: &mut HashMap<String, TablePartitioning>,
+        row_level_security_tables: &HashSet<String>,
     ) -> Table {
         let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
         let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
+        let policies = policies.remove(name).unwrap_or_else(Vec::new);
+        let partitioning = partitionings.remove(name);
         let columns = columns.remove(name).expect("could not get columns");
-        Table {
+        let mut table = Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices,
             primary_key,
-        }
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies,
+            partitioning,
+            row_level_security: row_level_security_tables.contains(name),
+            without_rowid: false,
+        };
+
+        table.sort_indexes_and_foreign_keys();
+
+        table
     }
 
-    async fn get_columns(&self, schema: &str, enums: &[Enum]) -> DescriberResult<HashMap<String, Vec<Column>>> {
+    async fn get_columns(
+        &self,
+        schema: &str,
+        table_names: &[String],
+        enums: &[Enum],
+    ) -> DescriberResult<HashMap<String, Vec<Column>>> {
         let mut columns: HashMap<String, Vec<Column>> = HashMap::new();
 
         let sql = r#"
@@ -146,11 +301,16 @@ impl SqlSchemaDescriber {
                 info.datetime_precision,
                 info.data_type,
                 info.udt_name as full_data_type,
+                info.domain_name,
                 info.column_default,
                 info.is_nullable,
                 info.is_identity,
+                info.identity_generation,
+                info.identity_start,
+                info.identity_increment,
                 info.data_type, 
-                info.character_maximum_length
+                info.character_maximum_length,
+                info.collation_name
             FROM information_schema.columns info
             JOIN pg_attribute  att on att.attname = info.column_name
             And att.attrelid = (
@@ -160,11 +320,18 @@ impl SqlSchemaDescriber {
             	WHERE relname = info.table_name
             	AND pg_namespace.nspname = $1
             	)
-            WHERE table_schema = $1	
+            WHERE table_schema = $1
+            AND info.table_name::text = ANY($2::text[])
             ORDER BY ordinal_position;
         "#;
 
-        let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into(), table_names_array(table_names)])
+            .await
+            .map_err(|err| {
+                DescriberError::from_system_view_query(SqlFamily::Postgres, "information_schema.columns", err)
+            })?;
 
         for col in rows {
             trace!("Got column: {:?}", col);
@@ -179,6 +346,22 @@ impl SqlSchemaDescriber {
                 _ => panic!("unrecognized is_identity variant '{}'", is_identity_str),
             };
 
+            let identity = if is_identity {
+                match col
+                    .get_string("identity_generation")
+                    .map(|s| s.to_uppercase())
+                    .as_deref()
+                {
+                    Some("ALWAYS") => Some(IdentityGeneration::Always),
+                    Some("BY DEFAULT") => Some(IdentityGeneration::ByDefault),
+                    other => panic!("unrecognized identity_generation variant '{:?}'", other),
+                }
+            } else {
+                None
+            };
+
+            let identity_options = identity.and_then(|_| identity_options(&col));
+
             let tpe = get_column_type(&col, enums);
             let default = get_default_value(schema, &col, &tpe);
 
@@ -189,6 +372,13 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                identity,
+                identity_options,
+                invisible: false,
+                on_update_current_timestamp: false,
+                default_constraint_name: None,
+                collation: col.get_string("collation_name"),
+                storage: None,
             };
 
             columns.entry(table_name).or_default().push(col);
@@ -258,7 +448,11 @@ impl SqlSchemaDescriber {
     }
 
     /// Returns a map from table name to foreign keys.
-    async fn get_foreign_keys(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
+    async fn get_foreign_keys(
+        &self,
+        schema: &str,
+        table_names: &[String],
+    ) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
         // The `generate_subscripts` in the inner select is needed because the optimizer is free to reorganize the unnested rows if not explicitly ordered.
         let sql = r#"
             SELECT
@@ -268,6 +462,9 @@ impl SqlSchemaDescriber {
                 att.attname as "parent_column",
                 con.confdeltype,
                 con.confupdtype,
+                con.condeferrable,
+                con.condeferred,
+                con.confmatchtype,
                 conname as constraint_name,
                 child,
                 parent,
@@ -283,13 +480,17 @@ impl SqlSchemaDescriber {
                     con1.conrelid,
                     con1.conname,
                     con1.confdeltype,
-                    con1.confupdtype
+                    con1.confupdtype,
+                    con1.condeferrable,
+                    con1.condeferred,
+                    con1.confmatchtype
                 FROM
                     pg_class cl
                     join pg_namespace ns on cl.relnamespace = ns.oid
                     join pg_constraint con1 on con1.conrelid = cl.oid
                 WHERE
                     ns.nspname = $1
+                    and cl.relname::text = ANY($2::text[])
                     and con1.contype = 'f'
                     ORDER BY colidx
             ) con
@@ -304,7 +505,11 @@ impl SqlSchemaDescriber {
         // One foreign key with multiple columns will be represented here as several
         // rows with the same ID, which we will have to combine into corresponding foreign key
         // objects.
-        let result_set = self.conn.query_raw(&sql, &[schema.into()]).await?;
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[schema.into(), table_names_array(table_names)])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Postgres, "pg_constraint", err))?;
         let mut intermediate_fks: HashMap<i64, (String, ForeignKey)> = HashMap::new();
         for row in result_set.into_iter() {
             trace!("Got description FK row {:?}", row);
@@ -316,6 +521,10 @@ impl SqlSchemaDescriber {
             let confdeltype = row.get_expect_char("confdeltype");
             let confupdtype = row.get_expect_char("confupdtype");
             let constraint_name = row.get_expect_string("constraint_name");
+            let deferrable = row.get_expect_bool("condeferrable");
+            let initially_deferred = row.get_expect_bool("condeferred");
+            // 'f' is MATCH FULL, 's' is MATCH SIMPLE, the default. MATCH PARTIAL is not implemented by Postgres.
+            let match_full = row.get_expect_char("confmatchtype") == 'f';
 
             let on_delete_action = match confdeltype {
                 'a' => ForeignKeyAction::NoAction,
@@ -346,6 +555,9 @@ impl SqlSchemaDescriber {
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
+                        deferrable,
+                        initially_deferred,
+                        match_full,
                     };
                     intermediate_fks.insert(id, (table_name, fk));
                 }
@@ -378,6 +590,7 @@ impl SqlSchemaDescriber {
     async fn get_indices(
         &self,
         schema: &str,
+        table_names: &[String],
         sequences: &[Sequence],
     ) -> DescriberResult<HashMap<String, (Vec<Index>, Option<PrimaryKey>)>> {
         let mut indexes_map = HashMap::new();
@@ -386,15 +599,28 @@ impl SqlSchemaDescriber {
         SELECT
             indexInfos.relname as name,
             columnInfos.attname AS column_name,
+            -- expression keys have no column, only their definition
+            CASE WHEN columnInfos.attname IS NULL
+                THEN pg_get_indexdef(rawIndex.indexrelid, rawIndex.indkeyidx + 1, true)
+            END AS expression,
             rawIndex.indisunique AS is_unique,
             rawIndex.indisprimary AS is_primary_key,
+            rawIndex.nulls_not_distinct,
             tableInfos.relname AS table_name,
             rawIndex.indkeyidx,
+            array_to_string(indexInfos.reloptions, ',') AS storage_parameters,
+            indexMethod.amname AS algorithm,
+            -- only the operator classes that are not the default for the key type
+            CASE WHEN NOT opclassInfos.opcdefault THEN opclassInfos.opcname END AS opclass,
             pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name
         FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
             pg_class tableInfos,
             pg_class indexInfos,
+            -- pg_am stores the index methods: https://www.postgresql.org/docs/current/catalog-pg-am.html
+            pg_am indexMethod,
+            -- pg_namespace stores info about the schema
+            pg_namespace schemaInfo,
             -- pg_index stores indices: https://www.postgresql.org/docs/current/catalog-pg-index.html
             (
                 SELECT
@@ -402,49 +628,84 @@ impl SqlSchemaDescriber {
                     indexrelid,
                     indisunique,
                     indisprimary,
+                    -- indnullsnotdistinct only exists from Postgres 15 on, so we go through jsonb
+                    -- to get NULL instead of an error on older versions.
+                    COALESCE((to_jsonb(pg_index) ->> 'indnullsnotdistinct')::boolean, false) AS nulls_not_distinct,
                     pg_index.indkey AS indkey,
+                    pg_index.indclass AS indclass,
                     generate_subscripts(pg_index.indkey, 1) AS indkeyidx
                 FROM pg_index
                 -- ignores partial indexes
                 Where indpred is Null
-                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey
+                GROUP BY indrelid, indexrelid, indisunique, indisprimary, nulls_not_distinct, indkeyidx, indkey, indclass
                 ORDER BY indrelid, indexrelid, indkeyidx
-            ) rawIndex,
+            ) rawIndex
             -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
-            pg_attribute columnInfos,
-            -- pg_namespace stores info about the schema
-            pg_namespace schemaInfo
+            -- expression keys are stored as 0 in indkey, and have no matching column
+            LEFT JOIN pg_attribute columnInfos
+                ON columnInfos.attrelid = rawIndex.indrelid
+                AND columnInfos.attnum = rawIndex.indkey[rawIndex.indkeyidx]
+            -- pg_opclass stores the operator classes: https://www.postgresql.org/docs/current/catalog-pg-opclass.html
+            LEFT JOIN pg_opclass opclassInfos
+                ON opclassInfos.oid = rawIndex.indclass[rawIndex.indkeyidx]
         WHERE
             -- find table info for index
             tableInfos.oid = rawIndex.indrelid
             -- find index info
             AND indexInfos.oid = rawIndex.indexrelid
-            -- find table columns
-            AND columnInfos.attrelid = tableInfos.oid
-            AND columnInfos.attnum = rawIndex.indkey[rawIndex.indkeyidx]
-            -- we only consider ordinary tables
-            AND tableInfos.relkind = 'r'
+            -- find the method of the index
+            AND indexMethod.oid = indexInfos.relam
+            -- we only consider ordinary and partitioned tables
+            AND tableInfos.relkind IN ('r', 'p')
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
-        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
+            AND tableInfos.relname::text = ANY($2::text[])
+        GROUP BY tableInfos.relname, indexInfos.relname, indexInfos.reloptions, indexMethod.amname, rawIndex.indexrelid, rawIndex.indisunique, rawIndex.indisprimary, rawIndex.nulls_not_distinct, columnInfos.attname, rawIndex.indkeyidx, opclassInfos.opcname, opclassInfos.opcdefault
         ORDER BY rawIndex.indkeyidx
         "#;
 
-        let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into(), table_names_array(table_names)])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Postgres, "pg_index", err))?;
 
         for index in rows {
             trace!("Got index: {:?}", index);
             let IndexRow {
                 column_name,
+                expression,
                 is_primary_key,
                 is_unique,
                 name,
+                nulls_not_distinct,
+                opclass,
                 sequence_name,
+                storage_parameters,
                 table_name,
+                algorithm,
             } = quaint::serde::from_row::<IndexRow>(index).unwrap();
 
+            let key = match (column_name, expression) {
+                (Some(column_name), _) => IndexKeyRow::Column(column_name),
+                (None, Some(expression)) => IndexKeyRow::Expression(expression),
+                (None, None) => {
+                    trace!(
+                        "Skipping index `{}`, which has a key without column nor expression.",
+                        name
+                    );
+                    continue;
+                }
+            };
+
             if is_primary_key {
+                // Primary keys cannot be defined on expressions.
+                let column_name = match key {
+                    IndexKeyRow::Column(column_name) => column_name,
+                    IndexKeyRow::Expression(_) => continue,
+                };
+
                 let entry: &mut (Vec<_>, Option<PrimaryKey>) =
                     indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
 
@@ -472,17 +733,43 @@ impl SqlSchemaDescriber {
             } else {
                 let entry: &mut (Vec<Index>, _) = indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
 
-                if let Some(existing_index) = entry.0.iter_mut().find(|idx| idx.name == name) {
-                    existing_index.columns.push(column_name);
-                } else {
-                    entry.0.push(Index {
-                        name,
-                        columns: vec![column_name],
-                        tpe: match is_unique {
-                            true => IndexType::Unique,
-                            false => IndexType::Normal,
-                        },
-                    })
+                let index = match entry.0.iter().position(|idx| idx.name == name) {
+                    Some(position) => &mut entry.0[position],
+                    None => {
+                        entry.0.push(Index {
+                            name,
+                            columns: Vec::new(),
+                            tpe: match is_unique {
+                                true => IndexType::Unique,
+                                false => IndexType::Normal,
+                            },
+                            storage_parameters: storage_parameters
+                                .map(|params| params.split(',').map(String::from).collect())
+                                .unwrap_or_default(),
+                            expressions: Vec::new(),
+                            // B-tree is the default index method.
+                            algorithm: Some(algorithm).filter(|algorithm| algorithm != "btree"),
+                            invisible: false,
+                            comment: None,
+                            nulls_not_distinct,
+                            opclasses: Vec::new(),
+                        });
+
+                        entry.0.last_mut().unwrap()
+                    }
+                };
+
+                let position = index.columns.len() + index.expressions.len();
+
+                if let Some(name) = opclass {
+                    index.opclasses.push(IndexOpclass { position, name });
+                }
+
+                match key {
+                    IndexKeyRow::Column(column_name) => index.columns.push(column_name),
+                    IndexKeyRow::Expression(expression) => {
+                        index.expressions.push(IndexExpression { position, expression })
+                    }
                 }
             }
         }
@@ -492,10 +779,29 @@ impl SqlSchemaDescriber {
 
     #[tracing::instrument]
     async fn get_sequences(&self, schema: &str) -> DescriberResult<Vec<Sequence>> {
-        let sql = "SELECT start_value, sequence_name
-                  FROM information_schema.sequences
-                  WHERE sequence_schema = $1";
-        let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
+        // A sequence is owned by a column if it has an automatic (serial or `OWNED BY`) or
+        // internal (identity) dependency on it.
+        let sql = r#"
+            SELECT
+                seq.start_value,
+                seq.sequence_name,
+                EXISTS (
+                    SELECT 1
+                    FROM pg_depend dep
+                    JOIN pg_class cl ON cl.oid = dep.objid
+                    JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+                    WHERE cl.relkind = 'S'
+                    AND cl.relname = seq.sequence_name
+                    AND ns.nspname = seq.sequence_schema
+                    AND dep.refclassid = 'pg_class'::regclass
+                    AND dep.deptype IN ('a', 'i')
+                ) AS owned
+            FROM information_schema.sequences seq
+            WHERE seq.sequence_schema = $1
+        "#;
+        let rows = self.conn.query_raw(&sql, &[schema.into()]).await.map_err(|err| {
+            DescriberError::from_system_view_query(SqlFamily::Postgres, "information_schema.sequences", err)
+        })?;
         let sequences = rows
             .into_iter()
             .map(|seq| {
@@ -511,6 +817,7 @@ impl SqlSchemaDescriber {
                     allocation_size: 1,
                     initial_value,
                     name: seq.get_expect_string("sequence_name"),
+                    owned: seq.get_expect_bool("owned"),
                 }
             })
             .collect();
@@ -519,6 +826,277 @@ impl SqlSchemaDescriber {
         Ok(sequences)
     }
 
+    #[tracing::instrument(skip(table_names))]
+    async fn get_policies(
+        &self,
+        schema: &str,
+        table_names: &[String],
+    ) -> DescriberResult<HashMap<String, Vec<Policy>>> {
+        // `polpermissive` only exists since Postgres 10, where restrictive
+        // policies were introduced. Reading it through `to_jsonb` makes it NULL
+        // on older versions.
+        let sql = r#"
+            SELECT
+                tableInfos.relname AS table_name,
+                policies.polname AS name,
+                policies.polcmd::text AS command,
+                COALESCE((to_jsonb(policies) ->> 'polpermissive')::boolean, true) AS permissive,
+                array_to_string(
+                    ARRAY(
+                        SELECT CASE WHEN role_oid = 0 THEN 'public' ELSE pg_get_userbyid(role_oid)::text END
+                        FROM unnest(policies.polroles) AS role_oid
+                    ),
+                    ','
+                ) AS roles,
+                pg_get_expr(policies.polqual, policies.polrelid) AS using_expression,
+                pg_get_expr(policies.polwithcheck, policies.polrelid) AS with_check_expression
+            FROM pg_policy policies
+            INNER JOIN pg_class tableInfos ON tableInfos.oid = policies.polrelid
+            INNER JOIN pg_namespace schemaInfo ON schemaInfo.oid = tableInfos.relnamespace
+            WHERE schemaInfo.nspname = $1
+                AND tableInfos.relname::text = ANY($2::text[])
+            ORDER BY tableInfos.relname, policies.polname
+        "#;
+
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into(), table_names_array(table_names)])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Postgres, "pg_policy", err))?;
+
+        let mut policies: HashMap<String, Vec<Policy>> = HashMap::new();
+
+        for row in rows {
+            trace!("Got policy: {:?}", row);
+
+            let command = match row.get_expect_string("command").as_str() {
+                "r" => PolicyCommand::Select,
+                "a" => PolicyCommand::Insert,
+                "w" => PolicyCommand::Update,
+                "d" => PolicyCommand::Delete,
+                _ => PolicyCommand::All,
+            };
+
+            let roles = row
+                .get_string("roles")
+                .map(|roles| {
+                    roles
+                        .split(',')
+                        .filter(|role| !role.is_empty())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or_else(Vec::new);
+
+            let policy = Policy {
+                name: row.get_expect_string("name"),
+                command,
+                permissive: row.get_expect_bool("permissive"),
+                roles,
+                using: row.get_string("using_expression"),
+                with_check: row.get_string("with_check_expression"),
+            };
+
+            policies
+                .entry(row.get_expect_string("table_name"))
+                .or_insert_with(Vec::new)
+                .push(policy);
+        }
+
+        trace!("Found policies: {:?}", policies);
+
+        Ok(policies)
+    }
+
+    #[tracing::instrument(skip(table_names))]
+    async fn get_row_level_security_tables(
+        &self,
+        schema: &str,
+        table_names: &[String],
+    ) -> DescriberResult<HashSet<String>> {
+        // `relrowsecurity` only exists since Postgres 9.5. Reading it through `to_jsonb` makes it
+        // NULL on older versions.
+        let sql = r#"
+            SELECT tableInfos.relname AS table_name
+            FROM pg_class tableInfos
+            INNER JOIN pg_namespace schemaInfo ON schemaInfo.oid = tableInfos.relnamespace
+            WHERE schemaInfo.nspname = $1
+                AND tableInfos.relname::text = ANY($2::text[])
+                AND COALESCE((to_jsonb(tableInfos) ->> 'relrowsecurity')::boolean, false)
+        "#;
+
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into(), table_names_array(table_names)])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Postgres, "pg_class", err))?;
+
+        let tables: HashSet<String> = rows
+            .into_iter()
+            .map(|row| row.get_expect_string("table_name"))
+            .collect();
+
+        trace!("Found tables with row-level security: {:?}", tables);
+
+        Ok(tables)
+    }
+
+    #[tracing::instrument(skip(table_names))]
+    async fn get_partitionings(
+        &self,
+        schema: &str,
+        table_names: &[String],
+    ) -> DescriberResult<HashMap<String, TablePartitioning>> {
+        let mut partitionings = HashMap::new();
+
+        // Declarative partitioning, and `pg_partitioned_table`, only exist since Postgres 10.
+        if self.get_version_num().await? < 100_000 {
+            return Ok(partitionings);
+        }
+
+        let sql = r#"
+            SELECT
+                tableInfos.relname AS table_name,
+                partitionInfos.partstrat::text AS strategy,
+                pg_get_partkeydef(tableInfos.oid) AS key_definition
+            FROM pg_partitioned_table partitionInfos
+            INNER JOIN pg_class tableInfos ON tableInfos.oid = partitionInfos.partrelid
+            INNER JOIN pg_namespace schemaInfo ON schemaInfo.oid = tableInfos.relnamespace
+            WHERE schemaInfo.nspname = $1
+                AND tableInfos.relname::text = ANY($2::text[])
+        "#;
+
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into(), table_names_array(table_names)])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Postgres, "pg_partitioned_table", err))?;
+
+        for row in rows {
+            trace!("Got partitioned table: {:?}", row);
+
+            let strategy = match row.get_expect_string("strategy").as_str() {
+                "r" => PartitionStrategy::Range,
+                "l" => PartitionStrategy::List,
+                "h" => PartitionStrategy::Hash,
+                other => {
+                    trace!("Skipping partitioned table with unknown strategy `{}`.", other);
+                    continue;
+                }
+            };
+
+            // The definition looks like `RANGE (created_at)`.
+            let key_definition = row.get_expect_string("key_definition");
+            let key = match (key_definition.find('('), key_definition.rfind(')')) {
+                (Some(start), Some(end)) if start < end => key_definition[start + 1..end].to_owned(),
+                _ => key_definition.clone(),
+            };
+
+            partitionings.insert(row.get_expect_string("table_name"), TablePartitioning { strategy, key });
+        }
+
+        Ok(partitionings)
+    }
+
+    #[tracing::instrument(skip(table_names))]
+    async fn get_column_storages(
+        &self,
+        schema: &str,
+        table_names: &[String],
+    ) -> DescriberResult<HashMap<(String, String), ColumnStorage>> {
+        // `attcompression` only exists since Postgres 14.
+        let compression = if self.get_version_num().await? >= 140_000 {
+            "attributeInfos.attcompression::text"
+        } else {
+            "NULL::text"
+        };
+
+        let sql = format!(
+            r#"
+            SELECT
+                tableInfos.relname AS table_name,
+                attributeInfos.attname AS column_name,
+                attributeInfos.attstorage::text AS storage,
+                typeInfos.typstorage::text AS type_storage,
+                {compression} AS compression
+            FROM pg_attribute attributeInfos
+            INNER JOIN pg_class tableInfos ON tableInfos.oid = attributeInfos.attrelid
+            INNER JOIN pg_namespace schemaInfo ON schemaInfo.oid = tableInfos.relnamespace
+            INNER JOIN pg_type typeInfos ON typeInfos.oid = attributeInfos.atttypid
+            WHERE schemaInfo.nspname = $1
+                AND tableInfos.relname::text = ANY($2::text[])
+                AND attributeInfos.attnum > 0
+                AND NOT attributeInfos.attisdropped
+            "#,
+            compression = compression
+        );
+
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into(), table_names_array(table_names)])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Postgres, "pg_attribute", err))?;
+
+        let mut storages = HashMap::new();
+
+        for row in rows {
+            trace!("Got column storage: {:?}", row);
+
+            let storage = row.get_expect_string("storage");
+
+            // Only the settings that differ from the defaults of the type are described.
+            let strategy = if Some(&storage) == row.get_string("type_storage").as_ref() {
+                None
+            } else {
+                match storage.as_str() {
+                    "p" => Some(StorageStrategy::Plain),
+                    "e" => Some(StorageStrategy::External),
+                    "x" => Some(StorageStrategy::Extended),
+                    "m" => Some(StorageStrategy::Main),
+                    other => {
+                        trace!("Skipping unknown column storage `{}`.", other);
+                        None
+                    }
+                }
+            };
+
+            let compression = match row.get_string("compression").as_deref() {
+                Some("p") => Some("pglz".to_owned()),
+                Some("l") => Some("lz4".to_owned()),
+                _ => None,
+            };
+
+            if strategy.is_none() && compression.is_none() {
+                continue;
+            }
+
+            storages.insert(
+                (
+                    row.get_expect_string("table_name"),
+                    row.get_expect_string("column_name"),
+                ),
+                ColumnStorage { strategy, compression },
+            );
+        }
+
+        Ok(storages)
+    }
+
+    async fn get_version_num(&self) -> DescriberResult<i64> {
+        Ok(self
+            .conn
+            .query_raw(
+                "SELECT current_setting('server_version_num')::integer AS version_num",
+                &[],
+            )
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Postgres, "server_version_num", err))?
+            .into_iter()
+            .next()
+            .and_then(|row| row.get_i64("version_num"))
+            .unwrap_or(0))
+    }
+
     #[tracing::instrument]
     async fn get_enums(&self, schema: &str) -> DescriberResult<Vec<Enum>> {
         let sql = "
@@ -529,7 +1107,11 @@ impl SqlSchemaDescriber {
             WHERE n.nspname = $1
             ORDER BY e.enumsortorder";
 
-        let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into()])
+            .await
+            .map_err(|err| DescriberError::from_system_view_query(SqlFamily::Postgres, "pg_enum", err))?;
         let mut enum_values: HashMap<String, Vec<String>> = HashMap::new();
 
         for row in rows.into_iter() {
@@ -554,14 +1136,37 @@ impl SqlSchemaDescriber {
     }
 }
 
+fn table_names_array(table_names: &[String]) -> Value<'_> {
+    Value::Array(Some(table_names.iter().map(|name| name.as_str().into()).collect()))
+}
+
 #[derive(Deserialize)]
 struct IndexRow {
     name: String,
-    column_name: String,
+    column_name: Option<String>,
+    expression: Option<String>,
     is_unique: bool,
     is_primary_key: bool,
+    nulls_not_distinct: bool,
+    opclass: Option<String>,
     table_name: String,
     sequence_name: Option<String>,
+    storage_parameters: Option<String>,
+    algorithm: String,
+}
+
+enum IndexKeyRow {
+    Column(String),
+    Expression(String),
+}
+
+/// The sequence options of an identity column, unless they are the defaults (`START WITH 1
+/// INCREMENT BY 1`). `information_schema.columns` returns them as strings.
+fn identity_options(col: &ResultRow) -> Option<IdentityOptions> {
+    let start = col.get_string("identity_start")?.parse().ok()?;
+    let increment = col.get_string("identity_increment")?.parse().ok()?;
+
+    Some(IdentityOptions { start, increment }).filter(|options| options.start != 1 || options.increment != 1)
 }
 
 fn get_default_value(schema: &str, col: &ResultRow, tpe: &ColumnType) -> Option<DefaultValue> {
@@ -572,6 +1177,11 @@ fn get_default_value(schema: &str, col: &ResultRow, tpe: &ColumnType) -> Option<
         Some(param_value) => match param_value.to_string() {
             None => None,
             Some(x) if x.starts_with("NULL") => None,
+            Some(default_string) if tpe.arity.is_list() => Some(
+                parse_array_default(&default_string, &tpe.family)
+                    .map(|values| DefaultValue::VALUE(PrismaValue::List(values)))
+                    .unwrap_or_else(move || DefaultValue::DBGENERATED(default_string)),
+            ),
             Some(default_string) => {
                 Some(match &tpe.family {
                     ColumnTypeFamily::Int => match parse_int(&default_string) {
@@ -646,7 +1256,9 @@ fn get_default_value(schema: &str, col: &ResultRow, tpe: &ColumnType) -> Option<
 fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
     use ColumnTypeFamily::*;
     let data_type = row.get_expect_string("data_type");
+    // For columns of a domain type, `full_data_type` is the base type of the domain.
     let full_data_type = row.get_expect_string("full_data_type");
+    let domain_name = row.get_string("domain_name");
     let is_required = match row.get_expect_string("is_nullable").to_lowercase().as_ref() {
         "no" => true,
         "yes" => false,
@@ -704,8 +1316,11 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
         "timetz" | "_timetz" => (DateTime, Some(PostgresType::Timetz(precision.time_precision))),
         "timestamp" | "_timestamp" => (DateTime, Some(PostgresType::Timestamp(precision.time_precision))),
         "timestamptz" | "_timestamptz" => (DateTime, Some(PostgresType::Timestamptz(precision.time_precision))),
-        "tsquery" | "_tsquery" => unsupported_type(),
-        "tsvector" | "_tsvector" => unsupported_type(),
+        // There is no interval native type yet, but the full data type is preserved for rendering.
+        "interval" | "_interval" => (Unsupported("interval".to_owned()), None),
+        // There are no full-text search native types yet, but the full data type is preserved for rendering.
+        "tsquery" | "_tsquery" => (Unsupported("tsquery".to_owned()), None),
+        "tsvector" | "_tsvector" => (Unsupported("tsvector".to_owned()), None),
         "txid_snapshot" | "_txid_snapshot" => unsupported_type(),
         "inet" | "_inet" => (String, None),
         //geometric
@@ -720,7 +1335,7 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
 
     ColumnType {
         data_type: data_type.to_owned(),
-        full_data_type: full_data_type.to_owned(),
+        full_data_type: domain_name.unwrap_or(full_data_type),
         character_maximum_length: precision.character_maximum_length.map(|l| l as i64),
         family,
         arity,
@@ -787,6 +1402,70 @@ fn unsuffix_default_literal<'a>(literal: &'a str, data_type: &str, full_data_typ
     Some(first_capture.into())
 }
 
+/// Parse the default of an array column. Postgres gives them back either as
+/// array literals (`'{1,2,3}'::integer[]`) or as array constructors
+/// (`ARRAY[1, 2, 3]`, `ARRAY['a'::text, 'b'::text]`). Only arrays of simple
+/// scalar values are parsed, the others are database-generated defaults.
+fn parse_array_default(default_string: &str, family: &ColumnTypeFamily) -> Option<Vec<PrismaValue>> {
+    static ARRAY_LITERAL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?s)^'\{(.*?)\}'(::.*)?$"#).unwrap());
+    static ARRAY_CONSTRUCTOR_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?s)^ARRAY\[(.*?)\](::.*)?$"#).unwrap());
+
+    let (elements, quoted) = if let Some(captures) = ARRAY_LITERAL_RE.captures(default_string) {
+        (captures.get(1).unwrap().as_str(), false)
+    } else if let Some(captures) = ARRAY_CONSTRUCTOR_RE.captures(default_string) {
+        (captures.get(1).unwrap().as_str(), true)
+    } else {
+        return None;
+    };
+
+    if elements.trim().is_empty() {
+        return Some(Vec::new());
+    }
+
+    elements
+        .split(',')
+        .map(|element| {
+            let element = element.trim();
+            // Strip the casts in `'a'::text`.
+            let element = match element.rfind("::") {
+                Some(idx) if !element[idx..].contains('\'') => &element[..idx],
+                _ => element,
+            };
+
+            parse_array_element(element, family, quoted)
+        })
+        .collect()
+}
+
+fn parse_array_element(element: &str, family: &ColumnTypeFamily, quoted: bool) -> Option<PrismaValue> {
+    match family {
+        ColumnTypeFamily::Int => parse_int(element),
+        ColumnTypeFamily::BigInt => parse_big_int(element),
+        ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => parse_float(element),
+        ColumnTypeFamily::Boolean => match element {
+            "t" => Some(PrismaValue::Boolean(true)),
+            "f" => Some(PrismaValue::Boolean(false)),
+            _ => parse_bool(element),
+        },
+        // Quotes and commas inside of string elements are not handled.
+        ColumnTypeFamily::String if quoted => {
+            if element.len() < 2 || !element.starts_with('\'') || !element.ends_with('\'') {
+                return None;
+            }
+
+            let inner = &element[1..element.len() - 1];
+
+            if inner.contains('\'') {
+                return None;
+            }
+
+            Some(PrismaValue::String(inner.to_owned()))
+        }
+        ColumnTypeFamily::String if !element.contains('"') => Some(PrismaValue::String(element.to_owned())),
+        _ => None,
+    }
+}
+
 // See https://www.postgresql.org/docs/9.3/sql-syntax-lexical.html
 fn process_string_literal(literal: &str) -> Cow<'_, str> {
     static POSTGRES_STRING_DEFAULT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?ms)^B?'(.*)'$"#).unwrap());
@@ -0,0 +1,94 @@
+//! Versioned JSON representation of `SqlSchema`, for tools consuming describer output.
+
+use crate::SqlSchema;
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt};
+
+/// The version of the JSON representation produced by `SqlSchema::to_versioned_json()`. It must
+/// be bumped on every change to the serialized shape of `SqlSchema`.
+pub const SQL_SCHEMA_JSON_VERSION: u32 = 22;
+
+#[derive(Serialize)]
+struct VersionedSqlSchemaRef<'a> {
+    version: u32,
+    schema: &'a SqlSchema,
+}
+
+#[derive(Deserialize)]
+struct VersionedSqlSchema {
+    schema: SqlSchema,
+}
+
+#[derive(Deserialize)]
+struct VersionTag {
+    version: u32,
+}
+
+/// Errors reading a versioned `SqlSchema` JSON document.
+#[derive(Debug)]
+pub enum SqlSchemaJsonError {
+    /// The document was serialized with another version of the representation.
+    VersionMismatch {
+        /// The version this crate reads and writes.
+        expected: u32,
+        /// The version found in the document.
+        found: u32,
+    },
+    /// The document is not valid JSON, or does not match the expected shape.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for SqlSchemaJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqlSchemaJsonError::VersionMismatch { expected, found } => write!(
+                f,
+                "Unsupported SQL schema JSON version. Expected version {}, found version {}.",
+                expected, found
+            ),
+            SqlSchemaJsonError::Json(err) => write!(f, "Invalid SQL schema JSON: {}", err),
+        }
+    }
+}
+
+impl Error for SqlSchemaJsonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SqlSchemaJsonError::VersionMismatch { .. } => None,
+            SqlSchemaJsonError::Json(err) => Some(err),
+        }
+    }
+}
+
+impl From<serde_json::Error> for SqlSchemaJsonError {
+    fn from(err: serde_json::Error) -> Self {
+        SqlSchemaJsonError::Json(err)
+    }
+}
+
+impl SqlSchema {
+    /// Serialize the schema to JSON, tagged with `SQL_SCHEMA_JSON_VERSION`.
+    pub fn to_versioned_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(&VersionedSqlSchemaRef {
+            version: SQL_SCHEMA_JSON_VERSION,
+            schema: self,
+        })
+    }
+
+    /// Read a schema serialized with `to_versioned_json()`. The version tag is checked before the
+    /// rest of the document, so documents from other versions fail with `VersionMismatch`.
+    pub fn from_versioned_json(json: &str) -> Result<SqlSchema, SqlSchemaJsonError> {
+        let VersionTag { version } = serde_json::from_str(json)?;
+
+        if version != SQL_SCHEMA_JSON_VERSION {
+            return Err(SqlSchemaJsonError::VersionMismatch {
+                expected: SQL_SCHEMA_JSON_VERSION,
+                found: version,
+            });
+        }
+
+        let VersionedSqlSchema { schema } = serde_json::from_str(json)?;
+
+        Ok(schema)
+    }
+}
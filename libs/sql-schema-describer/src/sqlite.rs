@@ -7,6 +7,7 @@ use tracing::trace;
 #[derive(Debug)]
 pub struct SqlSchemaDescriber {
     conn: Quaint,
+    include_ddl: bool,
 }
 
 #[async_trait::async_trait]
@@ -26,12 +27,40 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     }
 
     #[tracing::instrument]
-    async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
-        let table_names: Vec<String> = self.get_table_names(schema).await?;
+    async fn describe_filtered(
+        &self,
+        schema: &str,
+        table_name_filter: Option<&[String]>,
+    ) -> DescriberResult<SqlSchema> {
+        let tables = describe_tables_in_pages(self, schema, DEFAULT_PAGE_SIZE, table_name_filter)
+            .try_collect()
+            .await?;
 
+        Ok(SqlSchema {
+            // There's no enum type in SQLite.
+            enums: vec![],
+            // There are no sequences in SQLite.
+            sequences: vec![],
+            synonyms: Vec::new(),
+            flavour: Some(SqlSchemaFlavour::Sqlite),
+            tables,
+        })
+    }
+
+    async fn list_tables(&self, schema: &str) -> DescriberResult<Vec<String>> {
+        let table_names = self.get_table_names(schema).await?;
+
+        Ok(table_names
+            .into_iter()
+            .filter(|table| !is_system_table(&table))
+            .collect())
+    }
+
+    #[tracing::instrument(skip(table_names))]
+    async fn describe_tables(&self, schema: &str, table_names: &[String]) -> DescriberResult<(Vec<Table>, Vec<Enum>)> {
         let mut tables = Vec::with_capacity(table_names.len());
 
-        for table_name in table_names.iter().filter(|table| !is_system_table(&table)) {
+        for table_name in table_names {
             tables.push(self.get_table(schema, table_name).await?)
         }
 
@@ -40,9 +69,17 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         for (table_index, table) in tables.iter().enumerate() {
             for (fk_index, foreign_key) in table.foreign_keys.iter().enumerate() {
                 if foreign_key.referenced_columns.is_empty() {
-                    let referenced_table = tables.iter().find(|t| t.name == foreign_key.referenced_table).unwrap();
-                    let referenced_pk = referenced_table.primary_key.as_ref().unwrap();
-                    foreign_keys_without_referenced_columns.push((table_index, fk_index, referenced_pk.columns.clone()))
+                    let referenced_pk = match tables.iter().find(|t| t.name == foreign_key.referenced_table) {
+                        Some(referenced_table) => referenced_table.primary_key.clone(),
+                        // The referenced table is not part of this page.
+                        None => self.get_columns(&foreign_key.referenced_table).await?.1,
+                    };
+
+                    foreign_keys_without_referenced_columns.push((
+                        table_index,
+                        fk_index,
+                        referenced_pk.unwrap().columns,
+                    ))
                 }
             }
         }
@@ -51,13 +88,7 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables[table_index].foreign_keys[fk_index].referenced_columns = columns
         }
 
-        Ok(SqlSchema {
-            // There's no enum type in SQLite.
-            enums: vec![],
-            // There are no sequences in SQLite.
-            sequences: vec![],
-            tables,
-        })
+        Ok((tables, Vec::new()))
     }
 
     #[tracing::instrument]
@@ -69,7 +100,16 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 impl SqlSchemaDescriber {
     /// Constructor.
     pub fn new(conn: Quaint) -> SqlSchemaDescriber {
-        SqlSchemaDescriber { conn }
+        SqlSchemaDescriber {
+            conn,
+            include_ddl: false,
+        }
+    }
+
+    /// Populate the `ddl` of the described tables with their definition in `sqlite_master`.
+    pub fn include_ddl(mut self, include_ddl: bool) -> Self {
+        self.include_ddl = include_ddl;
+        self
     }
 
     #[tracing::instrument]
@@ -125,14 +165,52 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(name).await?;
         let foreign_keys = self.get_foreign_keys(name).await?;
         let indices = self.get_indices(name).await?;
+        // SQLite only exposes CHECK constraints through the table definition.
+        let definition = self.get_ddl(name).await?;
+        let check_constraints = definition.as_deref().map(parse_check_constraints).unwrap_or_default();
+        let without_rowid = definition.as_deref().map(is_without_rowid).unwrap_or(false);
+        let ddl = if self.include_ddl { definition } else { None };
 
-        Ok(Table {
+        let mut table = Table {
             name: name.to_string(),
             columns,
             indices,
             primary_key,
             foreign_keys,
-        })
+            ddl,
+            collation: None,
+            check_constraints,
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid,
+        };
+
+        table.sort_indexes_and_foreign_keys();
+
+        Ok(table)
+    }
+
+    #[tracing::instrument]
+    async fn get_ddl(&self, table: &str) -> DescriberResult<Option<String>> {
+        let sql = r#"SELECT sql FROM sqlite_master WHERE type='table' AND name = ?"#;
+        let result_set = self.conn.query_raw(&sql, &[table.into()]).await?;
+
+        Ok(result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string())))
+    }
+
+    #[tracing::instrument]
+    async fn get_index_ddl(&self, index: &str) -> DescriberResult<Option<String>> {
+        let sql = r#"SELECT sql FROM sqlite_master WHERE type='index' AND name = ?"#;
+        let result_set = self.conn.query_raw(&sql, &[index.into()]).await?;
+
+        Ok(result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string())))
     }
 
     #[tracing::instrument]
@@ -213,6 +291,13 @@ impl SqlSchemaDescriber {
                     tpe,
                     default,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -375,6 +460,9 @@ impl SqlSchemaDescriber {
                     // Not relevant in SQLite since we cannot ALTER or DROP foreign keys by
                     // constraint name.
                     constraint_name: None,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 };
 
                 trace!("Detected foreign key {:?}", fk);
@@ -411,18 +499,54 @@ impl SqlSchemaDescriber {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                storage_parameters: Vec::new(),
+                expressions: Vec::new(),
+                algorithm: None,
+                invisible: false,
+                comment: None,
+                nulls_not_distinct: false,
+                opclasses: Vec::new(),
             };
 
             let sql = format!(r#"PRAGMA index_info("{}");"#, name);
             let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
             trace!("Got index description results: {:?}", result_set);
-            for row in result_set.into_iter() {
-                let pos = row.get("seqno").and_then(|x| x.as_i64()).expect("get seqno") as usize;
-                let col_name = row.get("name").and_then(|x| x.to_string()).expect("get name");
-                if index.columns.len() <= pos {
-                    index.columns.resize(pos + 1, "".to_string());
+            let mut keys: Vec<(usize, Option<String>)> = result_set
+                .into_iter()
+                .map(|row| {
+                    let pos = row.get("seqno").and_then(|x| x.as_i64()).expect("get seqno") as usize;
+                    // Expression keys have no column name.
+                    (pos, row.get("name").and_then(|x| x.to_string()))
+                })
+                .collect();
+            keys.sort_by_key(|(pos, _)| *pos);
+
+            // The definitions of the expressions are only available in the index DDL.
+            let expression_definitions = if keys.iter().any(|(_, col_name)| col_name.is_none()) {
+                self.get_index_ddl(&name)
+                    .await?
+                    .map(|ddl| parse_index_keys(&ddl))
+                    .unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+
+            let mut is_complete = true;
+
+            for (pos, col_name) in keys {
+                match (col_name, expression_definitions.get(pos)) {
+                    (Some(col_name), _) => index.columns.push(col_name),
+                    (None, Some(expression)) => index.expressions.push(IndexExpression {
+                        position: pos,
+                        expression: expression.clone(),
+                    }),
+                    (None, None) => is_complete = false,
                 }
-                index.columns[pos] = col_name;
+            }
+
+            if !is_complete {
+                trace!("Skipping index `{}` on expressions that could not be parsed", name);
+                continue;
             }
 
             indices.push(index)
@@ -490,6 +614,286 @@ fn unquote_sqlite_string_default(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// Extract the CHECK constraints, column and table constraints alike, from a
+/// `CREATE TABLE` statement.
+fn parse_check_constraints(ddl: &str) -> Vec<CheckConstraint> {
+    let tokens = tokenize_ddl(ddl);
+    let mut check_constraints = Vec::new();
+
+    for (idx, token) in tokens.iter().enumerate() {
+        if !token.is_keyword("check") {
+            continue;
+        }
+
+        let open_paren = match tokens.get(idx + 1) {
+            Some(DdlToken::OpenParen(pos)) => *pos,
+            _ => continue,
+        };
+
+        let mut depth = 0;
+        let close_paren = tokens[idx + 1..].iter().find_map(|token| match token {
+            DdlToken::OpenParen(_) => {
+                depth += 1;
+                None
+            }
+            DdlToken::CloseParen(pos) => {
+                depth -= 1;
+                if depth == 0 {
+                    Some(*pos)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        });
+
+        let close_paren = match close_paren {
+            Some(pos) => pos,
+            None => continue,
+        };
+
+        let name = match idx.checked_sub(2).map(|start| &tokens[start..idx]) {
+            Some([constraint, DdlToken::Word { text, .. }]) if constraint.is_keyword("constraint") => {
+                Some(text.clone())
+            }
+            _ => None,
+        };
+
+        check_constraints.push(CheckConstraint {
+            name,
+            expression: ddl[open_paren + 1..close_paren].trim().to_owned(),
+        });
+    }
+
+    check_constraints
+}
+
+/// The keys of a `CREATE INDEX` statement, in index order, without their `ASC`/`DESC` ordering.
+fn parse_index_keys(ddl: &str) -> Vec<String> {
+    let tokens = tokenize_ddl(ddl);
+
+    let on_idx = match tokens.iter().position(|token| token.is_keyword("on")) {
+        Some(idx) => idx,
+        None => return Vec::new(),
+    };
+
+    let mut depth = 0;
+    let mut open_paren = None;
+    let mut close_paren = None;
+
+    for token in &tokens[on_idx..] {
+        match token {
+            DdlToken::OpenParen(pos) => {
+                if depth == 0 {
+                    open_paren = Some(*pos);
+                }
+                depth += 1;
+            }
+            DdlToken::CloseParen(pos) => {
+                depth -= 1;
+                if depth == 0 {
+                    close_paren = Some(*pos);
+                    break;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let key_list = match (open_paren, close_paren) {
+        (Some(open_paren), Some(close_paren)) => &ddl[open_paren + 1..close_paren],
+        _ => return Vec::new(),
+    };
+
+    split_top_level_commas(key_list)
+        .into_iter()
+        .map(|key| {
+            let key = key.trim();
+            let lowercase_key = key.to_lowercase();
+
+            let key = [" asc", " desc"]
+                .iter()
+                .find(|ordering| lowercase_key.ends_with(*ordering))
+                .map(|ordering| key[..key.len() - ordering.len()].trim_end())
+                .unwrap_or(key);
+
+            strip_enclosing_parens(key).to_owned()
+        })
+        .collect()
+}
+
+/// Remove the parentheses around a whole expression, so `(lower(name))` and `lower(name)` are
+/// described the same.
+fn strip_enclosing_parens(mut expression: &str) -> &str {
+    while expression.starts_with('(')
+        && expression.ends_with(')')
+        && split_top_level_commas(&expression[1..expression.len() - 1]).len() == 1
+        && is_balanced(&expression[1..expression.len() - 1])
+    {
+        expression = expression[1..expression.len() - 1].trim();
+    }
+
+    expression
+}
+
+/// Whether the parentheses outside of quotes are balanced, and never closed before they are opened.
+fn is_balanced(expression: &str) -> bool {
+    let mut depth = 0;
+    let mut closing_quote = None;
+
+    for c in expression.chars() {
+        if let Some(quote) = closing_quote {
+            if c == quote {
+                closing_quote = None;
+            }
+
+            continue;
+        }
+
+        match c {
+            '\'' | '"' | '`' => closing_quote = Some(c),
+            '[' => closing_quote = Some(']'),
+            '(' => depth += 1,
+            ')' if depth == 0 => return false,
+            ')' => depth -= 1,
+            _ => (),
+        }
+    }
+
+    depth == 0
+}
+
+/// Split a list on the commas that are not in parentheses or quotes.
+fn split_top_level_commas(list: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0;
+    let mut closing_quote = None;
+    let mut item_start = 0;
+
+    for (pos, c) in list.char_indices() {
+        if let Some(quote) = closing_quote {
+            if c == quote {
+                closing_quote = None;
+            }
+
+            continue;
+        }
+
+        match c {
+            '\'' | '"' | '`' => closing_quote = Some(c),
+            '[' => closing_quote = Some(']'),
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(&list[item_start..pos]);
+                item_start = pos + 1;
+            }
+            _ => (),
+        }
+    }
+
+    items.push(&list[item_start..]);
+
+    items
+}
+
+/// Whether a `CREATE TABLE` statement ends with the `WITHOUT ROWID` table option.
+fn is_without_rowid(ddl: &str) -> bool {
+    let tokens = tokenize_ddl(ddl);
+
+    let table_options = match tokens
+        .iter()
+        .rposition(|token| matches!(token, DdlToken::CloseParen(_)))
+    {
+        Some(last_close_paren) => &tokens[last_close_paren + 1..],
+        None => return false,
+    };
+
+    table_options
+        .windows(2)
+        .any(|window| window[0].is_keyword("without") && window[1].is_keyword("rowid"))
+}
+
+#[derive(Debug)]
+enum DdlToken {
+    /// A keyword or an identifier. Quoted identifiers are unquoted.
+    Word {
+        text: String,
+        quoted: bool,
+    },
+    OpenParen(usize),
+    CloseParen(usize),
+    /// String literals and punctuation.
+    Other,
+}
+
+impl DdlToken {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, DdlToken::Word { text, quoted: false } if text.eq_ignore_ascii_case(keyword))
+    }
+}
+
+/// A minimal tokenizer, just precise enough to find the constraint clauses of
+/// a `CREATE TABLE` statement. Whitespace is skipped.
+fn tokenize_ddl(ddl: &str) -> Vec<DdlToken> {
+    let mut tokens = Vec::new();
+    let mut chars = ddl.char_indices().peekable();
+
+    while let Some((pos, c)) = chars.next() {
+        match c {
+            '(' => tokens.push(DdlToken::OpenParen(pos)),
+            ')' => tokens.push(DdlToken::CloseParen(pos)),
+            '\'' => {
+                // An escaped quote ends the literal and starts a new one, which is fine here.
+                while let Some((_, c)) = chars.next() {
+                    if c == '\'' {
+                        break;
+                    }
+                }
+
+                tokens.push(DdlToken::Other);
+            }
+            '"' | '`' | '[' => {
+                let closing = if c == '[' { ']' } else { c };
+                let mut text = String::new();
+
+                while let Some((_, c)) = chars.next() {
+                    if c == closing {
+                        // Doubled quotes are escaped quotes.
+                        if closing != ']' && chars.peek().map(|(_, c)| *c) == Some(closing) {
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+
+                    text.push(c);
+                }
+
+                tokens.push(DdlToken::Word { text, quoted: true });
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut text = c.to_string();
+
+                while let Some((_, c)) = chars.peek() {
+                    if !(c.is_alphanumeric() || *c == '_' || *c == '$') {
+                        break;
+                    }
+
+                    text.push(*c);
+                    chars.next();
+                }
+
+                tokens.push(DdlToken::Word { text, quoted: false });
+            }
+            c if c.is_whitespace() => (),
+            _ => tokens.push(DdlToken::Other),
+        }
+    }
+
+    tokens
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
@@ -505,3 +909,72 @@ const SQLITE_SYSTEM_TABLES: &[&str] = &[
     "sqlite_stat3",
     "sqlite_stat4",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_check_constraints_finds_column_and_table_constraints() {
+        let ddl = r#"CREATE TABLE "Cat" (
+            "id" INTEGER PRIMARY KEY,
+            "age" INTEGER NOT NULL CHECK ("age" >= 0),
+            "name" TEXT NOT NULL DEFAULT 'check (me)',
+            CONSTRAINT "Cat_name_check" CHECK (length(name) > 0 AND name <> ')')
+        )"#;
+
+        assert_eq!(
+            parse_check_constraints(ddl),
+            &[
+                CheckConstraint {
+                    name: None,
+                    expression: r#""age" >= 0"#.to_owned(),
+                },
+                CheckConstraint {
+                    name: Some("Cat_name_check".to_owned()),
+                    expression: "length(name) > 0 AND name <> ')'".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_check_constraints_ignores_identifiers_named_check() {
+        let ddl = r#"CREATE TABLE "check" ("check" TEXT, [Check] INTEGER)"#;
+
+        assert!(parse_check_constraints(ddl).is_empty());
+    }
+
+    #[test]
+    fn is_without_rowid_only_looks_at_the_table_options() {
+        assert!(is_without_rowid(
+            r#"CREATE TABLE "Cat" ("id" INTEGER PRIMARY KEY) WITHOUT ROWID"#
+        ));
+        assert!(is_without_rowid(
+            r#"CREATE TABLE "Cat" ("id" INTEGER PRIMARY KEY) strict, without rowid"#
+        ));
+        assert!(!is_without_rowid(
+            r#"CREATE TABLE "Cat" ("id" INTEGER PRIMARY KEY, "without" TEXT, "rowid" TEXT)"#
+        ));
+        assert!(!is_without_rowid(
+            r#"CREATE TABLE "Cat" ("id" INTEGER PRIMARY KEY, "note" TEXT DEFAULT 'WITHOUT ROWID')"#
+        ));
+    }
+
+    #[test]
+    fn parse_index_keys_splits_columns_and_expressions() {
+        let ddl = r#"CREATE INDEX "Cat_lower_name_idx" ON "Cat" ("id", lower("name") DESC, substr(name, 1, 3), name || ',' ASC)"#;
+
+        assert_eq!(
+            parse_index_keys(ddl),
+            &[r#""id""#, r#"lower("name")"#, "substr(name, 1, 3)", "name || ','"]
+        );
+
+        let rendered = r#"CREATE INDEX "Cat_lower_name_idx" ON "Cat"("id", (lower("name")), (a) || (b))"#;
+
+        assert_eq!(
+            parse_index_keys(rendered),
+            &[r#""id""#, r#"lower("name")"#, "(a) || (b)"]
+        );
+    }
+}
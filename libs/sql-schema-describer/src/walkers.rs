@@ -3,8 +3,9 @@
 #![deny(missing_docs)]
 
 use crate::{
-    Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index,
-    IndexType, PrimaryKey, SqlSchema, Table,
+    CheckConstraint, Column, ColumnArity, ColumnStorage, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey,
+    ForeignKeyAction, IdentityGeneration, IdentityOptions, Index, IndexExpression, IndexKey, IndexOpclass, IndexType,
+    Policy, PrimaryKey, SqlSchema, Table, TablePartitioning,
 };
 
 /// Traverse all the columns in the schema.
@@ -18,6 +19,17 @@ pub fn walk_columns<'a>(schema: &'a SqlSchema) -> impl Iterator<Item = ColumnWal
     })
 }
 
+/// Traverse all the foreign keys in the schema.
+pub fn walk_foreign_keys<'a>(schema: &'a SqlSchema) -> impl Iterator<Item = ForeignKeyWalker<'a>> + 'a {
+    schema.tables.iter().enumerate().flat_map(move |(table_index, table)| {
+        (0..table.foreign_keys.len()).map(move |foreign_key_index| ForeignKeyWalker {
+            foreign_key_index,
+            table_index,
+            schema,
+        })
+    })
+}
+
 /// Traverse a table column.
 #[derive(Debug, Clone, Copy)]
 pub struct ColumnWalker<'a> {
@@ -80,6 +92,41 @@ impl<'a> ColumnWalker<'a> {
         self.column().auto_increment
     }
 
+    /// The generation mode of the column, if it is an identity column.
+    pub fn identity(&self) -> Option<IdentityGeneration> {
+        self.column().identity
+    }
+
+    /// The sequence options of the column, if it is an identity column with non-default options.
+    pub fn identity_options(&self) -> Option<IdentityOptions> {
+        self.column().identity_options
+    }
+
+    /// Is the column hidden from `SELECT *` queries?
+    pub fn is_invisible(&self) -> bool {
+        self.column().invisible
+    }
+
+    /// Is the column set to the current timestamp when the row is updated?
+    pub fn is_on_update_current_timestamp(&self) -> bool {
+        self.column().on_update_current_timestamp
+    }
+
+    /// The name of the constraint holding the column default, on MSSQL.
+    pub fn default_constraint_name(&self) -> Option<&'a str> {
+        self.column().default_constraint_name.as_deref()
+    }
+
+    /// The collation of the column, if it is not the default one. Only on Postgres.
+    pub fn collation(&self) -> Option<&'a str> {
+        self.column().collation.as_deref()
+    }
+
+    /// The storage settings of the column, if they were described. Only on Postgres.
+    pub fn storage(&self) -> Option<&'a ColumnStorage> {
+        self.column().storage.as_ref()
+    }
+
     /// Returns whether two columns are named the same and belong to the same table.
     pub fn is_same_column(&self, other: &ColumnWalker<'_>) -> bool {
         self.name() == other.name() && self.table().name() == other.table().name()
@@ -122,6 +169,36 @@ impl<'a> TableWalker<'a> {
         Self { schema, table_index }
     }
 
+    /// The table's default collation, if it was described.
+    pub fn collation(&self) -> Option<&'a str> {
+        self.table().collation.as_deref()
+    }
+
+    /// The CHECK constraints of the table, if they were described.
+    pub fn check_constraints(&self) -> &'a [CheckConstraint] {
+        &self.table().check_constraints
+    }
+
+    /// The row-level security policies of the table, if they were described.
+    pub fn policies(&self) -> &'a [Policy] {
+        &self.table().policies
+    }
+
+    /// How the table is partitioned, if it is a partitioned table.
+    pub fn partitioning(&self) -> Option<&'a TablePartitioning> {
+        self.table().partitioning.as_ref()
+    }
+
+    /// Whether row-level security is enabled on the table.
+    pub fn row_level_security(&self) -> bool {
+        self.table().row_level_security
+    }
+
+    /// Whether the table is a `WITHOUT ROWID` table.
+    pub fn without_rowid(&self) -> bool {
+        self.table().without_rowid
+    }
+
     /// Get a column in the table, by name.
     pub fn column(&self, column_name: &str) -> Option<ColumnWalker<'a>> {
         self.columns().find(|column| column.name() == column_name)
@@ -282,6 +359,21 @@ impl<'schema> ForeignKeyWalker<'schema> {
         &self.foreign_key().on_update_action
     }
 
+    /// Whether the foreign key constraint is `DEFERRABLE`.
+    pub fn is_deferrable(&self) -> bool {
+        self.foreign_key().deferrable
+    }
+
+    /// Whether the foreign key constraint is `INITIALLY DEFERRED`.
+    pub fn is_initially_deferred(&self) -> bool {
+        self.foreign_key().initially_deferred
+    }
+
+    /// Whether the foreign key uses `MATCH FULL` semantics.
+    pub fn is_match_full(&self) -> bool {
+        self.foreign_key().match_full
+    }
+
     /// The names of the columns referenced by the foreign key on the referenced table.
     pub fn referenced_column_names(&self) -> &[String] {
         &self.foreign_key().referenced_columns
@@ -292,6 +384,26 @@ impl<'schema> ForeignKeyWalker<'schema> {
         self.foreign_key().referenced_columns.len()
     }
 
+    /// Check that the foreign key constrains as many columns as it references. Any other shape
+    /// cannot be rendered, and points to a bug in the describer.
+    pub fn validate_column_alignment(&self) -> Result<(), String> {
+        let constrained_columns_count = self.foreign_key().columns.len();
+        let referenced_columns_count = self.referenced_columns_count();
+
+        if constrained_columns_count == referenced_columns_count {
+            return Ok(());
+        }
+
+        Err(format!(
+            "The foreign key on `{table}`({constrained_columns}) references {referenced_columns_count} column(s) of `{referenced_table}`, but constrains {constrained_columns_count}.",
+            table = self.table().name(),
+            constrained_columns = self.constrained_column_names().join(", "),
+            referenced_columns_count = referenced_columns_count,
+            referenced_table = self.foreign_key().referenced_table,
+            constrained_columns_count = constrained_columns_count,
+        ))
+    }
+
     /// The table the foreign key "points to".
     pub fn referenced_table(&self) -> TableWalker<'schema> {
         TableWalker {
@@ -356,6 +468,51 @@ impl<'a> IndexWalker<'a> {
         &self.get().name
     }
 
+    /// The storage parameters of the index, as `key=value` pairs.
+    pub fn storage_parameters(&self) -> &'a [String] {
+        &self.get().storage_parameters
+    }
+
+    /// The index method, when it is not the default one (e.g. `gin`).
+    pub fn algorithm(&self) -> Option<&'a str> {
+        self.get().algorithm.as_deref()
+    }
+
+    /// Is the index ignored by the query optimizer?
+    pub fn is_invisible(&self) -> bool {
+        self.get().invisible
+    }
+
+    /// The comment on the index, if any.
+    pub fn comment(&self) -> Option<&'a str> {
+        self.get().comment.as_deref()
+    }
+
+    /// Do NULL values conflict with each other in the unique index?
+    pub fn nulls_not_distinct(&self) -> bool {
+        self.get().nulls_not_distinct
+    }
+
+    /// The keys of the index, columns and expressions, in index order.
+    pub fn keys(&self) -> Vec<IndexKey<'a>> {
+        self.get().keys()
+    }
+
+    /// The keys of the index that are expressions.
+    pub fn expressions(&self) -> &'a [IndexExpression] {
+        &self.get().expressions
+    }
+
+    /// The operator classes of the keys of the index that do not use the default one.
+    pub fn opclasses(&self) -> &'a [IndexOpclass] {
+        &self.get().opclasses
+    }
+
+    /// The operator class of the key at `position`, when it is not the default one.
+    pub fn opclass_at(&self, position: usize) -> Option<&'a str> {
+        self.get().opclass_at(position)
+    }
+
     /// Traverse to the table of the index.
     pub fn table(&self) -> TableWalker<'a> {
         TableWalker {
@@ -426,3 +583,76 @@ impl SqlSchemaExt for SqlSchema {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(name: &str, foreign_keys: Vec<ForeignKey>) -> Table {
+        Table {
+            name: name.to_owned(),
+            columns: Vec::new(),
+            indices: Vec::new(),
+            primary_key: None,
+            foreign_keys,
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
+        }
+    }
+
+    fn foreign_key(column: &str, referenced_table: &str) -> ForeignKey {
+        ForeignKey {
+            constraint_name: None,
+            columns: vec![column.to_owned()],
+            referenced_table: referenced_table.to_owned(),
+            referenced_columns: vec!["id".to_owned()],
+            on_delete_action: ForeignKeyAction::NoAction,
+            on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
+            initially_deferred: false,
+            match_full: false,
+        }
+    }
+
+    #[test]
+    fn walk_foreign_keys_traverses_the_foreign_keys_of_all_tables() {
+        let schema = SqlSchema {
+            tables: vec![
+                table(
+                    "Cat",
+                    vec![foreign_key("boxId", "Box"), foreign_key("ownerId", "Human")],
+                ),
+                table("Box", Vec::new()),
+                table("Human", vec![foreign_key("boxId", "Box")]),
+            ],
+            enums: Vec::new(),
+            sequences: Vec::new(),
+            synonyms: Vec::new(),
+            flavour: None,
+        };
+
+        let foreign_keys: Vec<(&str, &str, &str)> = walk_foreign_keys(&schema)
+            .map(|fk| {
+                (
+                    fk.table().name(),
+                    fk.foreign_key().columns[0].as_str(),
+                    fk.referenced_table().name(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            foreign_keys,
+            &[
+                ("Cat", "boxId", "Box"),
+                ("Cat", "ownerId", "Human"),
+                ("Human", "boxId", "Box")
+            ]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{common::*, test_api::*};
 use barrel::types;
+use futures::TryStreamExt;
 use native_types::{MsSqlType, MsSqlTypeParameter, MySqlType, NativeType, PostgresType};
 use pretty_assertions::assert_eq;
 use prisma_value::PrismaValue;
@@ -15,6 +16,21 @@ mod postgres;
 mod sqlite;
 mod test_api;
 
+/// MySQL tables are described with the default collation of the test database.
+fn table_collation(api: &TestApi, table: &Table) -> Option<String> {
+    match api.sql_family() {
+        SqlFamily::Mysql => {
+            assert!(
+                table.collation.is_some(),
+                "MySQL table `{}` has no collation",
+                table.name
+            );
+            table.collation.clone()
+        }
+        _ => None,
+    }
+}
+
 fn int_full_data_type(api: &TestApi) -> String {
     match (api.sql_family(), api.connector_name()) {
         (SqlFamily::Postgres, _) => "int4".to_string(),
@@ -101,6 +117,13 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "column2".to_string(),
@@ -115,6 +138,13 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
     ];
 
@@ -158,6 +188,13 @@ async fn foreign_keys_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        identity: None,
+        identity_options: None,
+        invisible: false,
+        on_update_current_timestamp: false,
+        default_constraint_name: None,
+        collation: None,
+        storage: None,
     }];
 
     let on_delete_action = match api.sql_family() {
@@ -169,6 +206,13 @@ async fn foreign_keys_must_work(api: &TestApi) {
             name: "city".to_owned(),
             columns: vec!["city".to_owned()],
             tpe: IndexType::Normal,
+            storage_parameters: Vec::new(),
+            expressions: Vec::new(),
+            algorithm: None,
+            invisible: false,
+            comment: None,
+            nulls_not_distinct: false,
+            opclasses: Vec::new(),
         }]
     } else {
         vec![]
@@ -193,7 +237,17 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 referenced_table: "City".to_string(),
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
+                deferrable: false,
+                initially_deferred: false,
+                match_full: false,
             }],
+            ddl: None,
+            collation: table_collation(api, user_table),
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
@@ -253,6 +307,13 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "city_name".to_string(),
@@ -270,6 +331,13 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
     ];
 
@@ -278,6 +346,13 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             name: "city_name".to_owned(),
             columns: vec!["city_name".to_owned(), "city".to_owned()],
             tpe: IndexType::Normal,
+            storage_parameters: Vec::new(),
+            expressions: Vec::new(),
+            algorithm: None,
+            invisible: false,
+            comment: None,
+            nulls_not_distinct: false,
+            opclasses: Vec::new(),
         }]
     } else {
         vec![]
@@ -308,7 +383,17 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 referenced_table: "City".to_string(),
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
+                deferrable: false,
+                initially_deferred: false,
+                match_full: false,
             },],
+            ddl: None,
+            collation: table_collation(api, user_table),
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
@@ -337,6 +422,13 @@ async fn names_with_hyphens_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        identity: None,
+        identity_options: None,
+        invisible: false,
+        on_update_current_timestamp: false,
+        default_constraint_name: None,
+        collation: None,
+        storage: None,
     }];
     assert_eq!(user_table.columns, expected_columns);
 }
@@ -388,6 +480,13 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "name".to_string(),
@@ -405,6 +504,13 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -425,6 +531,13 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 },
             }),
             foreign_keys: vec![],
+            ddl: None,
+            collation: table_collation(api, table),
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
@@ -463,6 +576,13 @@ async fn indices_must_work(api: &TestApi) {
 
             default,
             auto_increment: true,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "count".to_string(),
@@ -477,6 +597,13 @@ async fn indices_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
     ];
     let pk_sequence = match api.sql_family() {
@@ -484,6 +611,7 @@ async fn indices_must_work(api: &TestApi) {
             name: "User_id_seq".to_string(),
             allocation_size: 1,
             initial_value: 1,
+            owned: true,
         }),
         _ => None,
     };
@@ -496,6 +624,13 @@ async fn indices_must_work(api: &TestApi) {
                 name: "count".to_string(),
                 columns: vec!["count".to_string()],
                 tpe: IndexType::Normal,
+                storage_parameters: Vec::new(),
+                expressions: Vec::new(),
+                algorithm: None,
+                invisible: false,
+                comment: None,
+                nulls_not_distinct: false,
+                opclasses: Vec::new(),
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
@@ -507,6 +642,13 @@ async fn indices_must_work(api: &TestApi) {
                 },
             }),
             foreign_keys: vec![],
+            ddl: None,
+            collation: table_collation(api, user_table),
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
@@ -539,6 +681,13 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "uniq2".to_string(),
@@ -554,18 +703,39 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
     ];
     let mut expected_indices = vec![Index {
         name: "uniq".to_string(),
         columns: vec!["uniq2".to_string()],
         tpe: IndexType::Unique,
+        storage_parameters: Vec::new(),
+        expressions: Vec::new(),
+        algorithm: None,
+        invisible: false,
+        comment: None,
+        nulls_not_distinct: false,
+        opclasses: Vec::new(),
     }];
     match api.sql_family() {
         SqlFamily::Mysql => expected_indices.push(Index {
             name: "uniq1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            storage_parameters: Vec::new(),
+            expressions: Vec::new(),
+            algorithm: None,
+            invisible: false,
+            comment: None,
+            nulls_not_distinct: false,
+            opclasses: Vec::new(),
         }),
         SqlFamily::Postgres => expected_indices.insert(
             0,
@@ -573,12 +743,26 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "User_uniq1_key".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                storage_parameters: Vec::new(),
+                expressions: Vec::new(),
+                algorithm: None,
+                invisible: false,
+                comment: None,
+                nulls_not_distinct: false,
+                opclasses: Vec::new(),
             },
         ),
         SqlFamily::Sqlite => expected_indices.push(Index {
             name: "sqlite_autoindex_User_1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            storage_parameters: Vec::new(),
+            expressions: Vec::new(),
+            algorithm: None,
+            invisible: false,
+            comment: None,
+            nulls_not_distinct: false,
+            opclasses: Vec::new(),
         }),
         SqlFamily::Mssql => expected_indices.insert(
             0,
@@ -586,6 +770,13 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "UQ__User__CD572100A176666B".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                storage_parameters: Vec::new(),
+                expressions: Vec::new(),
+                algorithm: None,
+                invisible: false,
+                comment: None,
+                nulls_not_distinct: false,
+                opclasses: Vec::new(),
             },
         ),
     };
@@ -616,6 +807,13 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                     indices: expected_indices,
                     primary_key: None,
                     foreign_keys: vec![],
+                    ddl: None,
+                    collation: table_collation(api, user_table),
+                    check_constraints: Vec::new(),
+                    policies: Vec::new(),
+                    partitioning: None,
+                    row_level_security: false,
+                    without_rowid: false,
                 }
             );
         }
@@ -658,6 +856,13 @@ async fn defaults_must_work(api: &TestApi) {
 
         default: Some(default),
         auto_increment: false,
+        identity: None,
+        identity_options: None,
+        invisible: false,
+        on_update_current_timestamp: false,
+        default_constraint_name: None,
+        collation: None,
+        storage: None,
     }];
     assert_eq!(
         user_table,
@@ -667,6 +872,95 @@ async fn defaults_must_work(api: &TestApi) {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            ddl: None,
+            collation: table_collation(api, user_table),
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
+
+#[test_each_connector]
+async fn streamed_tables_match_the_described_schema(api: &TestApi) {
+    let sql_family = api.sql_family();
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("City", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::varchar(100));
+            });
+            migration.create_table("Street", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::varchar(100).unique(true));
+            });
+            migration.create_table("User", move |t| {
+                t.add_column("id", types::primary());
+
+                if sql_family == SqlFamily::Mysql {
+                    t.add_column("city", types::integer());
+                    t.inject_custom("FOREIGN KEY(city) REFERENCES City(id)");
+                } else {
+                    t.add_column("city", types::foreign("City", "id"));
+                }
+            });
+        })
+        .await;
+
+    let describer = api.describer();
+    let schema = describer.describe(api.schema_name()).await.expect("describe failed");
+
+    for page_size in &[1, 2, DEFAULT_PAGE_SIZE] {
+        let streamed: Vec<Table> = describer
+            .describe_tables_stream(api.schema_name(), *page_size)
+            .try_collect()
+            .await
+            .expect("describe_tables_stream failed");
+
+        assert_eq!(streamed, schema.tables);
+    }
+}
+
+#[test_each_connector]
+async fn describe_filtered_only_describes_the_matching_tables(api: &TestApi) {
+    let sql_family = api.sql_family();
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("City", |t| {
+                t.add_column("id", types::primary());
+            });
+            migration.create_table("Street", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::varchar(100).unique(true));
+            });
+            migration.create_table("User", move |t| {
+                t.add_column("id", types::primary());
+
+                if sql_family == SqlFamily::Mysql {
+                    t.add_column("city", types::integer());
+                    t.inject_custom("FOREIGN KEY(city) REFERENCES City(id)");
+                } else {
+                    t.add_column("city", types::foreign("City", "id"));
+                }
+            });
+        })
+        .await;
+
+    let describer = api.describer();
+    let full_schema = describer.describe(api.schema_name()).await.expect("describe failed");
+
+    let filter = vec!["User".to_owned(), "DoesNotExist".to_owned()];
+    let schema = describer
+        .describe_filtered(api.schema_name(), Some(&filter))
+        .await
+        .expect("describe_filtered failed");
+
+    assert_eq!(schema.tables.len(), 1);
+    assert_eq!(schema.table_bang("User"), full_schema.table_bang("User"));
+    assert!(schema.get_table("City").is_none());
+    assert!(schema.get_table("Street").is_none());
+}
@@ -5,6 +5,8 @@ use crate::mssql::*;
 use barrel::{types, Migration};
 use native_types::{MsSqlType, MsSqlTypeParameter::*, NativeType};
 use pretty_assertions::assert_eq;
+use prisma_value::PrismaValue;
+use quaint::prelude::Queryable;
 use sql_schema_describer::*;
 
 #[tokio::test]
@@ -64,6 +66,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "bit_col".to_string(),
@@ -78,6 +87,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -92,6 +108,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -105,6 +128,13 @@ async fn all_mssql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "money_col".to_string(),
@@ -119,6 +149,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -133,6 +170,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -147,6 +191,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "smallmoney_col".to_string(),
@@ -161,6 +212,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "tinyint_col".to_string(),
@@ -175,6 +233,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -189,6 +254,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -203,6 +275,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -217,6 +296,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -230,6 +316,13 @@ async fn all_mssql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "datetime2_col".to_string(),
@@ -244,6 +337,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "datetimeoffset_col".to_string(),
@@ -258,6 +358,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "smalldatetime_col".to_string(),
@@ -272,6 +379,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -286,6 +400,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -300,6 +421,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -314,6 +442,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "varchar_max_col".to_string(),
@@ -328,6 +463,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -342,6 +484,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "nvarchar_col".to_string(),
@@ -356,6 +505,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "nvarchar_max_col".to_string(),
@@ -370,6 +526,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "ntext_col".to_string(),
@@ -384,6 +547,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -398,6 +568,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -412,6 +589,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "varbinary_max_col".to_string(),
@@ -426,6 +610,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "image_col".to_string(),
@@ -440,6 +631,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "xml_col".to_string(),
@@ -454,6 +652,13 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -470,6 +675,13 @@ async fn all_mssql_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
@@ -511,6 +723,13 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -524,6 +743,13 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -537,6 +763,13 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
             ],
             indices: vec![],
@@ -553,6 +786,9 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_2".to_owned()),
@@ -561,8 +797,18 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::Cascade,
                     on_delete_action: ForeignKeyAction::Cascade,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
             ],
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
@@ -589,11 +835,54 @@ async fn mssql_multi_field_indexes_must_be_inferred() {
         &[Index {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            storage_parameters: Vec::new(),
+            expressions: Vec::new(),
+            algorithm: None,
+            invisible: false,
+            comment: None,
+            nulls_not_distinct: false,
+            opclasses: Vec::new(),
         }]
     );
 }
 
+#[tokio::test]
+async fn mssql_index_columns_are_ordered_by_their_position_in_the_index() {
+    let db_name = "mssql_index_columns_are_ordered_by_their_position_in_the_index";
+
+    let mut migration = Migration::new().schema(db_name);
+    migration.create_table("Employee", move |t| {
+        t.add_column("id", types::primary());
+        t.add_column("age", types::integer());
+        t.add_column("name", types::varchar(200));
+        t.add_column("team", types::integer());
+        t.add_index("team_name_age_index", types::index(vec!["team", "name", "age"]));
+    });
+
+    let full_sql = migration.make::<barrel::backend::MsSql>();
+    let inspector = get_mssql_describer_for_schema(&full_sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+    let table = result.get_table("Employee").expect("couldn't get Employee table");
+
+    assert_eq!(
+        table.indices,
+        &[Index {
+            name: "team_name_age_index".into(),
+            columns: vec!["team".to_owned(), "name".to_owned(), "age".to_owned()],
+            tpe: IndexType::Normal,
+            storage_parameters: Vec::new(),
+            expressions: Vec::new(),
+            algorithm: None,
+            invisible: false,
+            comment: None,
+            nulls_not_distinct: false,
+            opclasses: Vec::new(),
+        }]
+    );
+    assert_eq!(table.primary_key.as_ref().unwrap().columns, &["id"]);
+}
+
 #[tokio::test]
 async fn mssql_join_table_unique_indexes_must_be_inferred() {
     let db_name = "mssql_join_table_unique_indexes_must_be_inferred";
@@ -628,6 +917,212 @@ async fn mssql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            storage_parameters: Vec::new(),
+            expressions: Vec::new(),
+            algorithm: None,
+            invisible: false,
+            comment: None,
+            nulls_not_distinct: false,
+            opclasses: Vec::new(),
         }]
     );
 }
+
+#[tokio::test]
+async fn mssql_unicode_string_defaults_must_work() {
+    let db_name = "mssql_unicode_string_defaults_must_work";
+
+    let sql = format!(
+        "CREATE TABLE [{0}].[City] (
+            id INT NOT NULL IDENTITY(1,1) PRIMARY KEY,
+            name NVARCHAR(100) NOT NULL DEFAULT N'Zürich',
+            nickname NVARCHAR(100) NOT NULL DEFAULT ((N'Züri')),
+            country VARCHAR(100) NOT NULL DEFAULT 'Switzerland'
+        )",
+        db_name
+    );
+    let inspector = get_mssql_describer_for_schema(&sql, db_name).await;
+
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let table = schema.table_bang("City");
+
+    let expected_defaults = &[("name", "Zürich"), ("nickname", "Züri"), ("country", "Switzerland")];
+
+    for (column_name, expected_default) in expected_defaults {
+        assert_eq!(
+            table.column_bang(column_name).default,
+            Some(DefaultValue::VALUE(PrismaValue::String((*expected_default).to_owned())))
+        );
+    }
+}
+
+#[tokio::test]
+async fn mssql_decimal_defaults_are_normalized() {
+    let db_name = "mssql_decimal_defaults_are_normalized";
+
+    let sql = format!(
+        "CREATE TABLE [{0}].[Product] (
+            id INT NOT NULL IDENTITY(1,1) PRIMARY KEY,
+            price DECIMAL(10, 2) NOT NULL DEFAULT 1.50,
+            scientific DECIMAL(10, 2) NOT NULL DEFAULT 1E2,
+            negative DECIMAL(10, 2) NOT NULL DEFAULT -2.250
+        )",
+        db_name
+    );
+    let inspector = get_mssql_describer_for_schema(&sql, db_name).await;
+
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let table = schema.table_bang("Product");
+
+    let expected_defaults = &[("price", "1.5"), ("scientific", "100"), ("negative", "-2.25")];
+
+    for (column_name, expected_default) in expected_defaults {
+        let default = match &table.column_bang(column_name).default {
+            Some(DefaultValue::VALUE(PrismaValue::Float(value))) => value.to_string(),
+            other => panic!("Unexpected default on `{}`: {:?}", column_name, other),
+        };
+
+        assert_eq!(&default, expected_default);
+    }
+}
+
+#[tokio::test]
+async fn mssql_column_types_keep_their_full_data_type() {
+    let db_name = "mssql_column_types_keep_their_full_data_type";
+
+    let sql = format!(
+        "CREATE TABLE [{0}].[Place] (
+            id INT NOT NULL IDENTITY(1,1) PRIMARY KEY,
+            node HIERARCHYID NULL,
+            location GEOGRAPHY NULL,
+            anything SQL_VARIANT NULL,
+            name VARCHAR(30) NULL
+        )",
+        db_name
+    );
+    let inspector = get_mssql_describer_for_schema(&sql, db_name).await;
+
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let table = schema.table_bang("Place");
+
+    for (column_name, expected_type) in &[
+        ("node", "hierarchyid"),
+        ("location", "geography"),
+        ("anything", "sql_variant"),
+    ] {
+        let tpe = &table.column_bang(column_name).tpe;
+
+        assert_eq!(tpe.family, ColumnTypeFamily::Unsupported(expected_type.to_string()));
+        assert_eq!(&tpe.full_data_type, expected_type);
+    }
+
+    let name = &table.column_bang("name").tpe;
+
+    assert_eq!(name.family, ColumnTypeFamily::String);
+    assert_eq!(name.full_data_type, "varchar(30)");
+}
+
+#[tokio::test]
+async fn mssql_default_constraint_names_are_described() {
+    let db_name = "mssql_default_constraint_names_are_described";
+
+    let sql = format!(
+        "CREATE TABLE [{0}].[Cat] (
+            id INT NOT NULL PRIMARY KEY,
+            name NVARCHAR(1000) NOT NULL CONSTRAINT [DF__Cat__name] DEFAULT 'Felix',
+            lives INT NOT NULL DEFAULT 9,
+            color NVARCHAR(1000) NULL
+        )",
+        db_name
+    );
+    let inspector = get_mssql_describer_for_schema(&sql, db_name).await;
+
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let table = schema.table_bang("Cat");
+
+    assert_eq!(
+        table.column_bang("name").default_constraint_name.as_deref(),
+        Some("DF__Cat__name")
+    );
+
+    // SQL Server generates a random name for unnamed default constraints.
+    let generated_name = table.column_bang("lives").default_constraint_name.as_ref().unwrap();
+    assert!(generated_name.starts_with("DF__Cat__lives"), "{}", generated_name);
+
+    assert_eq!(table.column_bang("color").default_constraint_name, None);
+    assert_eq!(table.column_bang("id").default_constraint_name, None);
+}
+
+#[tokio::test]
+async fn mssql_describe_changed_since_only_returns_the_modified_tables() {
+    let db_name = "mssql_describe_changed_since_only_returns_the_modified_tables";
+    let api = test_api::mssql_2019_test_api(test_setup::TestAPIArgs::new(db_name, 0b01000000)).await;
+
+    let sql = format!(
+        "CREATE TABLE [{0}].[Cat] (id INT NOT NULL PRIMARY KEY);
+         CREATE TABLE [{0}].[Dog] (id INT NOT NULL PRIMARY KEY);
+         CREATE TABLE [{0}].[Mouse] (id INT NOT NULL PRIMARY KEY);",
+        db_name
+    );
+    api.database().raw_cmd(&sql).await.unwrap();
+
+    let inspector = mssql::SqlSchemaDescriber::new(api.database().clone());
+
+    // Leave some room for the precision of `modify_date`.
+    tokio::time::delay_for(std::time::Duration::from_millis(50)).await;
+    let snapshot = inspector.take_snapshot().await.expect("taking a snapshot").unwrap();
+    tokio::time::delay_for(std::time::Duration::from_millis(50)).await;
+
+    let sql = format!("ALTER TABLE [{0}].[Dog] ADD name NVARCHAR(100) NULL", db_name);
+    api.database().raw_cmd(&sql).await.unwrap();
+
+    let changed_tables = inspector
+        .describe_changed_since(db_name, &snapshot)
+        .await
+        .expect("describing the changed tables")
+        .unwrap();
+
+    let full_schema = inspector.describe(db_name).await.expect("describing");
+
+    assert_eq!(changed_tables, &[full_schema.table_bang("Dog").clone()]);
+    assert!(changed_tables[0].column("name").is_some());
+}
+
+#[tokio::test]
+async fn mssql_synonyms_are_described_on_request() {
+    let db_name = "mssql_synonyms_are_described_on_request";
+    let api = test_api::mssql_2019_test_api(test_setup::TestAPIArgs::new(db_name, 0b01000000)).await;
+
+    let sql = format!(
+        "CREATE TABLE [{0}].[Cat] (id INT NOT NULL PRIMARY KEY);
+         CREATE SYNONYM [{0}].[Kitty] FOR [{0}].[Cat];
+         CREATE SYNONYM [{0}].[Ghost] FOR [{0}].[DoesNotExist];",
+        db_name
+    );
+    api.database().raw_cmd(&sql).await.unwrap();
+
+    let inspector = mssql::SqlSchemaDescriber::new(api.database().clone());
+    let schema = inspector.describe(db_name).await.expect("describing");
+
+    assert!(schema.synonyms.is_empty());
+
+    let inspector = inspector.include_synonyms(true);
+    let schema = inspector.describe(db_name).await.expect("describing");
+
+    assert_eq!(
+        schema.synonyms,
+        &[
+            Synonym {
+                name: "Ghost".to_owned(),
+                base_object_name: format!("[{}].[DoesNotExist]", db_name),
+                base_table: None,
+            },
+            Synonym {
+                name: "Kitty".to_owned(),
+                base_object_name: format!("[{}].[Cat]", db_name),
+                base_table: Some("Cat".to_owned()),
+            },
+        ]
+    );
+    assert!(schema.get_table("Kitty").is_none());
+}
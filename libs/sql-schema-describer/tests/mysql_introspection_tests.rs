@@ -77,6 +77,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -91,6 +98,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -105,6 +119,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -118,6 +139,13 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -132,6 +160,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -146,6 +181,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -160,6 +202,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -174,6 +223,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -188,6 +244,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -202,6 +265,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -216,6 +286,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -230,6 +307,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -244,6 +328,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -258,6 +349,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -272,6 +370,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: Some(DefaultValue::NOW),
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -286,6 +391,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -300,6 +412,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -314,6 +433,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -328,6 +454,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -342,6 +475,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -356,6 +496,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -370,6 +517,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "enum_col".to_string(),
@@ -384,6 +538,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -398,6 +559,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -412,6 +580,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -426,6 +601,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -440,6 +622,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -455,6 +644,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -469,6 +665,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -483,6 +686,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -497,6 +707,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -511,6 +728,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -525,6 +749,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -539,6 +770,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -553,6 +791,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -567,6 +812,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -581,6 +833,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -595,6 +854,13 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -609,10 +875,21 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
 
+    // The table gets the default collation of the test database.
+    let collation = table.collation.clone();
+    assert!(collation.is_some());
+
     assert_eq!(
         table,
         Table {
@@ -625,6 +902,8 @@ async fn all_mysql_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            ddl: None,
+            collation,
         }
     );
 }
@@ -652,6 +931,10 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
     let mut table = schema.get_table("User").expect("get User table").to_owned();
     table.foreign_keys.sort_unstable_by_key(|fk| fk.columns.clone());
 
+    // The table gets the default collation of the test database.
+    let collation = table.collation.clone();
+    assert!(collation.is_some());
+
     assert_eq!(
         table,
         Table {
@@ -670,6 +953,13 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -683,6 +973,13 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -696,6 +993,13 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -709,6 +1013,13 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -722,6 +1033,13 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
             ],
             indices: vec![
@@ -729,21 +1047,49 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     name: "city".to_owned(),
                     columns: vec!["city".to_owned(),],
                     tpe: IndexType::Normal,
+                    storage_parameters: Vec::new(),
+                    expressions: Vec::new(),
+                    algorithm: None,
+                    invisible: false,
+                    comment: None,
+                    nulls_not_distinct: false,
+                    opclasses: Vec::new(),
                 },
                 Index {
                     name: "city_cascade".to_owned(),
                     columns: vec!["city_cascade".to_owned(),],
                     tpe: IndexType::Normal,
+                    storage_parameters: Vec::new(),
+                    expressions: Vec::new(),
+                    algorithm: None,
+                    invisible: false,
+                    comment: None,
+                    nulls_not_distinct: false,
+                    opclasses: Vec::new(),
                 },
                 Index {
                     name: "city_restrict".to_owned(),
                     columns: vec!["city_restrict".to_owned(),],
                     tpe: IndexType::Normal,
+                    storage_parameters: Vec::new(),
+                    expressions: Vec::new(),
+                    algorithm: None,
+                    invisible: false,
+                    comment: None,
+                    nulls_not_distinct: false,
+                    opclasses: Vec::new(),
                 },
                 Index {
                     name: "city_set_null".to_owned(),
                     columns: vec!["city_set_null".to_owned(),],
                     tpe: IndexType::Normal,
+                    storage_parameters: Vec::new(),
+                    expressions: Vec::new(),
+                    algorithm: None,
+                    invisible: false,
+                    comment: None,
+                    nulls_not_distinct: false,
+                    opclasses: Vec::new(),
                 }
             ],
             primary_key: Some(PrimaryKey {
@@ -759,6 +1105,9 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
                     on_update_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_2".to_owned()),
@@ -767,6 +1116,9 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Cascade,
                     on_update_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_3".to_owned()),
@@ -775,6 +1127,9 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Restrict,
                     on_update_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_4".to_owned()),
@@ -783,8 +1138,13 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetNull,
                     on_update_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
             ],
+            ddl: None,
+            collation,
         }
     );
 }
@@ -812,6 +1172,13 @@ async fn mysql_multi_field_indexes_must_be_inferred() {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
             tpe: IndexType::Unique,
+            storage_parameters: Vec::new(),
+            expressions: Vec::new(),
+            algorithm: None,
+            invisible: false,
+            comment: None,
+            nulls_not_distinct: false,
+            opclasses: Vec::new(),
         }]
     );
 }
@@ -850,6 +1217,13 @@ async fn mysql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            storage_parameters: Vec::new(),
+            expressions: Vec::new(),
+            algorithm: None,
+            invisible: false,
+            comment: None,
+            nulls_not_distinct: false,
+            opclasses: Vec::new(),
         }]
     );
 }
@@ -890,6 +1264,9 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
+            initially_deferred: false,
+            match_full: false,
         }]
     );
 
@@ -922,6 +1299,9 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Restrict,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
+            initially_deferred: false,
+            match_full: false,
         }]
     );
 }
@@ -1035,3 +1415,223 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql_8"))]
+async fn multi_column_indexes_round_trip_with_their_column_order(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE `indexed` (
+            `a` INT NOT NULL,
+            `b` INT NOT NULL,
+            `c` INT NOT NULL,
+            PRIMARY KEY (`b`, `c`, `a`),
+            INDEX `indexed_c_a_b_idx` (`c`, `a`, `b`)
+        )
+    "#;
+
+    api.database().raw_cmd(&create_table).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("indexed");
+
+    assert_eq!(table.primary_key.as_ref().unwrap().columns, &["b", "c", "a"]);
+    assert_eq!(table.indices.len(), 1);
+    assert_eq!(table.indices[0].columns, &["c", "a", "b"]);
+
+    // Recreate the index from its description.
+    let recreate_index = format!(
+        "CREATE INDEX `indexed_c_a_b_idx` ON `indexed` ({})",
+        table.indices[0]
+            .columns
+            .iter()
+            .map(|column| format!("`{}`", column))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    api.database()
+        .raw_cmd("DROP INDEX `indexed_c_a_b_idx` ON `indexed`")
+        .await?;
+    api.database().raw_cmd(&recreate_index).await?;
+
+    assert_eq!(api.describe().await?, schema);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn check_constraints_round_trip_on_mysql_8(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE `Cat` (
+            `id` INT PRIMARY KEY,
+            `lives` INT NOT NULL,
+            CONSTRAINT `Cat_lives_check` CHECK (`lives` BETWEEN 0 AND 9),
+            CONSTRAINT `Cat_unenforced_check` CHECK (`lives` > 100) NOT ENFORCED
+        )
+    "#;
+
+    api.database().raw_cmd(&create_table).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Cat");
+
+    assert_eq!(table.check_constraints.len(), 1);
+
+    let check_constraint = &table.check_constraints[0];
+
+    assert_eq!(check_constraint.name.as_deref(), Some("Cat_lives_check"));
+
+    // Recreate the constraint from its description.
+    api.database()
+        .raw_cmd("ALTER TABLE `Cat` DROP CHECK `Cat_lives_check`")
+        .await?;
+    api.database()
+        .raw_cmd(&format!(
+            "ALTER TABLE `Cat` ADD CONSTRAINT `Cat_lives_check` CHECK ({})",
+            check_constraint.expression
+        ))
+        .await?;
+
+    assert_eq!(api.describe().await?, schema);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_5_6"))]
+async fn check_constraints_are_not_described_before_mysql_8(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE `Cat` (
+            `id` INT PRIMARY KEY,
+            `lives` INT NOT NULL,
+            CONSTRAINT `Cat_lives_check` CHECK (`lives` BETWEEN 0 AND 9)
+        )
+    "#;
+
+    api.database().raw_cmd(&create_table).await?;
+
+    let schema = api.describe().await?;
+
+    assert!(schema.table_bang("Cat").check_constraints.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mysql_table_ddl_is_included_on_request() {
+    let db_name = "mysql_table_ddl_is_included_on_request";
+
+    let sql = format!(
+        "CREATE TABLE `{0}`.User (id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY, name VARCHAR(100) NOT NULL)",
+        db_name
+    );
+
+    let inspector = get_mysql_describer_for_schema(&sql, db_name).await;
+    let schema = inspector.describe(db_name).await.expect("describing");
+
+    assert_eq!(schema.table_bang("User").ddl, None);
+
+    let inspector = inspector.include_ddl(true);
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let ddl = schema
+        .table_bang("User")
+        .ddl
+        .as_ref()
+        .expect("Expected the DDL to be included");
+
+    assert!(ddl.starts_with("CREATE TABLE `User`"), "{}", ddl);
+    assert!(ddl.contains("`name` varchar(100) NOT NULL"), "{}", ddl);
+}
+
+#[tokio::test]
+async fn mysql_table_ddl_is_included_for_names_with_backticks() {
+    let db_name = "mysql_table_ddl_is_included_for_names_with_backticks";
+
+    let sql = format!(
+        "CREATE TABLE `{0}`.`Cat``s toys` (id INTEGER NOT NULL PRIMARY KEY)",
+        db_name
+    );
+
+    let inspector = get_mysql_describer_for_schema(&sql, db_name).await.include_ddl(true);
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let ddl = schema
+        .table_bang("Cat`s toys")
+        .ddl
+        .as_ref()
+        .expect("Expected the DDL to be included");
+
+    assert!(ddl.starts_with("CREATE TABLE `Cat``s toys`"), "{}", ddl);
+}
+
+#[tokio::test]
+async fn mysql_inline_enum_and_set_columns_are_described() {
+    let db_name = "mysql_inline_enum_and_set_columns_are_described";
+
+    let sql = format!(
+        r#"
+        CREATE TABLE `{0}`.`Cat` (
+            id INTEGER NOT NULL PRIMARY KEY,
+            mood ENUM('happy', 'hungry, then happy', 'it''s complicated') NOT NULL,
+            toys SET('ball', 'mouse')
+        )
+        "#,
+        db_name
+    );
+
+    let inspector = get_mysql_describer_for_schema(&sql, db_name).await;
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let table = schema.table_bang("Cat");
+
+    let mood = table.column_bang("mood");
+
+    assert_eq!(mood.tpe.family, ColumnTypeFamily::Enum("Cat_mood".into()));
+    assert_eq!(
+        schema.get_enum("Cat_mood").map(|enm| enm.values.as_slice()),
+        Some(
+            &[
+                "happy".to_owned(),
+                "hungry, then happy".to_owned(),
+                "it's complicated".to_owned()
+            ][..]
+        )
+    );
+
+    let toys = table.column_bang("toys");
+
+    assert_eq!(toys.tpe.family, ColumnTypeFamily::String);
+    assert_eq!(toys.tpe.full_data_type, "set('ball','mouse')");
+}
+
+#[tokio::test]
+async fn mysql_describe_tables_returns_the_enums_of_the_columns() {
+    let db_name = "mysql_describe_tables_returns_the_enums_of_the_columns";
+
+    let sql = format!(
+        r#"
+        CREATE TABLE `{0}`.`Cat` (
+            id INTEGER NOT NULL PRIMARY KEY,
+            mood ENUM('happy', 'hungry') NOT NULL
+        );
+
+        CREATE TABLE `{0}`.`Dog` (
+            id INTEGER NOT NULL PRIMARY KEY,
+            mood ENUM('sleepy', 'playful') NOT NULL
+        )
+        "#,
+        db_name
+    );
+
+    let inspector = get_mysql_describer_for_schema(&sql, db_name).await;
+    let (tables, enums) = inspector
+        .describe_tables(db_name, &["Dog".to_owned()])
+        .await
+        .expect("describing the tables");
+
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].name, "Dog");
+    assert_eq!(
+        enums,
+        &[Enum {
+            name: "Dog_mood".to_owned(),
+            values: vec!["sleepy".to_owned(), "playful".to_owned()],
+        }]
+    );
+}
@@ -6,6 +6,7 @@ use crate::{common::*, postgres::*};
 use barrel::{types, Migration};
 use native_types::{NativeType, PostgresType};
 use pretty_assertions::assert_eq;
+use prisma_value::PrismaValue;
 use quaint::prelude::Queryable;
 use sql_schema_describer::*;
 use test_api::*;
@@ -80,6 +81,13 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "array_bool_col".into(),
@@ -94,6 +102,13 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "array_date_col".into(),
@@ -108,6 +123,13 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "array_double_col".into(),
@@ -122,6 +144,13 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "array_float_col".into(),
@@ -136,6 +165,13 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "array_int_col".into(),
@@ -150,6 +186,13 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "array_text_col".into(),
@@ -164,6 +207,13 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -177,6 +227,13 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "binary_col".into(),
@@ -191,6 +248,13 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "boolean_col".into(),
@@ -205,6 +269,13 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "date_time_col".into(),
@@ -220,6 +291,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "double_col".into(),
@@ -235,6 +313,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "float_col".into(),
@@ -250,6 +335,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "int_col".into(),
@@ -265,6 +357,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "primary_col".into(),
@@ -283,6 +382,13 @@ async fn all_postgres_column_types_must_work() {
                 SCHEMA
             ))),
             auto_increment: true,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "string1_col".into(),
@@ -298,6 +404,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "string2_col".into(),
@@ -312,6 +425,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "bigint_col".into(),
@@ -327,6 +447,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "bigserial_col".into(),
@@ -344,6 +471,13 @@ async fn all_postgres_column_types_must_work() {
                 SCHEMA
             ))),
             auto_increment: true,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "bit_col".into(),
@@ -358,6 +492,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -372,6 +513,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "box_col".into(),
@@ -386,6 +534,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "char_col".into(),
@@ -400,6 +555,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "circle_col".into(),
@@ -414,6 +576,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "line_col".into(),
@@ -428,6 +597,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "time_col".into(),
@@ -442,6 +618,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "timetz_col".into(),
@@ -456,6 +639,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "timestamp_col".into(),
@@ -471,6 +661,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "timestamptz_col".into(),
@@ -486,6 +683,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "lseg_col".into(),
@@ -500,6 +704,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "numeric_col".into(),
@@ -514,6 +725,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "path_col".into(),
@@ -528,6 +746,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -542,6 +767,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "polygon_col".into(),
@@ -556,6 +788,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "smallint_col".into(),
@@ -570,6 +809,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "smallserial_col".into(),
@@ -587,6 +833,13 @@ async fn all_postgres_column_types_must_work() {
                 SCHEMA
             ))),
             auto_increment: true,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "serial_col".into(),
@@ -604,6 +857,13 @@ async fn all_postgres_column_types_must_work() {
                 SCHEMA
             ))),
             auto_increment: true,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "tsquery_col".into(),
@@ -619,6 +879,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "tsvector_col".into(),
@@ -634,6 +901,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "txid_col".into(),
@@ -649,6 +923,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "json_col".into(),
@@ -664,6 +945,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "jsonb_col".into(),
@@ -679,6 +967,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "uuid_col".into(),
@@ -694,6 +989,13 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -707,6 +1009,13 @@ async fn all_postgres_column_types_must_work() {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                storage_parameters: Vec::new(),
+                expressions: Vec::new(),
+                algorithm: None,
+                invisible: false,
+                comment: None,
+                nulls_not_distinct: false,
+                opclasses: Vec::new(),
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
@@ -714,10 +1023,18 @@ async fn all_postgres_column_types_must_work() {
                     name: "User_primary_col_seq".into(),
                     initial_value: 1,
                     allocation_size: 1,
+                    owned: true,
                 },),
                 constraint_name: Some("User_pkey".into()),
             }),
             foreign_keys: vec![],
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
@@ -762,6 +1079,13 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city".into(),
@@ -776,6 +1100,13 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_cascade".into(),
@@ -790,6 +1121,13 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_restrict".into(),
@@ -804,6 +1142,13 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_set_null".into(),
@@ -818,6 +1163,13 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_set_default".into(),
@@ -832,6 +1184,13 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
             ],
             indices: vec![],
@@ -848,6 +1207,9 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".into(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_cascade_fkey".to_owned()),
@@ -856,6 +1218,9 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".into(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_restrict_fkey".to_owned()),
@@ -864,6 +1229,9 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".into(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_default_fkey".to_owned()),
@@ -872,6 +1240,9 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".into(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_null_fkey".to_owned()),
@@ -880,8 +1251,18 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".into(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
             ],
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
@@ -924,6 +1305,7 @@ async fn postgres_sequences_must_work() {
             name: "test".into(),
             initial_value: 1,
             allocation_size: 1,
+            owned: false,
         },
     );
 }
@@ -959,6 +1341,56 @@ async fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order() {
     assert_eq!(&index.columns, &["age", "name"]);
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn indexes_and_foreign_keys_are_described_in_a_deterministic_order(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."Owner" (id INTEGER PRIMARY KEY);
+            CREATE TABLE "{0}"."Breeder" (id INTEGER PRIMARY KEY);
+
+            CREATE TABLE "{0}"."Cat" (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                age INTEGER NOT NULL,
+                "personId" INTEGER NOT NULL,
+                CONSTRAINT "Cat_personId_owner_fkey" FOREIGN KEY ("personId") REFERENCES "{0}"."Owner"(id),
+                CONSTRAINT "Cat_personId_breeder_fkey" FOREIGN KEY ("personId") REFERENCES "{0}"."Breeder"(id)
+            );
+
+            CREATE INDEX "c_idx" ON "{0}"."Cat" (age);
+            CREATE INDEX "a_idx" ON "{0}"."Cat" (name);
+            CREATE INDEX "b_idx" ON "{0}"."Cat" (name, age);
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Cat");
+
+    let index_names: Vec<&str> = table.indices.iter().map(|index| index.name.as_str()).collect();
+
+    assert_eq!(index_names, &["a_idx", "b_idx", "c_idx"]);
+
+    let fk_names: Vec<Option<&str>> = table
+        .foreign_keys
+        .iter()
+        .map(|fk| fk.constraint_name.as_deref())
+        .collect();
+
+    assert_eq!(
+        fk_names,
+        &[Some("Cat_personId_breeder_fkey"), Some("Cat_personId_owner_fkey")]
+    );
+
+    for _ in 0..5 {
+        assert_eq!(api.describe().await?, schema);
+    }
+
+    Ok(())
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn escaped_quotes_in_string_defaults_must_be_unescaped(api: &TestApi) -> TestResult {
     let create_table = format!(
@@ -1036,3 +1468,225 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn columns_of_a_domain_type_are_described_with_the_base_type(api: &TestApi) -> TestResult {
+    let create_domain = format!(
+        r#"CREATE DOMAIN "{0}"."positive_int" AS INTEGER CHECK (VALUE > 0)"#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_domain, &[]).await?;
+
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."domains_test" (
+                id INTEGER PRIMARY KEY,
+                quantity "{0}"."positive_int" NOT NULL
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let column = schema.table_bang("domains_test").column_bang("quantity");
+
+    assert_eq!(column.tpe.family, ColumnTypeFamily::Int);
+    assert_eq!(column.tpe.full_data_type, "positive_int");
+    assert_eq!(column.tpe.arity, ColumnArity::Required);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn array_column_defaults_are_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."array_defaults_test" (
+                id INTEGER PRIMARY KEY,
+                literal_ints INTEGER[] NOT NULL DEFAULT '{{1,2,3}}',
+                constructed_ints INTEGER[] NOT NULL DEFAULT ARRAY[1, 2, 3],
+                texts TEXT[] NOT NULL DEFAULT ARRAY['meow', 'purr'],
+                empty_ints INTEGER[] NOT NULL DEFAULT '{{}}'
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("array_defaults_test");
+
+    let ints = PrismaValue::List(vec![PrismaValue::Int(1), PrismaValue::Int(2), PrismaValue::Int(3)]);
+    let texts = PrismaValue::List(vec![
+        PrismaValue::String("meow".to_owned()),
+        PrismaValue::String("purr".to_owned()),
+    ]);
+
+    let expected_defaults = &[
+        ("literal_ints", ints.clone()),
+        ("constructed_ints", ints),
+        ("texts", texts),
+        ("empty_ints", PrismaValue::List(Vec::new())),
+    ];
+
+    for (column_name, expected_default) in expected_defaults {
+        assert_eq!(
+            table.column_bang(column_name).default.as_ref(),
+            Some(&DefaultValue::VALUE(expected_default.clone()))
+        );
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn expression_indexes_are_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."expression_index_test" (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL
+            );
+
+            CREATE INDEX "expression_index_test_lower_name_idx" ON "{0}"."expression_index_test" (id, lower(name));
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_table).await?;
+
+    let schema = api.describe().await?;
+    let index = &schema.table_bang("expression_index_test").indices[0];
+
+    assert_eq!(index.name, "expression_index_test_lower_name_idx");
+    assert_eq!(index.columns, &["id"]);
+    assert_eq!(
+        index.expressions,
+        &[IndexExpression {
+            position: 1,
+            expression: "lower(name)".to_owned(),
+        }]
+    );
+    assert_eq!(
+        index.keys(),
+        &[IndexKey::Column("id"), IndexKey::Expression("lower(name)")]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn row_level_security_policies_are_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."policy_test" (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL
+            );
+
+            ALTER TABLE "{0}"."policy_test" ENABLE ROW LEVEL SECURITY;
+
+            CREATE POLICY "positive_ids" ON "{0}"."policy_test" FOR SELECT USING (id > 0);
+            CREATE POLICY "named_rows" ON "{0}"."policy_test" AS RESTRICTIVE FOR INSERT TO public WITH CHECK (name <> '');
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_table).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("policy_test");
+    let policies = &table.policies;
+
+    assert!(table.row_level_security);
+
+    assert_eq!(
+        policies,
+        &[
+            Policy {
+                name: "named_rows".to_owned(),
+                command: PolicyCommand::Insert,
+                permissive: false,
+                roles: vec!["public".to_owned()],
+                using: None,
+                with_check: Some("(name <> ''::text)".to_owned()),
+            },
+            Policy {
+                name: "positive_ids".to_owned(),
+                command: PolicyCommand::Select,
+                permissive: true,
+                roles: vec!["public".to_owned()],
+                using: Some("(id > 0)".to_owned()),
+                with_check: None,
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn row_level_security_is_only_described_where_enabled(api: &TestApi) -> TestResult {
+    let create_tables = format!(
+        r#"
+            CREATE TABLE "{0}"."secured" (id INTEGER PRIMARY KEY);
+            CREATE TABLE "{0}"."unsecured" (id INTEGER PRIMARY KEY);
+
+            ALTER TABLE "{0}"."secured" ENABLE ROW LEVEL SECURITY;
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_tables).await?;
+
+    let schema = api.describe().await?;
+
+    assert!(schema.table_bang("secured").row_level_security);
+    assert!(schema.table_bang("secured").policies.is_empty());
+    assert!(!schema.table_bang("unsecured").row_level_security);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn range_partitioned_tables_are_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."measurement" (
+                city_id INTEGER NOT NULL,
+                logged_at DATE NOT NULL,
+                PRIMARY KEY (city_id, logged_at)
+            ) PARTITION BY RANGE (logged_at);
+
+            CREATE TABLE "{0}"."measurement_2020" PARTITION OF "{0}"."measurement"
+                FOR VALUES FROM ('2020-01-01') TO ('2021-01-01');
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_table).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("measurement");
+
+    assert_eq!(
+        table.partitioning,
+        Some(TablePartitioning {
+            strategy: PartitionStrategy::Range,
+            key: "logged_at".to_owned(),
+        })
+    );
+    assert_eq!(
+        table.primary_key.as_ref().map(|pk| pk.columns.as_slice()),
+        Some(&["city_id".to_owned(), "logged_at".to_owned()][..])
+    );
+
+    // The partitions themselves are regular tables.
+    assert_eq!(schema.table_bang("measurement_2020").partitioning, None);
+
+    Ok(())
+}
@@ -38,6 +38,13 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: true,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "column2".to_string(),
@@ -52,6 +59,13 @@ fn database_schema_is_serializable() {
                         },
                         default: Some(DefaultValue::VALUE(PrismaValue::String("default value".to_string()))),
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                     Column {
                         name: "column3".to_string(),
@@ -66,12 +80,26 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: false,
+                        identity: None,
+                        identity_options: None,
+                        invisible: false,
+                        on_update_current_timestamp: false,
+                        default_constraint_name: None,
+                        collation: None,
+                        storage: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "column2".to_string(),
                     columns: vec!["column2".to_string()],
                     tpe: IndexType::Normal,
+                    storage_parameters: Vec::new(),
+                    expressions: Vec::new(),
+                    algorithm: None,
+                    invisible: false,
+                    comment: None,
+                    nulls_not_distinct: false,
+                    opclasses: Vec::new(),
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["column1".to_string()],
@@ -85,7 +113,17 @@ fn database_schema_is_serializable() {
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 }],
+                ddl: None,
+                collation: None,
+                check_constraints: Vec::new(),
+                policies: Vec::new(),
+                partitioning: None,
+                row_level_security: false,
+                without_rowid: false,
             },
             Table {
                 name: "table2".to_string(),
@@ -102,6 +140,13 @@ fn database_schema_is_serializable() {
                     },
                     default: None,
                     auto_increment: true,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -110,6 +155,13 @@ fn database_schema_is_serializable() {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                ddl: None,
+                collation: None,
+                check_constraints: Vec::new(),
+                policies: Vec::new(),
+                partitioning: None,
+                row_level_security: false,
+                without_rowid: false,
             },
         ],
         enums: vec![Enum {
@@ -120,7 +172,10 @@ fn database_schema_is_serializable() {
             name: "sequence1".to_string(),
             initial_value: 1,
             allocation_size: 32,
+            owned: false,
         }],
+        synonyms: Vec::new(),
+        flavour: None,
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -152,13 +207,29 @@ fn database_schema_without_primary_key_is_serializable() {
                 },
                 default: None,
                 auto_increment: false,
+                identity: None,
+                identity_options: None,
+                invisible: false,
+                on_update_current_timestamp: false,
+                default_constraint_name: None,
+                collation: None,
+                storage: None,
             }],
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }],
         enums: vec![],
         sequences: vec![],
+        synonyms: Vec::new(),
+        flavour: None,
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -199,6 +270,13 @@ fn database_schema_is_serializable_for_every_column_type_family() {
         },
         default: None,
         auto_increment: false,
+        identity: None,
+        identity_options: None,
+        invisible: false,
+        on_update_current_timestamp: false,
+        default_constraint_name: None,
+        collation: None,
+        storage: None,
     })
     .collect();
     let schema = SqlSchema {
@@ -208,9 +286,18 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }],
         enums: vec![],
         sequences: vec![],
+        synonyms: Vec::new(),
+        flavour: None,
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -243,6 +330,13 @@ fn database_schema_is_serializable_for_every_column_arity() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         })
         .collect();
     let schema = SqlSchema {
@@ -252,9 +346,18 @@ fn database_schema_is_serializable_for_every_column_arity() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }],
         enums: vec![],
         sequences: vec![],
+        synonyms: Vec::new(),
+        flavour: None,
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -287,6 +390,13 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                     default: None,
                 },
                 Column {
@@ -301,6 +411,13 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                     default: None,
                 },
                 Column {
@@ -315,6 +432,13 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                     default: None,
                 },
                 Column {
@@ -329,6 +453,13 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                     default: None,
                 },
                 Column {
@@ -343,6 +474,13 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                     default: None,
                 },
             ],
@@ -356,6 +494,9 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -364,6 +505,9 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -372,6 +516,9 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -380,6 +527,9 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -388,11 +538,23 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
             ],
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }],
         enums: vec![],
         sequences: vec![],
+        synonyms: Vec::new(),
+        flavour: None,
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
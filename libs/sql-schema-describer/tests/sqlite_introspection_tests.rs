@@ -40,6 +40,13 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "int4_col".to_string(),
@@ -53,6 +60,13 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -66,6 +80,13 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "real_col".to_string(),
@@ -79,6 +100,13 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "primary_col".to_string(),
@@ -92,6 +120,13 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: true,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -105,6 +140,13 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
         },
     ];
 
@@ -120,6 +162,13 @@ async fn sqlite_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
@@ -159,6 +208,13 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: true,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -172,6 +228,13 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -185,6 +248,13 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -198,6 +268,13 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -211,6 +288,13 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -225,6 +309,13 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
+                    identity_options: None,
+                    invisible: false,
+                    on_update_current_timestamp: false,
+                    default_constraint_name: None,
+                    collation: None,
+                    storage: None,
                 },
             ],
             indices: vec![],
@@ -241,6 +332,9 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -249,6 +343,9 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -257,6 +354,9 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -265,6 +365,9 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -273,8 +376,18 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
+                    deferrable: false,
+                    initially_deferred: false,
+                    match_full: false,
                 },
             ],
+            ddl: None,
+            collation: None,
+            check_constraints: Vec::new(),
+            policies: Vec::new(),
+            partitioning: None,
+            row_level_security: false,
+            without_rowid: false,
         }
     );
 }
@@ -395,3 +508,72 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[tokio::test]
+async fn sqlite_expression_indexes_are_described() {
+    let sql = r#"
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+        CREATE INDEX "Cat_lower_name_idx" ON "Cat" (id, lower("name") DESC);
+    "#;
+
+    let inspector = get_sqlite_describer(sql, "sqlite_expression_indexes_are_described").await;
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let index = &schema.table_bang("Cat").indices[0];
+
+    assert_eq!(index.name, "Cat_lower_name_idx");
+    assert_eq!(
+        index.keys(),
+        &[IndexKey::Column("id"), IndexKey::Expression(r#"lower("name")"#)]
+    );
+}
+
+#[tokio::test]
+async fn sqlite_table_ddl_is_included_on_request() {
+    let sql = r#"CREATE TABLE "User" (id INTEGER PRIMARY KEY, name TEXT NOT NULL)"#;
+
+    let inspector = get_sqlite_describer(sql, "sqlite_table_ddl_is_included_on_request").await;
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+
+    assert_eq!(schema.table_bang("User").ddl, None);
+
+    let inspector = inspector.include_ddl(true);
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+
+    assert_eq!(schema.table_bang("User").ddl.as_deref(), Some(sql));
+}
+
+#[tokio::test]
+async fn sqlite_describe_result_round_trips_through_versioned_json() {
+    let sql = r#"
+        CREATE TABLE "Box" (id INTEGER PRIMARY KEY, material TEXT NOT NULL DEFAULT 'cardboard');
+        CREATE TABLE "Cat" (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            box_id INTEGER REFERENCES "Box" (id) ON DELETE CASCADE
+        );
+        CREATE UNIQUE INDEX "Cat_name_key" ON "Cat" (name);
+    "#;
+
+    let inspector = get_sqlite_describer(sql, "sqlite_describe_result_round_trips_through_versioned_json").await;
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+
+    let json = schema.to_versioned_json().unwrap();
+    let deserialized = SqlSchema::from_versioned_json(&json).unwrap();
+
+    assert_eq!(deserialized, schema);
+
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(json["version"], SQL_SCHEMA_JSON_VERSION);
+
+    let mut future_json = json;
+    future_json["version"] = (SQL_SCHEMA_JSON_VERSION + 1).into();
+
+    let err = SqlSchema::from_versioned_json(&future_json.to_string()).unwrap_err();
+
+    assert!(matches!(
+        err,
+        SqlSchemaJsonError::VersionMismatch { expected, found }
+            if expected == SQL_SCHEMA_JSON_VERSION && found == SQL_SCHEMA_JSON_VERSION + 1
+    ));
+}
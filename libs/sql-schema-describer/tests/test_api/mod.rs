@@ -24,15 +24,18 @@ pub struct TestApi {
 
 impl TestApi {
     pub(crate) async fn describe(&self) -> Result<SqlSchema, anyhow::Error> {
+        Ok(self.describer().describe(self.schema_name()).await?)
+    }
+
+    pub(crate) fn describer(&self) -> Box<dyn sql_schema_describer::SqlSchemaDescriberBackend> {
         let db = self.database.clone();
-        let describer: Box<dyn sql_schema_describer::SqlSchemaDescriberBackend> = match self.sql_family() {
+
+        match self.sql_family() {
             SqlFamily::Postgres => Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(db)),
             SqlFamily::Sqlite => Box::new(sql_schema_describer::sqlite::SqlSchemaDescriber::new(db)),
             SqlFamily::Mysql => Box::new(sql_schema_describer::mysql::SqlSchemaDescriber::new(db)),
             SqlFamily::Mssql => Box::new(sql_schema_describer::mssql::SqlSchemaDescriber::new(db)),
-        };
-
-        Ok(describer.describe(self.schema_name()).await?)
+        }
     }
 
     pub(crate) fn db_name(&self) -> &'static str {
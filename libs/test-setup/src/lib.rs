@@ -31,7 +31,7 @@ pub struct TestAPIArgs {
 }
 
 impl TestAPIArgs {
-    pub fn new(name: &'static str, tags: u8) -> Self {
+    pub fn new(name: &'static str, tags: u16) -> Self {
         let tags: BitFlags<Tags> = BitFlags::from_bits(tags).unwrap();
         TestAPIArgs {
             test_function_name: name,
@@ -161,6 +161,33 @@ async fn test_create_sqlite_database() {
     assert!(sqlite_path.exists());
 }
 
+#[tokio::test]
+async fn test_connecting_to_a_sqlite_database_in_a_nonexisting_directory_creates_it() {
+    let base_dir = tempfile::tempdir().unwrap();
+    let sqlite_path = base_dir.path().join("prisma/nested").join("dev.db");
+    let url = format!("file:{}", sqlite_path.to_string_lossy());
+
+    run(&["--datasource", &url, "can-connect-to-database"]).await.unwrap();
+
+    assert!(sqlite_path.exists());
+}
+
+#[tokio::test]
+async fn test_create_sqlite_database_in_an_unwritable_location_fails_with_a_clear_error() {
+    let base_dir = tempfile::tempdir().unwrap();
+    let file_path = base_dir.path().join("not_a_directory");
+    std::fs::write(&file_path, "").unwrap();
+
+    let sqlite_path = file_path.join("nested").join("dev.db");
+    let url = format!("file:{}", sqlite_path.to_string_lossy());
+
+    let err = run(&["--datasource", &url, "create-database"]).await.unwrap_err();
+    let msg = err.to_string();
+
+    assert!(msg.contains("Failed to create the directory"), "{}", msg);
+    assert!(msg.contains(&*sqlite_path.to_string_lossy()), "{}", msg);
+}
+
 #[tokio::test]
 async fn test_drop_sqlite_database() {
     let base_dir = tempfile::tempdir().unwrap();
@@ -18,11 +18,17 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
     /// database as a starting point.
     fn infer_from_empty(&self, next: &Datamodel) -> ConnectorResult<T>;
 
+    /// Infer the database migration steps from a serialized snapshot of the
+    /// database schema to the next datamodel, without looking at the database.
+    /// The format of the snapshot is defined by the connector.
+    fn infer_from_snapshot(&self, snapshot: &str, next: &Datamodel) -> ConnectorResult<T>;
+
     /// Infer a database migration based on the previous and next datamodels.
     /// The method signature is identical to `infer`, but it is expected that
     /// this method is implemented based on the provided previous datamodel, and
-    /// does not rely on the current state of the database.
-    fn infer_from_datamodels(
+    /// only looks at the database for what the datamodels cannot tell, like the
+    /// names the database gave to constraints.
+    async fn infer_from_datamodels(
         &self,
         previous: &Datamodel,
         next: &Datamodel,
@@ -13,7 +13,11 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>>;
 
     /// Render the migration to a runnable script.
-    fn render_script(&self, database_migration: &T, diagnostics: &DestructiveChangeDiagnostics) -> String;
+    fn render_script(
+        &self,
+        database_migration: &T,
+        diagnostics: &DestructiveChangeDiagnostics,
+    ) -> ConnectorResult<String>;
 
     /// Apply a migration script to the database. The migration persistence is
     /// managed by the core.
@@ -28,4 +32,8 @@ pub struct PrettyDatabaseMigrationStep {
     pub step: serde_json::Value,
     /// The raw query string.
     pub raw: String,
+    /// Whether the step may lock a table while it runs, blocking writes to it.
+    /// This is a heuristic, based on the kind of step and the database.
+    #[serde(default, rename = "lockHint")]
+    pub lock_hint: bool,
 }
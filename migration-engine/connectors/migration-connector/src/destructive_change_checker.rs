@@ -16,9 +16,45 @@ where
     /// Check destructive changes resulting of applying the provided migration.
     async fn check(&self, database_migration: &T) -> ConnectorResult<DestructiveChangeDiagnostics>;
 
+    /// Same as `check()`, but also count the rows affected by each warning in
+    /// the database. This can require additional queries.
+    async fn check_with_row_counts(&self, database_migration: &T) -> ConnectorResult<DestructiveChangeDiagnostics> {
+        self.check(database_migration).await
+    }
+
     /// Check the database migration for destructive or unexecutable steps
     /// without performing any IO.
     fn pure_check(&self, database_migration: &T) -> DestructiveChangeDiagnostics;
+
+    /// Dispatch to `check()` or `pure_check()`, depending on the mode.
+    async fn check_with_mode(
+        &self,
+        database_migration: &T,
+        mode: CheckerMode,
+    ) -> ConnectorResult<DestructiveChangeDiagnostics> {
+        match mode {
+            CheckerMode::Online => self.check(database_migration).await,
+            CheckerMode::Offline => Ok(self.pure_check(database_migration)),
+        }
+    }
+}
+
+/// Whether the destructive change checker may query the database.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CheckerMode {
+    /// Query the database, for example to only warn about dropping tables that
+    /// contain data.
+    Online,
+    /// Only look at the migration, assuming all tables may contain data. This
+    /// is useful when generating migrations offline, or in CI.
+    Offline,
+}
+
+impl Default for CheckerMode {
+    fn default() -> Self {
+        CheckerMode::Online
+    }
 }
 
 /// The errors and warnings emitted by the [DestructiveChangeChecker](trait.DestructiveChangeChecker.html).
@@ -57,6 +93,10 @@ pub struct MigrationWarning {
     pub description: String,
     /// The index of the step in the migration that this warning applies to.
     pub step_index: usize,
+    /// The number of rows in the database affected by the change, if it was
+    /// counted. See `DestructiveChangeChecker::check_with_row_counts()`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub affected_rows_count: Option<i64>,
 }
 
 /// An unexecutable migration step detected by the DestructiveChangeChecker.
@@ -90,6 +90,16 @@ impl ConnectorError {
         }
     }
 
+    /// Construct an error for a migration step that cannot be rendered for the
+    /// given database flavour.
+    pub fn unsupported_step(step: &str, flavour: &str) -> Self {
+        Self::generic(anyhow::anyhow!(
+            "The `{}` migration step is not supported on {}.",
+            step,
+            flavour
+        ))
+    }
+
     /// Construct an UrlParseError.
     pub fn url_parse_error(err: impl Display, url: &str) -> Self {
         Self::generic(anyhow::anyhow!("{} in `{}`", err, url))
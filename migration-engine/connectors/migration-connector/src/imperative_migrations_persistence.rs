@@ -41,6 +41,31 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
     /// back, so they will be ignored by the engine in the future.
     async fn mark_migration_rolled_back_by_id(&self, migration_id: &str) -> ConnectorResult<()>;
 
+    /// Replace the records of squashed migrations with the record of the
+    /// migration they were squashed into, marked as applied. This must happen
+    /// atomically: either all the records are replaced, or none is.
+    ///
+    /// This is a default method that computes the checksum. Implementors should
+    /// implement mark_migrations_squashed_impl.
+    async fn mark_migrations_squashed(
+        &self,
+        squashed_migration_ids: &[String],
+        migration_name: &str,
+        script: &str,
+    ) -> ConnectorResult<String> {
+        self.mark_migrations_squashed_impl(squashed_migration_ids, migration_name, script, &checksum(script))
+            .await
+    }
+
+    /// Implementation in the connector for `mark_migrations_squashed()`.
+    async fn mark_migrations_squashed_impl(
+        &self,
+        squashed_migration_ids: &[String],
+        migration_name: &str,
+        script: &str,
+        checksum: &str,
+    ) -> ConnectorResult<String>;
+
     /// Record that a migration is about to be applied. Returns the unique
     /// identifier for the migration.
     ///
@@ -77,6 +102,13 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
     /// List all applied migrations, ordered by `started_at`. This should fail
     /// hard if the migration persistence is not initialized.
     async fn list_migrations(&self) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>>;
+
+    /// Find the migration record with the given checksum, to check whether a
+    /// specific migration script was already applied, under any name. If
+    /// several records share the checksum, the most recently started one is
+    /// returned. Returns `None` if the migration persistence is not
+    /// initialized.
+    async fn find_migration_by_checksum(&self, checksum: &str) -> ConnectorResult<Option<MigrationRecord>>;
 }
 
 /// Error returned when the persistence is not initialized.
@@ -55,6 +55,18 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Drop all database state.
     async fn reset(&self) -> ConnectorResult<()>;
 
+    /// Acquire a lock on the database, so that concurrent runs of the migration engine against
+    /// the same database wait for each other instead of interleaving. The lock is held until
+    /// `release_lock()` is called.
+    async fn acquire_lock(&self) -> ConnectorResult<()> {
+        Ok(())
+    }
+
+    /// Release the lock taken with `acquire_lock()`.
+    async fn release_lock(&self) -> ConnectorResult<()> {
+        Ok(())
+    }
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(
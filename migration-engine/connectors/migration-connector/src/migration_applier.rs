@@ -24,6 +24,7 @@ impl<'a, T> MigrationApplier<T> for MigrationApplierImpl<'a, T>
 where
     T: Send + Sync + 'static,
 {
+    #[tracing::instrument(skip(self, migration, database_migration), fields(migration_name = migration.name.as_str()))]
     async fn apply(&self, migration: &Migration, database_migration: &T) -> ConnectorResult<()> {
         assert_eq!(migration.status, MigrationStatus::Pending); // what other states are valid here?
         let mut migration_updates = migration.update_params();
@@ -47,6 +48,7 @@ where
         }
     }
 
+    #[tracing::instrument(skip(self, migration, database_migration), fields(migration_name = migration.name.as_str()))]
     async fn unapply(&self, migration: &Migration, database_migration: &T) -> ConnectorResult<()> {
         assert_eq!(migration.status, MigrationStatus::MigrationSuccess); // what other states are valid here?
         let mut migration_updates = migration.update_params();
@@ -5,12 +5,17 @@ use quaint::{
     prelude::{ConnectionInfo, Query, Queryable, ResultSet},
     single::Quaint,
 };
+use std::time::Duration;
 
 /// An internal helper for the SQL connector. It wraps a `Quaint` struct and
 /// exposes a similar API, with additional error handling to return
 /// `ConnectorResult`s.
 #[derive(Clone, Debug)]
-pub(crate) struct Connection(Quaint);
+pub(crate) struct Connection {
+    quaint: Quaint,
+    /// How long describing the schema may take. See `describe_timeout_from_connection_string()`.
+    describe_timeout: Option<Duration>,
+}
 
 #[derive(Debug)]
 pub(crate) struct ConnectionError<'a> {
@@ -34,15 +39,28 @@ impl From<ConnectionError<'_>> for ConnectorError {
 
 impl Connection {
     pub(crate) fn new(quaint: Quaint) -> Self {
-        Connection(quaint)
+        Connection {
+            quaint,
+            describe_timeout: None,
+        }
+    }
+
+    /// Fail the schema descriptions on this connection when they take longer than `timeout`.
+    pub(crate) fn with_describe_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.describe_timeout = timeout;
+        self
+    }
+
+    pub(crate) fn describe_timeout(&self) -> Option<Duration> {
+        self.describe_timeout
     }
 
     pub(crate) fn connection_info(&self) -> &ConnectionInfo {
-        self.0.connection_info()
+        self.quaint.connection_info()
     }
 
     pub(crate) async fn execute(&self, query: impl Into<Query<'_>>) -> ConnectionResult<'_, u64> {
-        self.0
+        self.quaint
             .execute(query.into())
             .await
             .map_err(|quaint_error| ConnectionError {
@@ -52,11 +70,11 @@ impl Connection {
     }
 
     pub(crate) fn quaint(&self) -> &Quaint {
-        &self.0
+        &self.quaint
     }
 
     pub(crate) async fn query(&self, query: impl Into<Query<'_>>) -> ConnectionResult<'_, ResultSet> {
-        self.0
+        self.quaint
             .query(query.into())
             .await
             .map_err(|quaint_error| ConnectionError {
@@ -66,7 +84,7 @@ impl Connection {
     }
 
     pub(crate) async fn query_raw(&self, sql: &str, params: &[quaint::Value<'_>]) -> ConnectionResult<'_, ResultSet> {
-        self.0
+        self.quaint
             .query_raw(sql, params)
             .await
             .map_err(|quaint_error| ConnectionError {
@@ -76,14 +94,14 @@ impl Connection {
     }
 
     pub(crate) async fn raw_cmd(&self, sql: &str) -> ConnectionResult<'_, ()> {
-        self.0.raw_cmd(sql).await.map_err(|quaint_error| ConnectionError {
+        self.quaint.raw_cmd(sql).await.map_err(|quaint_error| ConnectionError {
             quaint_error,
             connection_info: self.connection_info(),
         })
     }
 
     pub(crate) async fn version(&self) -> ConnectionResult<'_, Option<String>> {
-        self.0.version().await.map_err(|quaint_error| ConnectionError {
+        self.quaint.version().await.map_err(|quaint_error| ConnectionError {
             quaint_error,
             connection_info: self.connection_info(),
         })
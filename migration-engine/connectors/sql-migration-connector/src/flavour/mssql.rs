@@ -1,4 +1,8 @@
-use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, SqlFlavour};
+use super::MIGRATION_LOCK_TIMEOUT;
+use crate::{
+    connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, sql_renderer::SqlRenderer,
+    SqlFlavour,
+};
 use connection_string::JdbcString;
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
 use quaint::{connector::MssqlUrl, prelude::SqlFamily};
@@ -6,11 +10,27 @@ use sql_schema_describer::{DescriberErrorKind, SqlSchema, SqlSchemaDescriberBack
 use std::str::FromStr;
 
 #[derive(Debug)]
-pub(crate) struct MssqlFlavour(pub(crate) MssqlUrl);
+pub(crate) struct MssqlFlavour {
+    pub(crate) url: MssqlUrl,
+    /// Whether foreign keys are created `WITH NOCHECK`. See
+    /// `SqlFlavour::enable_unchecked_foreign_keys()`.
+    pub(super) unchecked_foreign_keys: bool,
+    /// Whether the foreign keys created `WITH NOCHECK` are validated by a following
+    /// `WITH CHECK CHECK CONSTRAINT` statement.
+    pub(super) validate_unchecked_foreign_keys: bool,
+}
 
 impl MssqlFlavour {
     pub(crate) fn schema_name(&self) -> &str {
-        self.0.schema()
+        self.url.schema()
+    }
+
+    pub(crate) fn unchecked_foreign_keys(&self) -> bool {
+        self.unchecked_foreign_keys
+    }
+
+    pub(crate) fn validate_unchecked_foreign_keys(&self) -> bool {
+        self.unchecked_foreign_keys && self.validate_unchecked_foreign_keys
     }
 
     /// Get the url as a JDBC string, extract the database name, and re-encode the string.
@@ -22,10 +42,43 @@ impl MssqlFlavour {
         let db_name = params.remove("database").unwrap_or_else(|| String::from("master"));
         Ok((db_name, conn.to_string()))
     }
+
+    /// Application locks are scoped to the database, so the resource name
+    /// includes the schema name.
+    fn lock_resource(&self) -> String {
+        format!("prisma_migrate_{}", self.schema_name())
+    }
 }
 
 #[async_trait::async_trait]
 impl SqlFlavour for MssqlFlavour {
+    async fn acquire_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = r#"
+            DECLARE @result INT;
+            EXEC @result = sp_getapplock @Resource = @P1, @LockMode = 'Exclusive', @LockOwner = 'Session', @LockTimeout = @P2;
+            SELECT @result;
+        "#;
+
+        let result = connection
+            .query_raw(
+                sql,
+                &[
+                    self.lock_resource().into(),
+                    (MIGRATION_LOCK_TIMEOUT.as_millis() as i64).into(),
+                ],
+            )
+            .await?;
+
+        // sp_getapplock returns a negative status code when the lock could not be granted.
+        match result.get(0).and_then(|row| row.at(0).and_then(|value| value.as_i64())) {
+            Some(status) if status >= 0 => Ok(()),
+            _ => Err(ConnectorError::generic(anyhow::anyhow!(
+                "Timed out trying to acquire the migration lock on the SQL Server database after {} seconds.",
+                MIGRATION_LOCK_TIMEOUT.as_secs()
+            ))),
+        }
+    }
+
     async fn create_database(&self, jdbc_string: &str) -> ConnectorResult<String> {
         let (db_name, master_uri) = Self::master_url(jdbc_string)?;
         let conn = connect(&master_uri.to_string()).await?;
@@ -41,9 +94,14 @@ impl SqlFlavour for MssqlFlavour {
         Ok(db_name)
     }
 
-    async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = r#"
-            CREATE TABLE [_prisma_migrations] (
+    async fn create_imperative_migrations_table(
+        &self,
+        connection: &Connection,
+        table_name: &str,
+    ) -> ConnectorResult<()> {
+        let sql = format!(
+            r#"
+            CREATE TABLE {} (
                 id                      VARCHAR(36) PRIMARY KEY NOT NULL,
                 checksum                VARCHAR(64) NOT NULL,
                 finished_at             DATETIMEOFFSET,
@@ -54,22 +112,35 @@ impl SqlFlavour for MssqlFlavour {
                 applied_steps_count     INT NOT NULL DEFAULT 0,
                 script                  NVARCHAR(MAX) NOT NULL
             );
-        "#;
+        "#,
+            self.quote(table_name)
+        );
 
-        Ok(connection.raw_cmd(sql).await?)
+        Ok(connection.raw_cmd(&sql).await?)
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::mssql::SqlSchemaDescriber::new(connection.quaint().clone())
-            .describe(connection.connection_info().schema_name())
+            .describe_with_timeout(
+                connection.connection_info().schema_name(),
+                connection.describe_timeout(),
+            )
             .await
             .map_err(|err| match err.into_kind() {
                 DescriberErrorKind::QuaintError(err) => {
                     quaint_error_to_connector_error(err, connection.connection_info())
                 }
+                kind @ DescriberErrorKind::InvalidIndexColumns { .. }
+                | kind @ DescriberErrorKind::PermissionDenied { .. }
+                | kind @ DescriberErrorKind::Timeout { .. } => ConnectorError::generic(anyhow::anyhow!("{}", kind)),
             })
     }
 
+    fn enable_unchecked_foreign_keys(&mut self, validate: bool) {
+        self.unchecked_foreign_keys = true;
+        self.validate_unchecked_foreign_keys = validate;
+    }
+
     async fn drop_database(&self, _database_url: &str) -> ConnectorResult<()> {
         let features = vec!["microsoftSqlServer".into()];
         return Err(ConnectorError::user_facing_error(
@@ -79,6 +150,27 @@ impl SqlFlavour for MssqlFlavour {
 
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let schema_name = connection.connection_info().schema_name();
+
+        // Triggers are dropped first, so they cannot fire while we drop the
+        // foreign keys and tables.
+        let drop_triggers = format!(
+            r#"
+            DECLARE @stmt NVARCHAR(max)
+            DECLARE @n CHAR(1)
+
+            SET @n = CHAR(10)
+
+            SELECT @stmt = ISNULL(@stmt + @n, '') +
+                'DROP TRIGGER [' + SCHEMA_NAME(objects.schema_id) + '].[' + triggers.name + ']'
+            FROM sys.triggers AS triggers
+            INNER JOIN sys.objects AS objects ON objects.object_id = triggers.object_id
+            WHERE triggers.parent_class = 1 AND SCHEMA_NAME(objects.schema_id) = '{0}'
+
+            EXEC SP_EXECUTESQL @stmt
+            "#,
+            schema_name
+        );
+
         let drop_fks = format!(
             r#"
             DECLARE @stmt NVARCHAR(max)
@@ -113,6 +205,7 @@ impl SqlFlavour for MssqlFlavour {
             schema_name
         );
 
+        connection.raw_cmd(&drop_triggers).await?;
         connection.raw_cmd(&drop_fks).await?;
         connection.raw_cmd(&drop_tables).await?;
 
@@ -147,6 +240,17 @@ impl SqlFlavour for MssqlFlavour {
         Ok(())
     }
 
+    async fn release_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        connection
+            .query_raw(
+                "EXEC sp_releaseapplock @Resource = @P1, @LockOwner = 'Session'",
+                &[self.lock_resource().into()],
+            )
+            .await?;
+
+        Ok(())
+    }
+
     async fn ensure_connection_validity(&self, connection: &Connection) -> ConnectorResult<()> {
         connection.raw_cmd("SELECT 1").await?;
 
@@ -1,8 +1,9 @@
-use super::SqlFlavour;
+use super::{redact_connection_string, SqlFlavour, MIGRATION_LOCK_TIMEOUT};
 use crate::{
     connect,
     connection_wrapper::Connection,
     error::{quaint_error_to_connector_error, SystemDatabase},
+    sql_renderer::SqlRenderer,
 };
 use datamodel::{walkers::walk_scalar_fields, Datamodel};
 use enumflags2::BitFlags;
@@ -34,11 +35,35 @@ impl MysqlFlavour {
             .contains(Circumstances::IsMysql56)
     }
 
+    pub(crate) fn is_mysql_8(&self) -> bool {
+        BitFlags::<Circumstances>::from_bits(self.circumstances.load(Ordering::Relaxed))
+            .unwrap_or_default()
+            .contains(Circumstances::IsMysql8)
+    }
+
+    /// The collation of created tables, when it is not specified on the table.
+    pub(crate) fn default_table_collation(&self) -> &'static str {
+        if self.is_mysql_8() {
+            "utf8mb4_0900_ai_ci"
+        } else {
+            "utf8mb4_unicode_ci"
+        }
+    }
+
     pub(crate) fn lower_cases_table_names(&self) -> bool {
         BitFlags::<Circumstances>::from_bits(self.circumstances.load(Ordering::Relaxed))
             .unwrap_or_default()
             .contains(Circumstances::LowerCasesTableNames)
     }
+
+    /// User-level locks are server-wide, so the lock name includes the
+    /// database name. Lock names can't be longer than 64 characters.
+    fn lock_name(&self) -> String {
+        format!("prisma_migrate_{}", self.url.dbname())
+            .chars()
+            .take(64)
+            .collect()
+    }
 }
 
 #[async_trait::async_trait]
@@ -73,8 +98,30 @@ impl SqlFlavour for MysqlFlavour {
         }
     }
 
+    async fn acquire_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        let result = connection
+            .query_raw(
+                "SELECT GET_LOCK(?, ?)",
+                &[
+                    self.lock_name().into(),
+                    (MIGRATION_LOCK_TIMEOUT.as_secs() as i64).into(),
+                ],
+            )
+            .await?;
+
+        // GET_LOCK returns 1 if the lock was obtained, 0 on timeout and NULL on error.
+        match result.get(0).and_then(|row| row.at(0).and_then(|value| value.as_i64())) {
+            Some(1) => Ok(()),
+            _ => Err(ConnectorError::generic(anyhow::anyhow!(
+                "Timed out trying to acquire the migration lock on the MySQL database after {} seconds.",
+                MIGRATION_LOCK_TIMEOUT.as_secs()
+            ))),
+        }
+    }
+
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
-        let mut url = Url::parse(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+        let mut url = Url::parse(database_str)
+            .map_err(|err| ConnectorError::url_parse_error(err, &redact_connection_string(database_str)))?;
         url.set_path("/mysql");
 
         let conn = connect(&url.to_string()).await?;
@@ -90,9 +137,14 @@ impl SqlFlavour for MysqlFlavour {
         Ok(db_name.to_owned())
     }
 
-    async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = r#"
-            CREATE TABLE _prisma_migrations (
+    async fn create_imperative_migrations_table(
+        &self,
+        connection: &Connection,
+        table_name: &str,
+    ) -> ConnectorResult<()> {
+        let sql = format!(
+            r#"
+            CREATE TABLE {} (
                 id                      VARCHAR(36) PRIMARY KEY NOT NULL,
                 checksum                VARCHAR(64) NOT NULL,
                 finished_at             DATETIME(3),
@@ -103,19 +155,27 @@ impl SqlFlavour for MysqlFlavour {
                 applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0,
                 script                  TEXT NOT NULL
             ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
-        "#;
+        "#,
+            self.quote(table_name)
+        );
 
-        Ok(connection.raw_cmd(sql).await?)
+        Ok(connection.raw_cmd(&sql).await?)
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::mysql::SqlSchemaDescriber::new(connection.quaint().clone())
-            .describe(connection.connection_info().schema_name())
+            .describe_with_timeout(
+                connection.connection_info().schema_name(),
+                connection.describe_timeout(),
+            )
             .await
             .map_err(|err| match err.into_kind() {
                 DescriberErrorKind::QuaintError(err) => {
                     quaint_error_to_connector_error(err, connection.connection_info())
                 }
+                kind @ DescriberErrorKind::InvalidIndexColumns { .. }
+                | kind @ DescriberErrorKind::PermissionDenied { .. }
+                | kind @ DescriberErrorKind::Timeout { .. } => ConnectorError::generic(anyhow::anyhow!("{}", kind)),
             })
     }
 
@@ -153,8 +213,10 @@ impl SqlFlavour for MysqlFlavour {
                 circumstances |= Circumstances::IsMysql56;
             }
 
-            if version.contains("MariaDB") {
+            if sql_schema_describer::mysql::is_mariadb(&version) {
                 circumstances |= Circumstances::IsMariadb;
+            } else if sql_schema_describer::mysql::is_mysql_8(&version) {
+                circumstances |= Circumstances::IsMysql8;
             }
         }
 
@@ -181,7 +243,8 @@ impl SqlFlavour for MysqlFlavour {
     }
 
     async fn qe_setup(&self, database_str: &str) -> ConnectorResult<()> {
-        let mut url = Url::parse(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+        let mut url = Url::parse(database_str)
+            .map_err(|err| ConnectorError::url_parse_error(err, &redact_connection_string(database_str)))?;
         url.set_path("/mysql");
 
         let conn = connect(&url.to_string()).await?;
@@ -199,6 +262,14 @@ impl SqlFlavour for MysqlFlavour {
         Ok(())
     }
 
+    async fn release_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        connection
+            .query_raw("SELECT RELEASE_LOCK(?)", &[self.lock_name().into()])
+            .await?;
+
+        Ok(())
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let db_name = connection.connection_info().dbname().unwrap();
 
@@ -230,7 +301,10 @@ impl SqlFlavour for MysqlFlavour {
         temporary_database_url.set_path(&format!("/{}", database_name));
         let temporary_database_url = temporary_database_url.to_string();
 
-        tracing::debug!("Connecting to temporary database at {:?}", temporary_database_url);
+        tracing::debug!(
+            "Connecting to temporary database at {:?}",
+            redact_connection_string(&temporary_database_url)
+        );
 
         let temp_database = crate::connect(&temporary_database_url).await?;
 
@@ -265,6 +339,7 @@ pub enum Circumstances {
     LowerCasesTableNames = 0b0001,
     IsMysql56 = 0b0010,
     IsMariadb = 0b0100,
+    IsMysql8 = 0b1000,
 }
 
 fn check_datamodel_for_mysql_5_6(datamodel: &Datamodel, errors: &mut Vec<String>) {
@@ -1,25 +1,74 @@
-use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, SqlFlavour};
+use super::{redact_connection_string, MIGRATION_LOCK_TIMEOUT};
+use crate::{
+    connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, sql_renderer::SqlRenderer,
+    SqlFlavour,
+};
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
-use quaint::{connector::PostgresUrl, error::ErrorKind as QuaintKind, prelude::SqlFamily};
+use quaint::{
+    connector::{PostgresUrl, Queryable, TransactionCapable},
+    error::{Error as QuaintError, ErrorKind as QuaintKind},
+    prelude::SqlFamily,
+};
 use sql_schema_describer::{DescriberErrorKind, SqlSchema, SqlSchemaDescriberBackend};
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
 use url::Url;
 use user_facing_errors::{common::DatabaseDoesNotExist, migration_engine, UserFacingError};
 
+/// The first key of the advisory lock taken by the migration engine. Advisory locks are scoped
+/// to the current database, and the second key is the hash of the schema name, so migrations of
+/// different schemas do not wait for each other.
+const ADVISORY_LOCK_KEY: i32 = 72707369;
+
 #[derive(Debug)]
-pub(crate) struct PostgresFlavour(pub(crate) PostgresUrl);
+pub(crate) struct PostgresFlavour {
+    pub(crate) url: PostgresUrl,
+    /// Whether the storage settings of the columns are described. See
+    /// `SqlFlavour::enable_column_storage()`.
+    pub(super) column_storage: bool,
+}
 
 impl PostgresFlavour {
     pub(crate) fn schema_name(&self) -> &str {
-        self.0.schema()
+        self.url.schema()
     }
 }
 
 #[async_trait::async_trait]
 impl SqlFlavour for PostgresFlavour {
+    async fn acquire_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = format!("SELECT pg_try_advisory_lock({}, hashtext($1))", ADVISORY_LOCK_KEY);
+        let started_at = Instant::now();
+
+        loop {
+            let result = connection.query_raw(&sql, &[self.schema_name().into()]).await?;
+            let locked = result
+                .get(0)
+                .and_then(|row| row.at(0).and_then(|value| value.as_bool()));
+
+            if locked == Some(true) {
+                return Ok(());
+            }
+
+            if started_at.elapsed() >= MIGRATION_LOCK_TIMEOUT {
+                return Err(ConnectorError::generic(anyhow::anyhow!(
+                    "Timed out trying to acquire the migration lock on the Postgres schema `{}` after {} seconds.",
+                    self.schema_name(),
+                    MIGRATION_LOCK_TIMEOUT.as_secs()
+                )));
+            }
+
+            // pg_try_advisory_lock does not wait, so poll until the lock is released.
+            tokio::time::delay_for(Duration::from_secs(1)).await
+        }
+    }
+
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
-        let mut url = Url::parse(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
-        let db_name = self.0.dbname();
+        let mut url = Url::parse(database_str)
+            .map_err(|err| ConnectorError::url_parse_error(err, &redact_connection_string(database_str)))?;
+        let db_name = self.url.dbname();
 
         strip_schema_param_from_url(&mut url);
 
@@ -56,9 +105,14 @@ impl SqlFlavour for PostgresFlavour {
         Ok(db_name.to_owned())
     }
 
-    async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = r#"
-            CREATE TABLE _prisma_migrations (
+    async fn create_imperative_migrations_table(
+        &self,
+        connection: &Connection,
+        table_name: &str,
+    ) -> ConnectorResult<()> {
+        let sql = format!(
+            r#"
+            CREATE TABLE {} (
                 id                      VARCHAR(36) PRIMARY KEY NOT NULL,
                 checksum                VARCHAR(64) NOT NULL,
                 finished_at             TIMESTAMPTZ,
@@ -69,24 +123,38 @@ impl SqlFlavour for PostgresFlavour {
                 applied_steps_count     INTEGER NOT NULL DEFAULT 0,
                 script                  TEXT NOT NULL
             );
-        "#;
+        "#,
+            self.quote(table_name)
+        );
 
-        Ok(connection.raw_cmd(sql).await?)
+        Ok(connection.raw_cmd(&sql).await?)
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::postgres::SqlSchemaDescriber::new(connection.quaint().clone())
-            .describe(connection.connection_info().schema_name())
+            .include_column_storage(self.column_storage)
+            .describe_with_timeout(
+                connection.connection_info().schema_name(),
+                connection.describe_timeout(),
+            )
             .await
             .map_err(|err| match err.into_kind() {
                 DescriberErrorKind::QuaintError(err) => {
                     quaint_error_to_connector_error(err, connection.connection_info())
                 }
+                kind @ DescriberErrorKind::InvalidIndexColumns { .. }
+                | kind @ DescriberErrorKind::PermissionDenied { .. }
+                | kind @ DescriberErrorKind::Timeout { .. } => ConnectorError::generic(anyhow::anyhow!("{}", kind)),
             })
     }
 
+    fn enable_column_storage(&mut self) {
+        self.column_storage = true;
+    }
+
     async fn drop_database(&self, database_str: &str) -> ConnectorResult<()> {
-        let mut url = Url::parse(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+        let mut url = Url::parse(database_str)
+            .map_err(|err| ConnectorError::url_parse_error(err, &redact_connection_string(database_str)))?;
         let db_name = url.path().trim_start_matches('/').to_owned();
         assert!(!db_name.is_empty(), "Database name should not be empty.");
 
@@ -127,12 +195,13 @@ impl SqlFlavour for PostgresFlavour {
     }
 
     async fn qe_setup(&self, database_str: &str) -> ConnectorResult<()> {
-        let mut url = Url::parse(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+        let mut url = Url::parse(database_str)
+            .map_err(|err| ConnectorError::url_parse_error(err, &redact_connection_string(database_str)))?;
 
         strip_schema_param_from_url(&mut url);
         let conn = create_postgres_admin_conn(url.clone()).await?;
-        let schema = self.0.schema();
-        let db_name = self.0.dbname();
+        let schema = self.url.schema();
+        let db_name = self.url.dbname();
 
         let query = format!("CREATE DATABASE \"{}\"", db_name);
         conn.raw_cmd(&query).await.ok();
@@ -151,18 +220,76 @@ impl SqlFlavour for PostgresFlavour {
         Ok(())
     }
 
-    async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
-        let schema_name = connection.connection_info().schema_name();
-
+    async fn release_lock(&self, connection: &Connection) -> ConnectorResult<()> {
         connection
-            .raw_cmd(&format!("DROP SCHEMA \"{}\" CASCADE", schema_name))
+            .query_raw(
+                &format!("SELECT pg_advisory_unlock({}, hashtext($1))", ADVISORY_LOCK_KEY),
+                &[self.schema_name().into()],
+            )
             .await?;
 
-        connection
-            .raw_cmd(&format!("CREATE SCHEMA \"{}\"", schema_name))
+        Ok(())
+    }
+
+    async fn installed_extensions(&self, connection: &Connection) -> ConnectorResult<Vec<String>> {
+        let sql = r#"
+            SELECT extname
+            FROM pg_extension
+            INNER JOIN pg_namespace ON pg_namespace.oid = pg_extension.extnamespace
+            WHERE pg_namespace.nspname = $1
+            ORDER BY extname
+        "#;
+
+        let rows = connection
+            .query_raw(sql, &[connection.connection_info().schema_name().into()])
             .await?;
 
-        Ok(())
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| row.at(0).and_then(|value| value.to_string()))
+            .collect())
+    }
+
+    async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
+        let schema_name = connection.connection_info().schema_name();
+
+        // The extensions installed in the schema are dropped along with it, so
+        // we install them again afterwards.
+        let extensions = self.installed_extensions(connection).await?;
+
+        // Dropping the whole schema does not fire the triggers of its tables,
+        // and drops them along with the tables. DDL is transactional on
+        // Postgres, so a failure leaves the schema as it was.
+        let mut statements = vec![
+            format!("DROP SCHEMA {} CASCADE", self.quote(schema_name)),
+            format!("CREATE SCHEMA {}", self.quote(schema_name)),
+        ];
+
+        statements.extend(extensions.iter().map(|extension| {
+            format!(
+                "CREATE EXTENSION IF NOT EXISTS {} SCHEMA {}",
+                self.quote(extension),
+                self.quote(schema_name)
+            )
+        }));
+
+        let to_connector_error = |err: QuaintError| quaint_error_to_connector_error(err, connection.connection_info());
+        let tx = connection
+            .quaint()
+            .start_transaction()
+            .await
+            .map_err(to_connector_error)?;
+
+        for statement in &statements {
+            if let Err(err) = tx.raw_cmd(statement).await {
+                // The original error is more useful than a failure to roll back.
+                tx.rollback().await.ok();
+
+                return Err(to_connector_error(err));
+            }
+        }
+
+        tx.commit().await.map_err(to_connector_error)
     }
 
     fn sql_family(&self) -> SqlFamily {
@@ -183,11 +310,14 @@ impl SqlFlavour for PostgresFlavour {
         connection.raw_cmd(&drop_database).await?;
         connection.raw_cmd(&create_database).await?;
 
-        let mut temporary_database_url = self.0.url().clone();
+        let mut temporary_database_url = self.url.url().clone();
         temporary_database_url.set_path(&format!("/{}", database_name));
         let temporary_database_url = temporary_database_url.to_string();
 
-        tracing::debug!("Connecting to temporary database at {}", temporary_database_url);
+        tracing::debug!(
+            "Connecting to temporary database at {}",
+            redact_connection_string(&temporary_database_url)
+        );
 
         let sql_schema = {
             let temporary_database = crate::connect(&temporary_database_url).await?;
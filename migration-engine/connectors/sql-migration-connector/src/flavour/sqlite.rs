@@ -1,41 +1,109 @@
-use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, flavour::SqlFlavour};
+use super::{redact_connection_string, MIGRATION_LOCK_TIMEOUT};
+use crate::{
+    connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, flavour::SqlFlavour,
+    sql_renderer::SqlRenderer,
+};
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
-use quaint::prelude::{ConnectionInfo, SqlFamily};
+use quaint::{
+    prelude::{ConnectionInfo, Queryable, SqlFamily},
+    single::Quaint,
+};
 use sql_schema_describer::{DescriberErrorKind, SqlSchema, SqlSchemaDescriberBackend};
-use std::path::Path;
+use std::{
+    path::Path,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// SQLite creates the database file when connecting, but not the directories
+/// it is in, so we create them first.
+pub(crate) fn create_parent_directories(file_path: &str) -> ConnectorResult<()> {
+    let dir = match Path::new(file_path).parent() {
+        Some(dir) if !dir.exists() => dir,
+        _ => return Ok(()),
+    };
+
+    std::fs::create_dir_all(dir).map_err(|err| {
+        ConnectorError::generic(anyhow::Error::new(err).context(format!(
+            "Failed to create the directory `{}` for the SQLite database at `{}`.",
+            dir.display(),
+            file_path
+        )))
+    })
+}
 
 #[derive(Debug)]
 pub(crate) struct SqliteFlavour {
     pub(super) file_path: String,
     pub(super) attached_name: String,
+    /// The connection holding the migration lock, between `acquire_lock()` and `release_lock()`.
+    pub(super) migration_lock: Mutex<Option<Quaint>>,
+}
+
+impl SqliteFlavour {
+    /// SQLite has no advisory locks of its own, so the migration lock is an
+    /// exclusive transaction on a separate database file next to the database.
+    /// SQLite holds it with the file locks of the operating system, which are
+    /// released when the process holding them exits, even when it crashes.
+    fn lock_file_path(&self) -> String {
+        format!("{}.migration-lock", self.file_path)
+    }
 }
 
 #[async_trait::async_trait]
 impl SqlFlavour for SqliteFlavour {
-    async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
-        use anyhow::Context;
+    async fn acquire_lock(&self, _connection: &Connection) -> ConnectorResult<()> {
+        let lock_file_path = self.lock_file_path();
+        let started_at = Instant::now();
 
-        let path = Path::new(&self.file_path);
-        if path.exists() {
-            return Ok(self.file_path.clone());
+        let lock_connection = Quaint::new(&format!("file:{}", lock_file_path)).await.map_err(|err| {
+            ConnectorError::generic(
+                anyhow::Error::new(err).context(format!("Failed to open the migration lock at `{}`.", lock_file_path)),
+            )
+        })?;
+
+        // The transaction cannot begin while another connection holds the lock.
+        loop {
+            match lock_connection.raw_cmd("BEGIN EXCLUSIVE").await {
+                Ok(()) => break,
+                Err(_) if started_at.elapsed() < MIGRATION_LOCK_TIMEOUT => {
+                    tokio::time::delay_for(Duration::from_millis(100)).await
+                }
+                Err(err) => {
+                    return Err(ConnectorError::generic(anyhow::Error::new(err).context(format!(
+                        "Timed out trying to acquire the migration lock at `{}` after {} seconds.",
+                        lock_file_path,
+                        MIGRATION_LOCK_TIMEOUT.as_secs()
+                    ))))
+                }
+            }
         }
 
-        let dir = path.parent();
+        *self.migration_lock.lock().unwrap() = Some(lock_connection);
 
-        if let Some((dir, false)) = dir.map(|dir| (dir, dir.exists())) {
-            std::fs::create_dir_all(dir)
-                .context("Creating SQLite database parent directory.")
-                .map_err(|io_err| ConnectorError::generic(io_err))?;
+        Ok(())
+    }
+
+    async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
+        let path = Path::new(&self.file_path);
+        if path.exists() {
+            return Ok(self.file_path.clone());
         }
 
+        create_parent_directories(&self.file_path)?;
         connect(database_str).await?;
 
         Ok(self.file_path.clone())
     }
 
-    async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = r#"
-            CREATE TABLE "_prisma_migrations" (
+    async fn create_imperative_migrations_table(
+        &self,
+        connection: &Connection,
+        table_name: &str,
+    ) -> ConnectorResult<()> {
+        let sql = format!(
+            r#"
+            CREATE TABLE {} (
                 "id"                    TEXT PRIMARY KEY NOT NULL,
                 "checksum"              TEXT NOT NULL,
                 "finished_at"           DATETIME,
@@ -46,19 +114,27 @@ impl SqlFlavour for SqliteFlavour {
                 "applied_steps_count"   INTEGER UNSIGNED NOT NULL DEFAULT 0,
                 "script"                TEXT NOT NULL
             );
-        "#;
+        "#,
+            self.quote(table_name)
+        );
 
-        Ok(connection.raw_cmd(sql).await?)
+        Ok(connection.raw_cmd(&sql).await?)
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::sqlite::SqlSchemaDescriber::new(connection.quaint().clone())
-            .describe(connection.connection_info().schema_name())
+            .describe_with_timeout(
+                connection.connection_info().schema_name(),
+                connection.describe_timeout(),
+            )
             .await
             .map_err(|err| match err.into_kind() {
                 DescriberErrorKind::QuaintError(err) => {
                     quaint_error_to_connector_error(err, connection.connection_info())
                 }
+                kind @ DescriberErrorKind::InvalidIndexColumns { .. }
+                | kind @ DescriberErrorKind::PermissionDenied { .. }
+                | kind @ DescriberErrorKind::Timeout { .. } => ConnectorError::generic(anyhow::anyhow!("{}", kind)),
             })
     }
 
@@ -66,7 +142,12 @@ impl SqlFlavour for SqliteFlavour {
         let file_path = match ConnectionInfo::from_url(database_url) {
             Ok(ConnectionInfo::Sqlite { file_path, .. }) => file_path,
             Ok(_) => unreachable!(),
-            Err(err) => return Err(ConnectorError::url_parse_error(err, database_url)),
+            Err(err) => {
+                return Err(ConnectorError::url_parse_error(
+                    err,
+                    &redact_connection_string(database_url),
+                ))
+            }
         };
 
         std::fs::remove_file(&file_path).map_err(|err| {
@@ -88,6 +169,20 @@ impl SqlFlavour for SqliteFlavour {
         Ok(())
     }
 
+    async fn release_lock(&self, _connection: &Connection) -> ConnectorResult<()> {
+        let lock_connection = match self.migration_lock.lock().unwrap().take() {
+            Some(lock_connection) => lock_connection,
+            None => return Ok(()),
+        };
+
+        lock_connection.raw_cmd("COMMIT").await.map_err(|err| {
+            ConnectorError::generic(anyhow::Error::new(err).context(format!(
+                "Failed to release the migration lock at `{}`",
+                self.lock_file_path()
+            )))
+        })
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let file_path = connection.connection_info().file_path().unwrap();
 
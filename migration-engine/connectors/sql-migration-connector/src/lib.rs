@@ -16,48 +16,109 @@ mod sql_migration_persistence;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
+mod validate_for_connector;
 
-pub use sql_migration::SqlMigration;
+pub use sql_imperative_migration_persistence::IMPERATIVE_MIGRATIONS_TABLE_NAME;
+pub use sql_migration::{AlteredTable, DiffSummary, SqlMigration};
 pub use sql_migration_persistence::MIGRATION_TABLE_NAME;
 
+/// The connection string parameter setting the name of the migrations table, instead of
+/// [IMPERATIVE_MIGRATIONS_TABLE_NAME](constant.IMPERATIVE_MIGRATIONS_TABLE_NAME.html).
+pub const MIGRATIONS_TABLE_PARAM: &str = "migrations_table";
+
+/// The connection string parameter making the connector drop columns with `CASCADE` on Postgres,
+/// so the views and other objects depending on them are dropped with them instead of making the
+/// migration fail. Set it to `true` to enable it.
+pub const CASCADE_COLUMN_DROPS_PARAM: &str = "cascade_column_drops";
+
+/// The connection string parameter making the connector create the indexes of existing tables
+/// with `CREATE INDEX CONCURRENTLY` on Postgres, so writes to the tables are not locked while the
+/// indexes are built. Postgres refuses to run these statements in a transaction, so this only
+/// applies to the migration steps applied one by one, never to migration scripts. Set it to
+/// `true` to enable it.
+pub const CONCURRENT_INDEX_CREATION_PARAM: &str = "concurrent_index_creation";
+
+/// The connection string parameter making the connector describe the `STORAGE` and `COMPRESSION`
+/// settings of the columns on Postgres, and render them when the columns are created. These are
+/// advanced settings, and the migration engine only reproduces them, it never changes them on
+/// existing columns. Set it to `true` to enable it.
+pub const COLUMN_STORAGE_PARAM: &str = "column_storage";
+
+/// The connection string parameter making the connector create foreign keys `WITH NOCHECK` on SQL
+/// Server, so the existing rows are not validated against the new constraint. This makes adding
+/// foreign keys to large tables fast, but the constraints are then marked as not trusted by SQL
+/// Server until they are validated. Set it to `true` to enable it.
+pub const UNCHECKED_FOREIGN_KEYS_PARAM: &str = "unchecked_foreign_keys";
+
+/// The connection string parameter making the connector validate the foreign keys created with
+/// [UNCHECKED_FOREIGN_KEYS_PARAM](constant.UNCHECKED_FOREIGN_KEYS_PARAM.html) in a separate
+/// `ALTER TABLE ... WITH CHECK CHECK CONSTRAINT ...` statement, so they are trusted again. Set it
+/// to `true` to enable it.
+pub const VALIDATE_UNCHECKED_FOREIGN_KEYS_PARAM: &str = "validate_unchecked_foreign_keys";
+
 use connection_wrapper::Connection;
 use datamodel::Datamodel;
 use error::quaint_error_to_connector_error;
-use flavour::SqlFlavour;
+use flavour::{redact_connection_string, SqlFlavour};
 use migration_connector::*;
 use quaint::{prelude::ConnectionInfo, single::Quaint};
 use sql_database_migration_inferrer::*;
-use sql_schema_describer::SqlSchema;
+use sql_schema_describer::{connection_string_param, describe_timeout_from_connection_string, SqlSchema};
 
 /// The top-level SQL migration connector.
 pub struct SqlMigrationConnector {
     connection: Connection,
     flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
+    migrations_table_name: String,
+    cascade_column_drops: bool,
+    concurrent_index_creation: bool,
 }
 
 impl SqlMigrationConnector {
     /// Construct and initialize the SQL migration connector.
     pub async fn new(database_str: &str) -> ConnectorResult<Self> {
+        if let Ok(ConnectionInfo::Sqlite { file_path, .. }) = ConnectionInfo::from_url(database_str) {
+            flavour::create_sqlite_parent_directories(&file_path)?;
+        }
+
         let connection = connect(database_str).await?;
-        let flavour = flavour::from_connection_info(connection.connection_info());
+        let mut flavour = flavour::from_connection_info(connection.connection_info());
+
+        if connection_string_flag(database_str, COLUMN_STORAGE_PARAM) {
+            flavour.enable_column_storage();
+        }
+
+        if connection_string_flag(database_str, UNCHECKED_FOREIGN_KEYS_PARAM) {
+            flavour.enable_unchecked_foreign_keys(connection_string_flag(
+                database_str,
+                VALIDATE_UNCHECKED_FOREIGN_KEYS_PARAM,
+            ));
+        }
 
         flavour.ensure_connection_validity(&connection).await?;
 
-        Ok(Self { flavour, connection })
+        Ok(Self {
+            flavour,
+            connection,
+            migrations_table_name: connection_string_param(database_str, MIGRATIONS_TABLE_PARAM)
+                .unwrap_or_else(|| IMPERATIVE_MIGRATIONS_TABLE_NAME.to_owned()),
+            cascade_column_drops: connection_string_flag(database_str, CASCADE_COLUMN_DROPS_PARAM),
+            concurrent_index_creation: connection_string_flag(database_str, CONCURRENT_INDEX_CREATION_PARAM),
+        })
     }
 
     /// Create the database corresponding to the connection string, without initializing the connector.
     pub async fn create_database(database_str: &str) -> ConnectorResult<String> {
-        let connection_info =
-            ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+        let connection_info = ConnectionInfo::from_url(database_str)
+            .map_err(|err| ConnectorError::url_parse_error(err, &redact_connection_string(database_str)))?;
         let flavour = flavour::from_connection_info(&connection_info);
         flavour.create_database(database_str).await
     }
 
     /// Drop the database corresponding to the connection string, without initializing the connector.
     pub async fn drop_database(database_str: &str) -> ConnectorResult<()> {
-        let connection_info =
-            ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+        let connection_info = ConnectionInfo::from_url(database_str)
+            .map_err(|err| ConnectorError::url_parse_error(err, &redact_connection_string(database_str)))?;
         let flavour = flavour::from_connection_info(&connection_info);
 
         flavour.drop_database(database_str).await
@@ -65,14 +126,33 @@ impl SqlMigrationConnector {
 
     /// Set up the database for connector-test-kit, without initializing the connector.
     pub async fn qe_setup(database_str: &str) -> ConnectorResult<()> {
-        let connection_info =
-            ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+        let connection_info = ConnectionInfo::from_url(database_str)
+            .map_err(|err| ConnectorError::url_parse_error(err, &redact_connection_string(database_str)))?;
 
         let flavour = flavour::from_connection_info(&connection_info);
 
         flavour.qe_setup(database_str).await
     }
 
+    /// Calculate the steps migrating a database from a snapshot of its schema to the datamodel,
+    /// with their diagnostics, without initializing the connector. The database is never
+    /// connected to: the connection string only determines the SQL flavour.
+    pub fn calculate_database_steps_from_snapshot(
+        database_str: &str,
+        snapshot: &str,
+        next: &Datamodel,
+    ) -> ConnectorResult<(Vec<PrettyDatabaseMigrationStep>, DestructiveChangeDiagnostics)> {
+        let connection_info = ConnectionInfo::from_url(database_str)
+            .map_err(|err| ConnectorError::url_parse_error(err, &redact_connection_string(database_str)))?;
+        let flavour = flavour::from_connection_info(&connection_info);
+
+        let migration = sql_database_migration_inferrer::infer_from_snapshot(snapshot, next, flavour.as_ref())?;
+        let diagnostics = sql_destructive_change_checker::pure_check(flavour.as_ref(), &migration);
+        let steps = sql_database_step_applier::render_steps_pretty(&migration, flavour.as_ref(), migration.schemas())?;
+
+        Ok((steps, diagnostics))
+    }
+
     fn conn(&self) -> &Connection {
         &self.connection
     }
@@ -81,6 +161,21 @@ impl SqlMigrationConnector {
         self.flavour.as_ref()
     }
 
+    /// The name of the table the migrations history is persisted in.
+    pub fn migrations_table_name(&self) -> &str {
+        &self.migrations_table_name
+    }
+
+    /// Whether dropped columns should be dropped with `CASCADE`.
+    pub(crate) fn cascade_column_drops(&self) -> bool {
+        self.cascade_column_drops
+    }
+
+    /// Whether the indexes of existing tables should be created concurrently.
+    pub(crate) fn concurrent_index_creation(&self) -> bool {
+        self.concurrent_index_creation
+    }
+
     /// For tests.
     pub fn quaint(&self) -> &Quaint {
         self.connection.quaint()
@@ -90,6 +185,18 @@ impl SqlMigrationConnector {
     pub async fn describe_schema(&self) -> ConnectorResult<SqlSchema> {
         self.flavour.describe_schema(&self.connection).await
     }
+
+    /// The names of the extensions installed in the connected schema. Only Postgres has extensions.
+    pub async fn installed_extensions(&self) -> ConnectorResult<Vec<String>> {
+        self.flavour.installed_extensions(&self.connection).await
+    }
+
+    /// Delete the rows of every table except the migrations table, keeping the schema intact.
+    pub async fn truncate_all(&self) -> ConnectorResult<()> {
+        self.flavour
+            .truncate_all(&self.connection, &self.migrations_table_name)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -116,6 +223,14 @@ impl MigrationConnector for SqlMigrationConnector {
         self.flavour.reset(self.conn()).await
     }
 
+    async fn acquire_lock(&self) -> ConnectorResult<()> {
+        self.flavour.acquire_lock(self.conn()).await
+    }
+
+    async fn release_lock(&self) -> ConnectorResult<()> {
+        self.flavour.release_lock(self.conn()).await
+    }
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(
@@ -147,12 +262,17 @@ impl MigrationConnector for SqlMigrationConnector {
 }
 
 async fn connect(database_str: &str) -> ConnectorResult<Connection> {
-    let connection_info =
-        ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+    let connection_info = ConnectionInfo::from_url(database_str)
+        .map_err(|err| ConnectorError::url_parse_error(err, &redact_connection_string(database_str)))?;
 
     let connection = Quaint::new(database_str)
         .await
         .map_err(|err| quaint_error_to_connector_error(err, &connection_info))?;
 
-    Ok(Connection::new(connection))
+    Ok(Connection::new(connection).with_describe_timeout(describe_timeout_from_connection_string(database_str)))
+}
+
+/// Whether a boolean connection string parameter is set to `true`.
+fn connection_string_flag(database_str: &str, name: &str) -> bool {
+    connection_string_param(database_str, name).map_or(false, |value| value == "true")
 }
@@ -3,7 +3,7 @@ use sql_schema_describer::{
     SqlSchema,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) struct Pair<T> {
     previous: T,
     next: T,
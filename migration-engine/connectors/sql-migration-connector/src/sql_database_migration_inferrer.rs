@@ -1,12 +1,14 @@
 use crate::{
-    flavour::SqlFlavour,
     pair::Pair,
-    sql_migration::{SqlMigration, SqlMigrationStep},
-    sql_schema_calculator, sql_schema_differ, SqlMigrationConnector,
+    sql_migration::{DropForeignKey, SqlMigration, SqlMigrationStep},
+    sql_schema_calculator, sql_schema_differ,
+    validate_for_connector::validate_for_connector,
+    SqlFlavour, SqlMigrationConnector, IMPERATIVE_MIGRATIONS_TABLE_NAME,
 };
 use datamodel::*;
 use migration_connector::{
-    steps::MigrationStep, ConnectorResult, DatabaseMigrationInferrer, MigrationConnector, MigrationDirectory,
+    steps::MigrationStep, ConnectorError, ConnectorResult, DatabaseMigrationInferrer, DatabaseMigrationMarker,
+    MigrationConnector, MigrationDirectory,
 };
 use sql_schema_describer::*;
 
@@ -18,29 +20,48 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         next: &Datamodel,
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
+        validate_for_connector(next, self.flavour().sql_family())?;
+
         let current_database_schema: SqlSchema = self.describe_schema().await?;
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        infer(current_database_schema, expected_database_schema, self)
     }
 
     /// Infer the database migration steps, skipping the schema describer and assuming an empty database.
     fn infer_from_empty(&self, next: &Datamodel) -> ConnectorResult<SqlMigration> {
+        validate_for_connector(next, self.flavour().sql_family())?;
+
         let current_database_schema = SqlSchema::empty();
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
 
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        infer(current_database_schema, expected_database_schema, self)
+    }
+
+    fn infer_from_snapshot(&self, snapshot: &str, next: &Datamodel) -> ConnectorResult<SqlMigration> {
+        validate_for_connector(next, self.flavour().sql_family())?;
+
+        let current_database_schema = read_snapshot(snapshot)?;
+        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
+
+        infer(current_database_schema, expected_database_schema, self)
     }
 
-    fn infer_from_datamodels(
+    async fn infer_from_datamodels(
         &self,
         previous: &Datamodel,
         next: &Datamodel,
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
+        validate_for_connector(next, self.flavour().sql_family())?;
+
         let current_database_schema: SqlSchema = sql_schema_calculator::calculate_sql_schema(previous, self.flavour());
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
 
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        let mut migration = infer(current_database_schema, expected_database_schema, self)?;
+
+        self.read_dropped_foreign_key_names(&mut migration).await?;
+
+        Ok(migration)
     }
 
     #[tracing::instrument(skip(self, previous_migrations, target_schema))]
@@ -49,13 +70,15 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         previous_migrations: &[MigrationDirectory],
         target_schema: &Datamodel,
     ) -> ConnectorResult<SqlMigration> {
+        validate_for_connector(target_schema, self.flavour().sql_family())?;
+
         let current_database_schema = self
             .flavour()
             .sql_schema_from_migration_history(previous_migrations, self.conn())
             .await?;
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(target_schema, self.flavour());
 
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        infer_script(current_database_schema, expected_database_schema, self)
     }
 
     #[tracing::instrument(skip(self, applied_migrations))]
@@ -67,23 +90,32 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
 
         let actual_schema = self.describe_schema().await?;
 
-        let steps = sql_schema_differ::calculate_steps(Pair::new(&actual_schema, &expected_schema), self.flavour());
-
-        if steps.is_empty() {
-            return Ok(None);
-        }
+        let steps = sql_schema_differ::calculate_steps(
+            Pair::new(&actual_schema, &expected_schema),
+            self.flavour(),
+            self.migrations_table_name(),
+            self.cascade_column_drops(),
+            // The rollback is rendered as a script.
+            false,
+        )?;
 
-        let migration = SqlMigration {
+        let mut migration = SqlMigration {
             before: actual_schema,
             after: expected_schema,
             steps: steps,
         };
 
+        migration.remove_no_op_steps(self.flavour());
+
+        if migration.is_empty() {
+            return Ok(None);
+        }
+
         let diagnostics = self.destructive_change_checker().pure_check(&migration);
 
         let rollback = self
             .database_migration_step_applier()
-            .render_script(&migration, &diagnostics);
+            .render_script(&migration, &diagnostics)?;
 
         Ok(Some(rollback))
     }
@@ -98,19 +130,148 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
     }
 }
 
+impl SqlMigrationConnector {
+    /// The foreign keys of a calculated schema have no constraint name, since it is given by the
+    /// database, so the names of the dropped ones are read from the database. The foreign keys
+    /// that are not found there keep no name, and fail to render.
+    async fn read_dropped_foreign_key_names(&self, migration: &mut SqlMigration) -> ConnectorResult<()> {
+        let has_unnamed_foreign_key_drops = migration.steps.iter().any(|step| {
+            matches!(
+                step,
+                SqlMigrationStep::DropForeignKey(DropForeignKey {
+                    constraint_name: None,
+                    ..
+                })
+            )
+        });
+
+        if !has_unnamed_foreign_key_drops {
+            return Ok(());
+        }
+
+        let database_schema = self.describe_schema().await?;
+        let SqlMigration { before, steps, .. } = migration;
+
+        for step in steps.iter_mut() {
+            let drop_foreign_key = match step {
+                SqlMigrationStep::DropForeignKey(drop_foreign_key) if drop_foreign_key.constraint_name.is_none() => {
+                    drop_foreign_key
+                }
+                _ => continue,
+            };
+
+            let calculated_foreign_key =
+                &before.tables[drop_foreign_key.table_index].foreign_keys[drop_foreign_key.foreign_key_index];
+
+            drop_foreign_key.constraint_name = database_schema
+                .get_table(&drop_foreign_key.table)
+                .and_then(|table| {
+                    table.foreign_keys.iter().find(|foreign_key| {
+                        foreign_key.columns == calculated_foreign_key.columns
+                            && foreign_key.referenced_table == calculated_foreign_key.referenced_table
+                    })
+                })
+                .and_then(|foreign_key| foreign_key.constraint_name.clone());
+        }
+
+        Ok(())
+    }
+}
+
+/// Infer the migration from a snapshot of the database schema to the datamodel without a
+/// connector, so without connecting to the database. The migrations table has its default name,
+/// and columns are dropped and indexes created the default way.
+pub(crate) fn infer_from_snapshot(
+    snapshot: &str,
+    next: &Datamodel,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+) -> ConnectorResult<SqlMigration> {
+    validate_for_connector(next, flavour.sql_family())?;
+
+    let current_database_schema = read_snapshot(snapshot)?;
+    let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, flavour);
+    let steps = sql_schema_differ::calculate_steps(
+        Pair::new(&current_database_schema, &expected_database_schema),
+        flavour,
+        IMPERATIVE_MIGRATIONS_TABLE_NAME,
+        false,
+        false,
+    )?;
+
+    Ok(migration_from_steps(
+        current_database_schema,
+        expected_database_schema,
+        steps,
+        flavour,
+    ))
+}
+
+fn read_snapshot(snapshot: &str) -> ConnectorResult<SqlSchema> {
+    SqlSchema::from_versioned_json(snapshot).map_err(|err| {
+        ConnectorError::generic(anyhow::Error::new(err).context("Failed to read the SQL schema snapshot."))
+    })
+}
+
 fn infer(
     current_database_schema: SqlSchema,
     expected_database_schema: SqlSchema,
-    flavour: &dyn SqlFlavour,
-) -> SqlMigration {
-    let steps =
-        sql_schema_differ::calculate_steps(Pair::new(&current_database_schema, &expected_database_schema), flavour);
+    connector: &SqlMigrationConnector,
+) -> ConnectorResult<SqlMigration> {
+    infer_with_concurrency(
+        current_database_schema,
+        expected_database_schema,
+        connector,
+        connector.concurrent_index_creation(),
+    )
+}
 
-    SqlMigration {
+/// Infer a migration that will be rendered as a migration script. Scripts are applied as a single
+/// batch, which Postgres runs in a transaction, so their indexes are never created concurrently.
+fn infer_script(
+    current_database_schema: SqlSchema,
+    expected_database_schema: SqlSchema,
+    connector: &SqlMigrationConnector,
+) -> ConnectorResult<SqlMigration> {
+    infer_with_concurrency(current_database_schema, expected_database_schema, connector, false)
+}
+
+fn infer_with_concurrency(
+    current_database_schema: SqlSchema,
+    expected_database_schema: SqlSchema,
+    connector: &SqlMigrationConnector,
+    concurrent_index_creation: bool,
+) -> ConnectorResult<SqlMigration> {
+    let steps = sql_schema_differ::calculate_steps(
+        Pair::new(&current_database_schema, &expected_database_schema),
+        connector.flavour(),
+        connector.migrations_table_name(),
+        connector.cascade_column_drops(),
+        concurrent_index_creation,
+    )?;
+
+    Ok(migration_from_steps(
+        current_database_schema,
+        expected_database_schema,
+        steps,
+        connector.flavour(),
+    ))
+}
+
+fn migration_from_steps(
+    current_database_schema: SqlSchema,
+    expected_database_schema: SqlSchema,
+    steps: Vec<SqlMigrationStep>,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+) -> SqlMigration {
+    let mut migration = SqlMigration {
         before: current_database_schema,
         after: expected_database_schema,
         steps,
-    }
+    };
+
+    migration.remove_no_op_steps(flavour);
+
+    migration
 }
 
 pub(crate) fn wrap_as_step<T, F>(steps: Vec<T>, wrap_fn: F) -> impl Iterator<Item = SqlMigrationStep>
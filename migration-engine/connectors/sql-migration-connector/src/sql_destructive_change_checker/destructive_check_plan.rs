@@ -40,8 +40,15 @@ impl DestructiveCheckPlan {
     /// errors.
     ///
     /// For example, dropping a table that has 0 rows can be considered safe.
+    ///
+    /// When `count_affected_rows` is true, the number of rows affected by each
+    /// warning is queried and reported on the warning.
     #[tracing::instrument(skip(conn), level = "debug")]
-    pub(super) async fn execute(&self, conn: &Connection) -> ConnectorResult<DestructiveChangeDiagnostics> {
+    pub(super) async fn execute(
+        &self,
+        conn: &Connection,
+        count_affected_rows: bool,
+    ) -> ConnectorResult<DestructiveChangeDiagnostics> {
         let mut results = DatabaseInspectionResults::default();
 
         let inspection = async {
@@ -51,6 +58,15 @@ impl DestructiveCheckPlan {
 
             for (warning, _idx) in &self.warnings {
                 self.inspect_for_check(warning, &mut results, conn).await?;
+
+                if count_affected_rows {
+                    if let Some(table) = warning.affected_rows_table() {
+                        if results.get_row_count(table).is_none() {
+                            let count = count_rows_in_table(table, conn).await?;
+                            results.set_row_count(table.to_owned(), count)
+                        }
+                    }
+                }
             }
 
             Ok::<(), ConnectorError>(())
@@ -78,6 +94,11 @@ impl DestructiveCheckPlan {
                 diagnostics.warnings.push(MigrationWarning {
                     description: message,
                     step_index: *step_index,
+                    affected_rows_count: if count_affected_rows {
+                        warning.affected_rows_count(&results)
+                    } else {
+                        None
+                    },
                 })
             }
         }
@@ -132,6 +153,7 @@ impl DestructiveCheckPlan {
                 diagnostics.warnings.push(MigrationWarning {
                     description: message,
                     step_index: *step_index,
+                    affected_rows_count: None,
                 })
             }
         }
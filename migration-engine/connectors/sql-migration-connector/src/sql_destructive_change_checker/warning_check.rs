@@ -10,6 +10,10 @@ pub(super) enum SqlMigrationWarningCheck {
         table: String,
         column: String,
     },
+    CascadedColumnDrop {
+        table: String,
+        column: String,
+    },
     NonEmptyTableDrop {
         table: String,
     },
@@ -32,6 +36,44 @@ pub(super) enum SqlMigrationWarningCheck {
     },
 }
 
+impl SqlMigrationWarningCheck {
+    /// The table whose row count is the number of rows affected by the warning,
+    /// for warnings that are not about the values of a single column.
+    pub(super) fn affected_rows_table(&self) -> Option<&str> {
+        match self {
+            SqlMigrationWarningCheck::NonEmptyTableDrop { table }
+            | SqlMigrationWarningCheck::PrimaryKeyChange { table }
+            | SqlMigrationWarningCheck::UniqueConstraintAddition { table, .. } => Some(table),
+            SqlMigrationWarningCheck::DropAndRecreateColumn { .. }
+            | SqlMigrationWarningCheck::NonEmptyColumnDrop { .. }
+            | SqlMigrationWarningCheck::CascadedColumnDrop { .. }
+            | SqlMigrationWarningCheck::RiskyCast { .. }
+            | SqlMigrationWarningCheck::EnumValueRemoval { .. } => None,
+        }
+    }
+
+    /// The number of rows affected by the warning: the rows of the table for
+    /// table-level changes, and the rows with a non-null value in the column
+    /// for column-level changes.
+    pub(super) fn affected_rows_count(&self, database_check_results: &DatabaseInspectionResults) -> Option<i64> {
+        match self {
+            SqlMigrationWarningCheck::DropAndRecreateColumn { table, column }
+            | SqlMigrationWarningCheck::NonEmptyColumnDrop { table, column }
+            | SqlMigrationWarningCheck::RiskyCast { table, column, .. } => {
+                database_check_results.get_row_and_non_null_value_count(table, column).1
+            }
+            SqlMigrationWarningCheck::NonEmptyTableDrop { table }
+            | SqlMigrationWarningCheck::PrimaryKeyChange { table }
+            | SqlMigrationWarningCheck::UniqueConstraintAddition { table, .. } => {
+                database_check_results.get_row_count(table)
+            }
+            SqlMigrationWarningCheck::CascadedColumnDrop { .. } | SqlMigrationWarningCheck::EnumValueRemoval { .. } => {
+                None
+            }
+        }
+    }
+}
+
 impl Check for SqlMigrationWarningCheck {
     fn needed_table_row_count(&self) -> Option<&str> {
         match self {
@@ -78,6 +120,7 @@ impl Check for SqlMigrationWarningCheck {
                 (_, Some(value_count)) => Some(format!("You are about to drop the column `{column_name}` on the `{table_name}` table, which still contains {value_count} non-null values.", column_name = column, table_name = table, value_count = value_count)),
                 (_, _) => Some(format!("You are about to drop the column `{column_name}` on the `{table_name}` table. All the data in the column will be lost.", column_name = column, table_name = table)),
             },
+            SqlMigrationWarningCheck::CascadedColumnDrop { table, column } => Some(format!("You are about to drop the column `{column_name}` on the `{table_name}` table with CASCADE. The views and other database objects depending on the column will be dropped too.", column_name = column, table_name = table)),
             SqlMigrationWarningCheck::RiskyCast { table, column, previous_type, next_type } => match database_check_results.get_row_and_non_null_value_count(table, column) {
                 (Some(0), _) => None, // it's safe to alter a column on an empty table
                 (_, Some(0)) => None, // it's safe to alter a column if it only contains null values
@@ -2,10 +2,15 @@ use crate::{error::quaint_error_to_connector_error, SqlMigrationConnector};
 use migration_connector::{
     ConnectorError, ConnectorResult, ImperativeMigrationsPersistence, MigrationRecord, PersistenceNotInitializedError,
 };
-use quaint::{ast::*, error::ErrorKind as QuaintKind};
+use quaint::{
+    ast::*,
+    connector::{Queryable, TransactionCapable},
+    error::{Error as QuaintError, ErrorKind as QuaintKind},
+};
 use uuid::Uuid;
 
-const IMPERATIVE_MIGRATIONS_TABLE_NAME: &str = "_prisma_migrations";
+/// The default name of the migrations table.
+pub const IMPERATIVE_MIGRATIONS_TABLE_NAME: &str = "_prisma_migrations";
 
 #[async_trait::async_trait]
 impl ImperativeMigrationsPersistence for SqlMigrationConnector {
@@ -15,7 +20,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         if schema
             .tables
             .iter()
-            .any(|table| table.name == IMPERATIVE_MIGRATIONS_TABLE_NAME)
+            .any(|table| table.name == self.migrations_table_name())
         {
             return Ok(());
         }
@@ -28,7 +33,9 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
             ));
         }
 
-        self.flavour.create_imperative_migrations_table(&self.conn()).await?;
+        self.flavour
+            .create_imperative_migrations_table(&self.conn(), self.migrations_table_name())
+            .await?;
 
         Ok(())
     }
@@ -43,7 +50,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         let id = Uuid::new_v4().to_string();
         let now = chrono::Utc::now();
 
-        let insert = Insert::single_into(IMPERATIVE_MIGRATIONS_TABLE_NAME)
+        let insert = Insert::single_into(self.migrations_table_name())
             .value("id", id.as_str())
             .value("checksum", checksum)
             .value("logs", "")
@@ -60,7 +67,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
     async fn mark_migration_rolled_back_by_id(&self, migration_id: &str) -> ConnectorResult<()> {
         let conn = self.conn();
 
-        let update = Update::table(IMPERATIVE_MIGRATIONS_TABLE_NAME)
+        let update = Update::table(self.migrations_table_name())
             .so_that(Column::from("id").equals(migration_id))
             .set("rolled_back_at", chrono::Utc::now());
 
@@ -69,6 +76,62 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn mark_migrations_squashed_impl(
+        &self,
+        squashed_migration_ids: &[String],
+        migration_name: &str,
+        script: &str,
+        checksum: &str,
+    ) -> ConnectorResult<String> {
+        let id = Uuid::new_v4().to_string();
+        let now = chrono::Utc::now();
+
+        let delete = Delete::from_table(self.migrations_table_name()).so_that(
+            Column::from("id").in_selection(squashed_migration_ids.iter().map(String::as_str).collect::<Vec<_>>()),
+        );
+
+        let insert = Insert::single_into(self.migrations_table_name())
+            .value("id", id.as_str())
+            .value("checksum", checksum)
+            .value("logs", "")
+            .value("started_at", now)
+            .value("finished_at", now)
+            .value("migration_name", migration_name)
+            .value("script", script);
+
+        let to_connector_error =
+            |err: QuaintError| quaint_error_to_connector_error(err, self.connection.connection_info());
+        let tx = self
+            .conn()
+            .quaint()
+            .start_transaction()
+            .await
+            .map_err(to_connector_error)?;
+
+        let result = async {
+            if !squashed_migration_ids.is_empty() {
+                tx.execute(delete.into()).await?;
+            }
+
+            tx.execute(insert.into()).await?;
+
+            Ok::<(), QuaintError>(())
+        }
+        .await;
+
+        match result {
+            Ok(()) => tx.commit().await.map_err(to_connector_error)?,
+            Err(err) => {
+                // The original error is more useful than a failure to roll back.
+                tx.rollback().await.ok();
+
+                return Err(to_connector_error(err));
+            }
+        }
+
+        Ok(id)
+    }
+
     async fn record_migration_started_impl(
         &self,
         migration_name: &str,
@@ -79,7 +142,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         let id = Uuid::new_v4().to_string();
         let now = chrono::Utc::now();
 
-        let insert = Insert::single_into(IMPERATIVE_MIGRATIONS_TABLE_NAME)
+        let insert = Insert::single_into(self.migrations_table_name())
             .value("id", id.as_str())
             .value("checksum", checksum)
             .value("started_at", now)
@@ -96,7 +159,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
     async fn record_successful_step(&self, id: &str, logs: &str) -> ConnectorResult<()> {
         use quaint::ast::*;
 
-        let update = Update::table(IMPERATIVE_MIGRATIONS_TABLE_NAME)
+        let update = Update::table(self.migrations_table_name())
             .so_that(Column::from("id").equals(id))
             .set(
                 "applied_steps_count",
@@ -110,7 +173,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
     }
 
     async fn record_failed_step(&self, id: &str, logs: &str) -> ConnectorResult<()> {
-        let update = Update::table(IMPERATIVE_MIGRATIONS_TABLE_NAME)
+        let update = Update::table(self.migrations_table_name())
             .so_that(Column::from("id").equals(id))
             .set("logs", logs);
 
@@ -120,7 +183,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
     }
 
     async fn record_migration_finished(&self, id: &str) -> ConnectorResult<()> {
-        let update = Update::table(IMPERATIVE_MIGRATIONS_TABLE_NAME)
+        let update = Update::table(self.migrations_table_name())
             .so_that(Column::from("id").equals(id))
             .set("finished_at", chrono::Utc::now()); // TODO maybe use a database generated timestamp
 
@@ -131,7 +194,36 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
 
     #[tracing::instrument(skip(self))]
     async fn list_migrations(&self) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>> {
-        let select = Select::from_table(IMPERATIVE_MIGRATIONS_TABLE_NAME)
+        let select = self.select_migrations().order_by("started_at".ascend());
+
+        let rows = match self.query_migrations(select).await? {
+            Ok(rows) => rows,
+            Err(err) => return Ok(Err(err)),
+        };
+
+        tracing::debug!("Found {} migrations in the migrations table.", rows.len());
+
+        Ok(Ok(rows))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_migration_by_checksum(&self, checksum: &str) -> ConnectorResult<Option<MigrationRecord>> {
+        let select = self
+            .select_migrations()
+            .so_that(Column::from("checksum").equals(checksum))
+            .order_by("started_at".descend());
+
+        match self.query_migrations(select).await? {
+            Ok(rows) => Ok(rows.into_iter().next()),
+            Err(PersistenceNotInitializedError) => Ok(None),
+        }
+    }
+}
+
+impl SqlMigrationConnector {
+    /// A select of all the columns of the migrations table that make up a `MigrationRecord`.
+    fn select_migrations(&self) -> Select<'_> {
+        Select::from_table(self.migrations_table_name())
             .column("id")
             .column("checksum")
             .column("finished_at")
@@ -141,11 +233,15 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
             .column("started_at")
             .column("applied_steps_count")
             .column("script")
-            .order_by("started_at".ascend());
+    }
 
+    async fn query_migrations(
+        &self,
+        select: Select<'_>,
+    ) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>> {
         let result = match self.conn().query(select).await {
             Ok(result) => result,
-            Err(err) if matches!(err.kind(), QuaintKind::TableDoesNotExist { table } if table.contains(IMPERATIVE_MIGRATIONS_TABLE_NAME)) => {
+            Err(err) if matches!(err.kind(), QuaintKind::TableDoesNotExist { table } if table.contains(self.migrations_table_name())) => {
                 return Ok(Err(PersistenceNotInitializedError))
             }
             err @ Err(_) => err?,
@@ -154,8 +250,6 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         let rows = quaint::serde::from_rows(result)
             .map_err(|err| quaint_error_to_connector_error(err, self.connection.connection_info()))?;
 
-        tracing::debug!("Found {} migrations in the migrations table.", rows.len());
-
         Ok(Ok(rows))
     }
 }
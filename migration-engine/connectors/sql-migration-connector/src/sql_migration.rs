@@ -1,7 +1,8 @@
 use crate::{pair::Pair, sql_schema_differ::ColumnChanges};
-use migration_connector::DatabaseMigrationMarker;
+use migration_connector::{ConnectorError, ConnectorResult, DatabaseMigrationMarker};
 use serde::{Serialize, Serializer};
 use sql_schema_describer::SqlSchema;
+use std::collections::BTreeMap;
 
 /// The database migration type for SqlMigrationConnector.
 #[derive(Debug, Serialize)]
@@ -15,6 +16,148 @@ impl SqlMigration {
     pub(crate) fn schemas(&self) -> Pair<&SqlSchema> {
         Pair::new(&self.before, &self.after)
     }
+
+    /// Summarize the changes of the migration by table, column and enum name, without SQL.
+    pub fn summary(&self) -> DiffSummary {
+        let mut summary = DiffSummary::default();
+        let mut altered_tables: BTreeMap<String, AlteredTable> = BTreeMap::new();
+        let (before, after) = (&self.before, &self.after);
+
+        for step in &self.steps {
+            match step {
+                SqlMigrationStep::CreateTable(CreateTable { table_index }) => {
+                    summary.added_tables.push(after.tables[*table_index].name.clone())
+                }
+                SqlMigrationStep::DropTable(DropTable { table_index }) => {
+                    summary.dropped_tables.push(before.tables[*table_index].name.clone())
+                }
+                SqlMigrationStep::AlterTable(AlterTable { table_index, changes }) => {
+                    let (previous_table, next_table) = (
+                        &before.tables[*table_index.previous()],
+                        &after.tables[*table_index.next()],
+                    );
+                    let altered_table = altered_table_entry(&mut altered_tables, &next_table.name);
+
+                    for change in changes {
+                        match change {
+                            TableChange::AddColumn(AddColumn { column_index }) => altered_table
+                                .added_columns
+                                .push(next_table.columns[*column_index].name.clone()),
+                            TableChange::DropColumn(DropColumn { index, .. }) => altered_table
+                                .dropped_columns
+                                .push(previous_table.columns[*index].name.clone()),
+                            TableChange::AlterColumn(AlterColumn { column_index, .. })
+                            | TableChange::DropAndRecreateColumn { column_index, .. } => altered_table
+                                .altered_columns
+                                .push(next_table.columns[*column_index.next()].name.clone()),
+                            TableChange::DropPrimaryKey
+                            | TableChange::AddPrimaryKey { .. }
+                            | TableChange::AddIndex { .. }
+                            | TableChange::AddForeignKey { .. } => (),
+                        }
+                    }
+                }
+                SqlMigrationStep::RedefineTables(redefine_tables) => {
+                    for redefine_table in redefine_tables {
+                        let previous_table = &before.tables[*redefine_table.table_index.previous()];
+                        let next_table = &after.tables[*redefine_table.table_index.next()];
+                        let altered_table = altered_table_entry(&mut altered_tables, &next_table.name);
+
+                        altered_table.added_columns.extend(
+                            redefine_table
+                                .added_columns
+                                .iter()
+                                .map(|idx| next_table.columns[*idx].name.clone()),
+                        );
+                        altered_table.dropped_columns.extend(
+                            redefine_table
+                                .dropped_columns
+                                .iter()
+                                .map(|idx| previous_table.columns[*idx].name.clone()),
+                        );
+                        altered_table.altered_columns.extend(
+                            redefine_table
+                                .column_pairs
+                                .iter()
+                                .filter(|(_, changes, _)| changes.differs_in_something())
+                                .map(|(column_index, _, _)| next_table.columns[*column_index.next()].name.clone()),
+                        );
+                    }
+                }
+                SqlMigrationStep::CreateIndex(CreateIndex {
+                    table_index,
+                    caused_by_create_table: false,
+                    ..
+                })
+                | SqlMigrationStep::AddForeignKey(AddForeignKey { table_index, .. }) => {
+                    altered_table_entry(&mut altered_tables, &after.tables[*table_index].name);
+                }
+                SqlMigrationStep::CreateIndex(_) => (),
+                SqlMigrationStep::DropIndex(DropIndex { table, .. })
+                | SqlMigrationStep::DropForeignKey(DropForeignKey { table, .. }) => {
+                    altered_table_entry(&mut altered_tables, table);
+                }
+                SqlMigrationStep::AlterIndex { table, .. } | SqlMigrationStep::RedefineIndex { table, .. } => {
+                    altered_table_entry(&mut altered_tables, &after.tables[*table.next()].name);
+                }
+                SqlMigrationStep::AlterRowLevelSecurity(AlterRowLevelSecurity { table_index, .. }) => {
+                    altered_table_entry(&mut altered_tables, &after.tables[*table_index.next()].name);
+                }
+                SqlMigrationStep::CreateEnum(CreateEnum { enum_index }) => {
+                    summary.added_enums.push(after.enums[*enum_index].name.clone())
+                }
+                SqlMigrationStep::DropEnum(DropEnum { enum_index }) => {
+                    summary.dropped_enums.push(before.enums[*enum_index].name.clone())
+                }
+                SqlMigrationStep::AlterEnum(AlterEnum { index, .. }) => {
+                    summary.altered_enums.push(after.enums[*index.next()].name.clone())
+                }
+            }
+        }
+
+        // Created tables get their indexes and foreign keys in separate steps, and dropped tables
+        // can lose their foreign keys before being dropped.
+        summary.altered_tables = altered_tables
+            .into_iter()
+            .map(|(_, table)| table)
+            .filter(|table| {
+                !summary.added_tables.contains(&table.name) && !summary.dropped_tables.contains(&table.name)
+            })
+            .collect();
+
+        summary
+    }
+}
+
+fn altered_table_entry<'a>(altered_tables: &'a mut BTreeMap<String, AlteredTable>, name: &str) -> &'a mut AlteredTable {
+    altered_tables.entry(name.to_owned()).or_insert_with(|| AlteredTable {
+        name: name.to_owned(),
+        ..Default::default()
+    })
+}
+
+/// The changes of a migration by table, column and enum name, for example to display them in a UI.
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffSummary {
+    pub added_tables: Vec<String>,
+    pub dropped_tables: Vec<String>,
+    /// The tables that exist before and after the migration, and are changed by it.
+    pub altered_tables: Vec<AlteredTable>,
+    pub added_enums: Vec<String>,
+    pub dropped_enums: Vec<String>,
+    pub altered_enums: Vec<String>,
+}
+
+/// A table changed by a migration. Tables whose indexes, foreign keys or primary key change are
+/// listed even when none of their columns change.
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlteredTable {
+    pub name: String,
+    pub added_columns: Vec<String>,
+    pub dropped_columns: Vec<String>,
+    pub altered_columns: Vec<String>,
 }
 
 impl DatabaseMigrationMarker for SqlMigration {
@@ -44,6 +187,7 @@ pub(crate) enum SqlMigrationStep {
     CreateEnum(CreateEnum),
     DropEnum(DropEnum),
     AlterEnum(AlterEnum),
+    AlterRowLevelSecurity(AlterRowLevelSecurity),
 }
 
 impl Serialize for SqlMigrationStep {
@@ -76,6 +220,7 @@ impl SqlMigrationStep {
             SqlMigrationStep::CreateEnum(_) => "CreateEnum",
             SqlMigrationStep::DropEnum(_) => "DropEnum",
             SqlMigrationStep::AlterEnum(_) => "AlterEnum",
+            SqlMigrationStep::AlterRowLevelSecurity(_) => "AlterRowLevelSecurity",
         }
     }
 }
@@ -112,6 +257,16 @@ pub(crate) enum TableChange {
     AddPrimaryKey {
         columns: Vec<String>,
     },
+    /// Only produced when the flavour combines the steps on a table into its ALTER TABLE.
+    AddIndex {
+        /// The index of the index in the next table.
+        index_index: usize,
+    },
+    /// Only produced when the flavour combines the steps on a table into its ALTER TABLE.
+    AddForeignKey {
+        /// The index of the foreign key in the next table.
+        foreign_key_index: usize,
+    },
 }
 
 #[derive(Debug)]
@@ -122,6 +277,8 @@ pub(crate) struct AddColumn {
 #[derive(Debug)]
 pub(crate) struct DropColumn {
     pub index: usize,
+    /// Drop the objects depending on the column with it. Only rendered on Postgres.
+    pub cascade: bool,
 }
 
 #[derive(Debug)]
@@ -150,7 +307,22 @@ pub(crate) struct DropForeignKey {
     pub table: String,
     pub table_index: usize,
     pub foreign_key_index: usize,
-    pub constraint_name: String,
+    /// The name of the constraint. It can be missing when the describer could
+    /// not read it from the database.
+    pub constraint_name: Option<String>,
+}
+
+impl DropForeignKey {
+    /// The name of the constraint, or an error if it is unknown: a foreign key
+    /// cannot be dropped without its name.
+    pub(crate) fn required_constraint_name(&self) -> ConnectorResult<&str> {
+        self.constraint_name.as_deref().ok_or_else(|| {
+            ConnectorError::generic(anyhow::anyhow!(
+                "Cannot drop a foreign key on the `{}` table: the name of the constraint could not be read from the database.",
+                self.table
+            ))
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -158,6 +330,8 @@ pub(crate) struct CreateIndex {
     pub table_index: usize,
     pub index_index: usize,
     pub caused_by_create_table: bool,
+    /// Create the index without locking writes on the table. Only rendered on Postgres.
+    pub concurrently: bool,
 }
 
 #[derive(Debug)]
@@ -166,6 +340,18 @@ pub(crate) struct DropIndex {
     pub name: String,
 }
 
+/// Changes to the row-level security of an existing table. Only Postgres describes it.
+#[derive(Debug)]
+pub(crate) struct AlterRowLevelSecurity {
+    pub table_index: Pair<usize>,
+    /// Whether row-level security gets enabled or disabled, if that changed.
+    pub enable: Option<bool>,
+    /// The positions of the policies to drop, in the previous table.
+    pub dropped_policies: Vec<usize>,
+    /// The positions of the policies to create, in the next table.
+    pub created_policies: Vec<usize>,
+}
+
 #[derive(Debug)]
 pub(crate) struct CreateEnum {
     pub enum_index: usize,
@@ -21,68 +21,133 @@ use crate::{
     sql_migration::{AlterEnum, AlterTable, DropForeignKey, DropIndex, RedefineTable},
 };
 use common::{Quoted, QuotedWithSchema};
+use migration_connector::{ConnectorError, ConnectorResult};
 use sql_schema_describer::{
     walkers::EnumWalker,
     walkers::ForeignKeyWalker,
     walkers::IndexWalker,
     walkers::{ColumnWalker, TableWalker},
-    ColumnTypeFamily, DefaultValue, SqlSchema,
+    ColumnTypeFamily, DefaultValue, Policy, SqlSchema,
 };
 use std::borrow::Cow;
 
+/// The statements deleting all the rows of a set of tables.
+#[derive(Debug, Default)]
+pub(crate) struct TruncateTables {
+    /// Disable the foreign key checks that would make the deletions fail.
+    pub(crate) disable_checks: Vec<String>,
+    /// Delete the rows.
+    pub(crate) truncate: Vec<String>,
+    /// Enable the disabled checks again. These must run even when the deletions fail.
+    pub(crate) enable_checks: Vec<String>,
+    /// Whether the deletions can run in a transaction.
+    pub(crate) transactional: bool,
+}
+
 pub(crate) trait SqlRenderer {
+    /// The human-readable name of the flavour, used in error messages.
+    fn flavour_name(&self) -> &'static str;
+
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str>;
 
-    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
+    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String>;
+
+    fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> ConnectorResult<Vec<String>>;
 
-    fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> Vec<String>;
+    fn render_column(&self, column: &ColumnWalker<'_>) -> ConnectorResult<String>;
 
-    fn render_column(&self, column: &ColumnWalker<'_>) -> String;
+    /// Render the storage settings of a created column, as a statement following its creation.
+    /// Only Postgres describes them.
+    fn render_column_storage(&self, _column: &ColumnWalker<'_>) -> Option<String> {
+        None
+    }
+
+    fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String>;
 
-    fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
+    fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily)
+        -> ConnectorResult<Cow<'a, str>>;
 
-    fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str>;
+    fn render_alter_index(&self, _indexes: Pair<&IndexWalker<'_>>) -> ConnectorResult<Vec<String>> {
+        Err(ConnectorError::unsupported_step("AlterIndex", self.flavour_name()))
+    }
 
-    fn render_alter_index(&self, _indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
-        unreachable!("unreachable render_alter_index")
+    /// Render enabling or disabling row-level security on a table. Only Postgres describes it.
+    fn render_alter_row_level_security(&self, _table: &TableWalker<'_>, _enable: bool) -> ConnectorResult<String> {
+        Err(ConnectorError::unsupported_step(
+            "AlterRowLevelSecurity",
+            self.flavour_name(),
+        ))
     }
 
-    fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> Vec<String>;
+    fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> ConnectorResult<Vec<String>>;
 
     /// Render a `CreateEnum` step.
-    fn render_create_enum(&self, create_enum: &EnumWalker<'_>) -> Vec<String>;
+    fn render_create_enum(&self, create_enum: &EnumWalker<'_>) -> ConnectorResult<Vec<String>>;
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String;
 
+    /// Render an index creation that does not lock writes on the table while
+    /// the index is built. Databases without such a statement render the
+    /// regular form.
+    fn render_create_index_concurrently(&self, index: &IndexWalker<'_>) -> String {
+        self.render_create_index(index)
+    }
+
+    /// Render the creation of a row-level security policy on a table. Only
+    /// Postgres describes policies.
+    fn render_create_policy(&self, _table: &TableWalker<'_>, _policy: &Policy) -> ConnectorResult<String> {
+        Err(ConnectorError::unsupported_step("CreatePolicy", self.flavour_name()))
+    }
+
     /// Render a table creation step.
-    fn render_create_table(&self, table: &TableWalker<'_>) -> String {
+    fn render_create_table(&self, table: &TableWalker<'_>) -> ConnectorResult<String> {
         self.render_create_table_as(table, table.name())
     }
 
     /// Render a table creation with the provided table name.
-    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String;
+    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> ConnectorResult<String>;
 
-    fn render_drop_and_recreate_index(&self, _indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
-        unreachable!("unreachable render_drop_and_recreate_index")
+    fn render_drop_and_recreate_index(&self, _indexes: Pair<&IndexWalker<'_>>) -> ConnectorResult<Vec<String>> {
+        Err(ConnectorError::unsupported_step("RedefineIndex", self.flavour_name()))
     }
 
     /// Render a `DropEnum` step.
-    fn render_drop_enum(&self, dropped_enum: &EnumWalker<'_>) -> Vec<String>;
+    fn render_drop_enum(&self, dropped_enum: &EnumWalker<'_>) -> ConnectorResult<Vec<String>>;
 
     /// Render a `DropForeignKey` step.
-    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> String;
+    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> ConnectorResult<String>;
 
     /// Render a `DropIndex` step.
     fn render_drop_index(&self, drop_index: &DropIndex) -> String;
 
+    /// Render the removal of a row-level security policy from a table. Only Postgres describes
+    /// policies.
+    fn render_drop_policy(&self, _table: &TableWalker<'_>, _policy: &Policy) -> ConnectorResult<String> {
+        Err(ConnectorError::unsupported_step("DropPolicy", self.flavour_name()))
+    }
+
     /// Render a `DropTable` step.
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
     }
 
     /// Render a `RedefineTables` step.
-    fn render_redefine_tables(&self, tables: &[RedefineTable], schemas: &Pair<&SqlSchema>) -> Vec<String>;
+    fn render_redefine_tables(
+        &self,
+        tables: &[RedefineTable],
+        schemas: &Pair<&SqlSchema>,
+    ) -> ConnectorResult<Vec<String>>;
 
     /// Render a table renaming step.
     fn render_rename_table(&self, name: &str, new_name: &str) -> String;
+
+    /// Render the statements deleting all the rows of the given tables, leaving their
+    /// definitions untouched.
+    fn render_truncate_tables(&self, tables: &[TableWalker<'_>]) -> TruncateTables;
+
+    /// Render the statement validating the existing rows against a foreign key created without
+    /// checking them, following its creation. Only SQL Server creates such foreign keys.
+    fn render_validate_foreign_key(&self, _foreign_key: &ForeignKeyWalker<'_>) -> Option<String> {
+        None
+    }
 }
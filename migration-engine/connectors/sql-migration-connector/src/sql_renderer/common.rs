@@ -1,3 +1,4 @@
+use migration_connector::{ConnectorError, ConnectorResult};
 use sql_schema_describer::walkers::*;
 use sql_schema_describer::*;
 use std::fmt::{Display, Write as _};
@@ -86,6 +87,29 @@ pub(crate) fn render_nullability(column: &ColumnWalker<'_>) -> &'static str {
     }
 }
 
+/// The error for a `now()` default on a column that does not hold datetimes.
+pub(crate) fn now_default_on_non_datetime_column(family: &ColumnTypeFamily) -> ConnectorError {
+    ConnectorError::generic(anyhow::anyhow!(
+        "Cannot render a `now()` default on a column of the {:?} type family.",
+        family
+    ))
+}
+
+/// The error for a primary key that cannot be dropped, because its constraint name is unknown.
+pub(crate) fn missing_primary_key_constraint_name(table_name: &str) -> ConnectorError {
+    ConnectorError::generic(anyhow::anyhow!(
+        "Cannot drop the primary key of the `{}` table: the name of the constraint could not be read from the database.",
+        table_name
+    ))
+}
+
+/// Refuse to render a foreign key whose constrained and referenced columns do not line up.
+pub(crate) fn validate_foreign_key_columns(foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<()> {
+    foreign_key
+        .validate_column_alignment()
+        .map_err(|err| ConnectorError::generic(anyhow::anyhow!(err)))
+}
+
 pub(crate) fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
     match on_delete {
         ForeignKeyAction::NoAction => "",
@@ -119,7 +143,11 @@ pub(crate) fn format_hex(bytes: &[u8]) -> String {
 }
 
 pub(crate) trait IteratorJoin {
+    /// Render the items, separated by `sep`.
     fn join(self, sep: &str) -> String;
+
+    /// Render the items with `prefix` before each of them, separated by `sep`.
+    fn join_with(self, sep: &str, prefix: &str) -> String;
 }
 
 impl<T, I> IteratorJoin for T
@@ -142,4 +170,58 @@ where
 
         out
     }
+
+    fn join_with(mut self, sep: &str, prefix: &str) -> String {
+        let (lower_bound, _) = self.size_hint();
+        let mut out = String::with_capacity((sep.len() + prefix.len()) * lower_bound);
+
+        if let Some(first_item) = self.next() {
+            write!(out, "{}{}", prefix, first_item).unwrap();
+        }
+
+        for item in self {
+            out.push_str(sep);
+            write!(out, "{}{}", prefix, item).unwrap();
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IteratorJoin;
+
+    #[test]
+    fn join_separates_the_items() {
+        assert_eq!(["a", "b", "c"].iter().join(", "), "a, b, c");
+    }
+
+    #[test]
+    fn join_on_a_single_item_has_no_separator() {
+        assert_eq!(std::iter::once(1).join(", "), "1");
+    }
+
+    #[test]
+    fn join_on_an_empty_iterator_is_empty() {
+        assert_eq!(std::iter::empty::<&str>().join(", "), "");
+    }
+
+    #[test]
+    fn join_with_prefixes_every_item() {
+        assert_eq!(
+            ["\"a\" INTEGER", "\"b\" TEXT"].iter().join_with(",\n", "ADD "),
+            "ADD \"a\" INTEGER,\nADD \"b\" TEXT"
+        );
+    }
+
+    #[test]
+    fn join_with_on_a_single_item_only_has_the_prefix() {
+        assert_eq!(std::iter::once(1).join_with(", ", "ADD "), "ADD 1");
+    }
+
+    #[test]
+    fn join_with_on_an_empty_iterator_is_empty() {
+        assert_eq!(std::iter::empty::<&str>().join_with(", ", "ADD "), "");
+    }
 }
@@ -1,16 +1,18 @@
-use super::{common, IteratorJoin, Quoted, QuotedWithSchema, SqlRenderer};
+use super::{common, IteratorJoin, Quoted, QuotedWithSchema, SqlRenderer, TruncateTables};
 use crate::{
-    flavour::MssqlFlavour,
+    flavour::{MssqlFlavour, MSSQL_IDENTIFIER_SIZE_LIMIT},
     pair::Pair,
     sql_migration::{
         AddColumn, AlterColumn, AlterEnum, AlterTable, DropColumn, DropForeignKey, DropIndex, RedefineTable,
         TableChange,
     },
+    sql_schema_calculator::shorten_identifier,
 };
+use migration_connector::{ConnectorError, ConnectorResult};
 use prisma_value::PrismaValue;
 use sql_schema_describer::{
     walkers::{ColumnWalker, EnumWalker, ForeignKeyWalker, IndexWalker, TableWalker},
-    ColumnTypeFamily, DefaultValue, IndexType, SqlSchema,
+    ColumnTypeFamily, DefaultValue, ForeignKeyAction, IndexType, SqlSchema,
 };
 use std::{borrow::Cow, fmt::Write};
 
@@ -24,16 +26,24 @@ impl MssqlFlavour {
 }
 
 impl SqlRenderer for MssqlFlavour {
+    fn flavour_name(&self) -> &'static str {
+        "Microsoft SQL Server"
+    }
+
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
         Quoted::mssql_ident(name)
     }
 
-    fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> Vec<String> {
+    fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> ConnectorResult<Vec<String>> {
         let AlterTable { table_index, changes } = alter_table;
 
         let tables = schemas.tables(table_index);
 
-        let mut lines = Vec::new();
+        // T-SQL does not allow mixing `ADD` and `DROP` clauses in the same
+        // `ALTER TABLE`, but each keyword takes a list of definitions.
+        let mut drop_constraints = Vec::new();
+        let mut drop_columns = Vec::new();
+        let mut adds = Vec::new();
 
         for change in changes {
             match change {
@@ -42,44 +52,77 @@ impl SqlRenderer for MssqlFlavour {
                         .previous()
                         .primary_key()
                         .and_then(|pk| pk.constraint_name.as_ref())
-                        .expect("Missing constraint name in DropPrimaryKey on MSSQL");
-                    lines.push(format!("DROP CONSTRAINT {}", self.quote(constraint)));
+                        .ok_or_else(|| common::missing_primary_key_constraint_name(tables.previous().name()))?;
+                    drop_constraints.push(self.quote(constraint).to_string());
                 }
                 TableChange::AddPrimaryKey { columns } => {
                     let columns = columns.iter().map(|colname| self.quote(colname)).join(", ");
-                    lines.push(format!("ADD PRIMARY KEY ({})", columns));
+                    adds.push(format!("PRIMARY KEY ({})", columns));
                 }
                 TableChange::AddColumn(AddColumn { column_index }) => {
                     let column = tables.next().column_at(*column_index);
-                    let col_sql = self.render_column(&column);
+                    adds.push(self.render_column(&column)?);
+                }
+                TableChange::DropColumn(DropColumn { index, .. }) => {
+                    let column = tables.previous().column_at(*index);
+
+                    // The default constraint has to go first, SQL Server refuses to drop
+                    // columns that are still referenced by a constraint.
+                    if let Some(constraint_name) = column.default_constraint_name() {
+                        drop_constraints.push(self.quote(constraint_name).to_string());
+                    }
 
-                    lines.push(format!("ADD COLUMN {}", col_sql));
+                    drop_columns.push(self.quote(column.name()).to_string());
+                }
+                TableChange::DropAndRecreateColumn { .. } => {
+                    return Err(ConnectorError::unsupported_step(
+                        "DropAndRecreateColumn",
+                        self.flavour_name(),
+                    ))
+                }
+                TableChange::AlterColumn(AlterColumn { .. }) => {
+                    return Err(ConnectorError::unsupported_step("AlterColumn", self.flavour_name()))
+                }
+                TableChange::AddIndex { .. } => {
+                    return Err(ConnectorError::unsupported_step("AddIndex", self.flavour_name()))
                 }
-                TableChange::DropColumn(DropColumn { index }) => {
-                    let name = self.quote(tables.previous().column_at(*index).name());
-                    lines.push(format!("DROP COLUMN {}", name));
+                TableChange::AddForeignKey { .. } => {
+                    return Err(ConnectorError::unsupported_step("AddForeignKey", self.flavour_name()))
                 }
-                TableChange::DropAndRecreateColumn { .. } => todo!("DropAndRecreateColumn on MSSQL"),
-                TableChange::AlterColumn(AlterColumn { .. }) => todo!("We must handle altering columns in MSSQL"),
             };
         }
 
-        if lines.is_empty() {
-            return Vec::new();
+        let table = self.quote_with_schema(tables.previous().name());
+        let mut statements = Vec::new();
+
+        if !drop_constraints.is_empty() {
+            statements.push(format!(
+                "ALTER TABLE {} DROP CONSTRAINT {}",
+                table,
+                drop_constraints.join(",\n")
+            ));
+        }
+
+        if !drop_columns.is_empty() {
+            statements.push(format!(
+                "ALTER TABLE {} DROP COLUMN {}",
+                table,
+                drop_columns.join(",\n")
+            ));
         }
 
-        vec![format!(
-            "ALTER TABLE {} {}",
-            self.quote_with_schema(tables.previous().name()),
-            lines.join(",\n")
-        )]
+        if !adds.is_empty() {
+            statements.push(format!("ALTER TABLE {} ADD {}", table, adds.join(",\n")));
+        }
+
+        Ok(statements)
     }
 
-    fn render_alter_enum(&self, _: &AlterEnum, _: &Pair<&SqlSchema>) -> Vec<String> {
-        unreachable!("render_alter_enum on Microsoft SQL Server")
+    fn render_alter_enum(&self, _: &AlterEnum, _: &Pair<&SqlSchema>) -> ConnectorResult<Vec<String>> {
+        Err(ConnectorError::unsupported_step("AlterEnum", self.flavour_name()))
     }
 
-    fn render_column(&self, column: &ColumnWalker<'_>) -> String {
+    fn render_column(&self, column: &ColumnWalker<'_>) -> ConnectorResult<String> {
         let column_name = self.quote(column.name());
 
         let r#type = if !column.column_type().full_data_type.is_empty() {
@@ -94,55 +137,82 @@ impl SqlRenderer for MssqlFlavour {
                 ColumnTypeFamily::BigInt => "bigint",
                 ColumnTypeFamily::String | ColumnTypeFamily::Json => "nvarchar(1000)",
                 ColumnTypeFamily::Binary => "varbinary(max)",
-                ColumnTypeFamily::Enum(_) => unimplemented!("Enum not handled yet"),
-                ColumnTypeFamily::Uuid => unimplemented!("Uuid not handled yet"),
-                ColumnTypeFamily::Unsupported(x) => unimplemented!("{} not handled yet", x),
+                family @ ColumnTypeFamily::Enum(_)
+                | family @ ColumnTypeFamily::Uuid
+                | family @ ColumnTypeFamily::Unsupported(_) => {
+                    return Err(ConnectorError::generic(anyhow::anyhow!(
+                        "Cannot render the `{}` column: the {:?} type family is not supported on {}.",
+                        column.name(),
+                        family,
+                        self.flavour_name()
+                    )))
+                }
             }
         };
 
-        let nullability = common::render_nullability(&column);
+        // SQL Server needs the nullability to be explicit, it can depend on
+        // session settings otherwise.
+        let nullability = if column.arity().is_required() {
+            "NOT NULL"
+        } else {
+            "NULL"
+        };
 
         let default = column
             .default()
             .filter(|default| !matches!(default, DefaultValue::DBGENERATED(_)))
-            .map(|default| format!("DEFAULT {}", self.render_default(default, &column.column_type_family())))
+            .map(|default| {
+                Ok(format!(
+                    " CONSTRAINT {} DEFAULT {}",
+                    self.quote(&default_constraint_name(column)),
+                    self.render_default(default, &column.column_type_family())?
+                ))
+            })
+            .transpose()?
             .unwrap_or_else(String::new);
 
         if column.is_autoincrement() {
-            format!("{} int IDENTITY(1,1)", column_name)
+            // Identity columns can't be nullable, even when they are not part of the primary key.
+            Ok(format!("{} int IDENTITY(1,1) NOT NULL", column_name))
         } else {
-            format!("{} {} {} {}", column_name, r#type, nullability, default)
+            Ok(format!("{} {} {}{}", column_name, r#type, nullability, default))
         }
     }
 
-    fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String> {
+        common::validate_foreign_key_columns(foreign_key)?;
+
         let cols = foreign_key
             .referenced_column_names()
             .iter()
             .map(Quoted::mssql_ident)
             .join(",");
-        let is_self_relation = foreign_key.table().name() == foreign_key.referenced_table().name();
 
-        let (on_delete, on_update) = if is_self_relation {
-            ("ON DELETE NO ACTION", "ON UPDATE NO ACTION")
-        } else {
-            let on_delete = common::render_on_delete(&foreign_key.on_delete_action());
-            let on_update = common::render_on_update(&foreign_key.on_update_action());
+        let on_delete = match foreign_key.on_delete_action() {
+            ForeignKeyAction::NoAction => "ON DELETE NO ACTION",
+            action => common::render_on_delete(action),
+        };
 
-            (on_delete, on_update)
+        let on_update = match foreign_key.on_update_action() {
+            ForeignKeyAction::NoAction => "ON UPDATE NO ACTION",
+            action => common::render_on_update(action),
         };
 
-        format!(
+        Ok(format!(
             " REFERENCES {}({}) {} {}",
             self.quote_with_schema(&foreign_key.referenced_table().name()),
             cols,
             on_delete,
             on_update
-        )
+        ))
     }
 
-    fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
-        match (default, family) {
+    fn render_default<'a>(
+        &self,
+        default: &'a DefaultValue,
+        family: &ColumnTypeFamily,
+    ) -> ConnectorResult<Cow<'a, str>> {
+        Ok(match (default, family) {
             (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
             (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
             | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
@@ -152,18 +222,19 @@ impl SqlRenderer for MssqlFlavour {
                 format!("0x{}", common::format_hex(b)).into()
             }
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
-            (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
+            (DefaultValue::NOW, _) => return Err(common::now_default_on_non_datetime_column(family)),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
             (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => format!("'{}'", val).into(),
             (DefaultValue::VALUE(PrismaValue::Boolean(val)), ColumnTypeFamily::Boolean) => {
                 Cow::from(if *val { "1" } else { "0" })
             }
+            (DefaultValue::VALUE(PrismaValue::BigInt(val)), _) => val.to_string().into(),
             (DefaultValue::VALUE(val), _) => val.to_string().into(),
             (DefaultValue::SEQUENCE(_), _) => "".into(),
-        }
+        })
     }
 
-    fn render_alter_index(&self, indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
+    fn render_alter_index(&self, indexes: Pair<&IndexWalker<'_>>) -> ConnectorResult<Vec<String>> {
         let index_with_table = Quoted::Single(format!(
             "{}.{}.{}",
             self.schema_name(),
@@ -171,15 +242,15 @@ impl SqlRenderer for MssqlFlavour {
             indexes.previous().name()
         ));
 
-        vec![format!(
+        Ok(vec![format!(
             "EXEC SP_RENAME N{index_with_table}, N{index_new_name}, N'INDEX'",
             index_with_table = Quoted::Single(index_with_table),
             index_new_name = Quoted::Single(indexes.next().name()),
-        )]
+        )])
     }
 
-    fn render_create_enum(&self, _: &EnumWalker<'_>) -> Vec<String> {
-        unreachable!("render_create_enum on Microsoft SQL Server")
+    fn render_create_enum(&self, _: &EnumWalker<'_>) -> ConnectorResult<Vec<String>> {
+        Err(ConnectorError::unsupported_step("CreateEnum", self.flavour_name()))
     }
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String {
@@ -203,8 +274,12 @@ impl SqlRenderer for MssqlFlavour {
         )
     }
 
-    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
-        let columns: String = table.columns().map(|column| self.render_column(&column)).join(",\n");
+    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> ConnectorResult<String> {
+        let columns: String = table
+            .columns()
+            .map(|column| self.render_column(&column))
+            .collect::<ConnectorResult<Vec<_>>>()?
+            .join(",\n");
 
         let primary_columns = table.primary_key_column_names();
 
@@ -217,11 +292,9 @@ impl SqlRenderer for MssqlFlavour {
             String::new()
         };
 
-        let constraints = table
-            .indexes()
-            .filter(|index| index.index_type().is_unique())
-            .collect::<Vec<_>>();
+        let constraints = table.indexes().collect::<Vec<_>>();
 
+        // The indexes of new tables are created inline, in the same statement.
         let constraints = if !constraints.is_empty() {
             let constraints = constraints
                 .iter()
@@ -229,7 +302,11 @@ impl SqlRenderer for MssqlFlavour {
                     let name = index.name().replace('.', "_");
                     let columns = index.columns().map(|col| self.quote(col.name()));
 
-                    format!("CONSTRAINT {} UNIQUE ({})", name, columns.join(","))
+                    if index.index_type().is_unique() {
+                        format!("CONSTRAINT {} UNIQUE ({})", name, columns.join(","))
+                    } else {
+                        format!("INDEX {} ({})", self.quote(&name), columns.join(","))
+                    }
                 })
                 .join(",\n");
 
@@ -238,25 +315,25 @@ impl SqlRenderer for MssqlFlavour {
             String::new()
         };
 
-        format!(
+        Ok(format!(
             "CREATE TABLE {} ({columns}{primary_key}{constraints})",
             table_name = self.quote_with_schema(table_name),
             columns = columns,
             primary_key = primary_key,
             constraints = constraints,
-        )
+        ))
     }
 
-    fn render_drop_enum(&self, _: &EnumWalker<'_>) -> Vec<String> {
-        unreachable!("render_drop_enum on MSSQL")
+    fn render_drop_enum(&self, _: &EnumWalker<'_>) -> ConnectorResult<Vec<String>> {
+        Err(ConnectorError::unsupported_step("DropEnum", self.flavour_name()))
     }
 
-    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> String {
-        format!(
+    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> ConnectorResult<String> {
+        Ok(format!(
             "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
             table = self.quote_with_schema(&drop_foreign_key.table),
-            constraint_name = Quoted::mssql_ident(&drop_foreign_key.constraint_name),
-        )
+            constraint_name = Quoted::mssql_ident(drop_foreign_key.required_constraint_name()?),
+        ))
     }
 
     fn render_drop_index(&self, drop_index: &DropIndex) -> String {
@@ -267,8 +344,12 @@ impl SqlRenderer for MssqlFlavour {
         )
     }
 
-    fn render_redefine_tables(&self, _tables: &[RedefineTable], _schemas: &Pair<&SqlSchema>) -> Vec<String> {
-        unreachable!("render_redefine_table on MSSQL")
+    fn render_redefine_tables(
+        &self,
+        _tables: &[RedefineTable],
+        _schemas: &Pair<&SqlSchema>,
+    ) -> ConnectorResult<Vec<String>> {
+        Err(ConnectorError::unsupported_step("RedefineTables", self.flavour_name()))
     }
 
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
@@ -281,16 +362,22 @@ impl SqlRenderer for MssqlFlavour {
         )
     }
 
-    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String> {
         let mut add_constraint = String::with_capacity(120);
 
         write!(
             add_constraint,
-            "ALTER TABLE {table} ADD ",
+            "ALTER TABLE {table} ",
             table = self.quote_with_schema(foreign_key.table().name())
         )
         .unwrap();
 
+        if self.unchecked_foreign_keys() {
+            add_constraint.push_str("WITH NOCHECK ");
+        }
+
+        add_constraint.push_str("ADD ");
+
         if let Some(constraint_name) = foreign_key.constraint_name() {
             write!(add_constraint, "CONSTRAINT {} ", self.quote(constraint_name)).unwrap();
         }
@@ -306,14 +393,91 @@ impl SqlRenderer for MssqlFlavour {
         )
         .unwrap();
 
-        add_constraint.push_str(&self.render_references(foreign_key));
+        add_constraint.push_str(&self.render_references(foreign_key)?);
 
-        add_constraint
+        Ok(add_constraint)
     }
 
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
     }
+
+    fn render_truncate_tables(&self, tables: &[TableWalker<'_>]) -> TruncateTables {
+        // SQL Server refuses to truncate a table referenced by a foreign key, even a disabled
+        // one, so the rows of these tables are deleted instead.
+        let truncate = tables
+            .iter()
+            .map(|table| {
+                let is_referenced = tables.iter().any(|other| {
+                    other
+                        .foreign_keys()
+                        .any(|fk| fk.referenced_table().name() == table.name())
+                });
+
+                let statement = if is_referenced { "DELETE FROM" } else { "TRUNCATE TABLE" };
+
+                format!("{} {}", statement, self.quote_with_schema(table.name()))
+            })
+            .collect();
+
+        TruncateTables {
+            disable_checks: tables
+                .iter()
+                .map(|table| {
+                    format!(
+                        "ALTER TABLE {} NOCHECK CONSTRAINT ALL",
+                        self.quote_with_schema(table.name())
+                    )
+                })
+                .collect(),
+            truncate,
+            enable_checks: tables
+                .iter()
+                .map(|table| {
+                    format!(
+                        "ALTER TABLE {} WITH CHECK CHECK CONSTRAINT ALL",
+                        self.quote_with_schema(table.name())
+                    )
+                })
+                .collect(),
+            transactional: true,
+        }
+    }
+
+    fn render_validate_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> Option<String> {
+        if !self.validate_unchecked_foreign_keys() {
+            return None;
+        }
+
+        // Without a name, the constraint can only be validated with the others of its table.
+        let constraint = match foreign_key.constraint_name() {
+            Some(constraint_name) => self.quote(constraint_name).to_string(),
+            None => "ALL".to_owned(),
+        };
+
+        Some(format!(
+            "ALTER TABLE {} WITH CHECK CHECK CONSTRAINT {}",
+            self.quote_with_schema(foreign_key.table().name()),
+            constraint
+        ))
+    }
+}
+
+/// Default constraints get a deterministic name, instead of the random one SQL
+/// Server would generate, so they can be referenced by later migrations. Described
+/// columns keep the name of their constraint.
+fn default_constraint_name<'a>(column: &ColumnWalker<'a>) -> Cow<'a, str> {
+    if let Some(name) = column.default_constraint_name() {
+        return name.into();
+    }
+
+    let name = format!("DF__{}__{}", column.table().name(), column.name());
+
+    if name.len() > MSSQL_IDENTIFIER_SIZE_LIMIT {
+        shorten_identifier(&name, MSSQL_IDENTIFIER_SIZE_LIMIT).into()
+    } else {
+        name.into()
+    }
 }
 
 fn escape_string_literal(s: &str) -> String {
@@ -1,7 +1,10 @@
 use super::{
     common::SQL_INDENTATION,
-    common::{render_nullability, render_on_delete, Quoted},
-    IteratorJoin, SqlRenderer,
+    common::{
+        now_default_on_non_datetime_column, render_nullability, render_on_delete, render_on_update,
+        validate_foreign_key_columns, Quoted,
+    },
+    IteratorJoin, SqlRenderer, TruncateTables,
 };
 use crate::{
     flavour::{MysqlFlavour, SqlFlavour, MYSQL_IDENTIFIER_SIZE_LIMIT},
@@ -12,23 +15,21 @@ use crate::{
     },
     sql_schema_differ::ColumnChanges,
 };
+use migration_connector::{ConnectorError, ConnectorResult};
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
 use regex::Regex;
 use sql_schema_describer::{
     walkers::{ColumnWalker, EnumWalker, ForeignKeyWalker, IndexWalker, TableWalker},
-    ColumnTypeFamily, DefaultValue, IndexType, SqlSchema,
+    ColumnTypeFamily, DefaultValue, ForeignKeyAction, IndexType, SqlSchema,
 };
 use std::borrow::Cow;
 
 const VARCHAR_LENGTH_PREFIX: &str = "(191)";
 
-impl SqlRenderer for MysqlFlavour {
-    fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
-        Quoted::Backticks(name)
-    }
-
-    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+impl MysqlFlavour {
+    /// The `[CONSTRAINT name] FOREIGN KEY (...) REFERENCES ...` part of an `ADD` clause.
+    fn render_foreign_key_definition(&self, foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String> {
         let constraint_clause = foreign_key
             .constraint_name()
             .map(|constraint_name| format!("CONSTRAINT {} ", self.quote(constraint_name)))
@@ -40,29 +41,46 @@ impl SqlRenderer for MysqlFlavour {
             .map(|col| self.quote(col))
             .join(", ");
 
-        format!(
-            "ALTER TABLE `{table}` ADD {constraint_clause}FOREIGN KEY ({columns}){references}",
-            table = foreign_key.table().name(),
+        Ok(format!(
+            "{constraint_clause}FOREIGN KEY ({columns}){references}",
             constraint_clause = constraint_clause,
             columns = columns,
-            references = self.render_references(foreign_key),
-        )
+            references = self.render_references(foreign_key)?,
+        ))
     }
+}
 
-    fn render_alter_enum(&self, _alter_enum: &AlterEnum, _differ: &Pair<&SqlSchema>) -> Vec<String> {
-        unreachable!("render_alter_enum on MySQL")
+impl SqlRenderer for MysqlFlavour {
+    fn flavour_name(&self) -> &'static str {
+        "MySQL"
     }
 
-    fn render_alter_index(&self, indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
-        vec![format!(
+    fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
+        Quoted::Backticks(name)
+    }
+
+    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String> {
+        Ok(format!(
+            "ALTER TABLE `{table}` ADD {definition}",
+            table = foreign_key.table().name(),
+            definition = self.render_foreign_key_definition(foreign_key)?,
+        ))
+    }
+
+    fn render_alter_enum(&self, _alter_enum: &AlterEnum, _differ: &Pair<&SqlSchema>) -> ConnectorResult<Vec<String>> {
+        Err(ConnectorError::unsupported_step("AlterEnum", self.flavour_name()))
+    }
+
+    fn render_alter_index(&self, indexes: Pair<&IndexWalker<'_>>) -> ConnectorResult<Vec<String>> {
+        Ok(vec![format!(
             "ALTER TABLE {table_name} RENAME INDEX {index_name} TO {index_new_name}",
             table_name = self.quote(indexes.previous().table().name()),
             index_name = self.quote(indexes.previous().name()),
             index_new_name = self.quote(indexes.next().name())
-        )]
+        )])
     }
 
-    fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> Vec<String> {
+    fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> ConnectorResult<Vec<String>> {
         let AlterTable { table_index, changes } = alter_table;
 
         let tables = schemas.tables(table_index);
@@ -78,11 +96,12 @@ impl SqlRenderer for MysqlFlavour {
                 )),
                 TableChange::AddColumn(AddColumn { column_index }) => {
                     let column = tables.next().column_at(*column_index);
-                    let col_sql = self.render_column(&column);
+                    let col_sql = self.render_column(&column)?;
+                    let position = render_column_position(&tables.next(), *column_index);
 
-                    lines.push(format!("ADD COLUMN {}", col_sql));
+                    lines.push(format!("ADD COLUMN {}{}", col_sql, position));
                 }
-                TableChange::DropColumn(DropColumn { index }) => {
+                TableChange::DropColumn(DropColumn { index, .. }) => {
                     let name = self.quote(tables.previous().column_at(*index).name());
                     lines.push(format!("DROP COLUMN {}", name));
                 }
@@ -92,6 +111,11 @@ impl SqlRenderer for MysqlFlavour {
                     type_change: _,
                 }) => {
                     let columns = tables.columns(column_index);
+
+                    if changes.column_was_renamed() {
+                        return Err(ConnectorError::unsupported_step("RenameColumn", self.flavour_name()));
+                    }
+
                     let expanded = MysqlAlterColumn::new(&columns, &changes);
 
                     match expanded {
@@ -104,25 +128,48 @@ impl SqlRenderer for MysqlFlavour {
                             new_default.as_ref(),
                             columns.next(),
                             self,
-                        )),
+                        )?),
                     };
                 }
-                TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on MySQL"),
+                TableChange::DropAndRecreateColumn { .. } => {
+                    return Err(ConnectorError::unsupported_step(
+                        "DropAndRecreateColumn",
+                        self.flavour_name(),
+                    ))
+                }
+                TableChange::AddIndex { index_index } => {
+                    let index = tables.next().index_at(*index_index);
+                    let index_type = if index.index_type().is_unique() { "UNIQUE " } else { "" };
+
+                    lines.push(format!(
+                        "ADD {index_type}INDEX {index_name}({columns}){invisible}{comment}",
+                        index_type = index_type,
+                        index_name = self.quote(truncate_index_name(index.name())),
+                        columns = index.columns().map(|c| self.quote(c.name())).join(", "),
+                        invisible = render_invisible(index.is_invisible()),
+                        comment = render_index_comment(index.comment()),
+                    ));
+                }
+                TableChange::AddForeignKey { foreign_key_index } => {
+                    let foreign_key = tables.next().foreign_key_at(*foreign_key_index);
+
+                    lines.push(format!("ADD {}", self.render_foreign_key_definition(&foreign_key)?));
+                }
             };
         }
 
         if lines.is_empty() {
-            return Vec::new();
+            return Ok(Vec::new());
         }
 
-        vec![format!(
+        Ok(vec![format!(
             "ALTER TABLE {} {}",
             self.quote(tables.previous().name()),
             lines.join(",\n    ")
-        )]
+        )])
     }
 
-    fn render_column(&self, column: &ColumnWalker<'_>) -> String {
+    fn render_column(&self, column: &ColumnWalker<'_>) -> ConnectorResult<String> {
         let column_name = self.quote(column.name());
         let tpe_str = render_column_type(&column);
         let nullability_str = render_nullability(&column);
@@ -135,12 +182,9 @@ impl SqlRenderer for MysqlFlavour {
                     // We do not want to render binary defaults because they are not supported by MySQL.
                     && !matches!(column.column_type_family(), ColumnTypeFamily::Binary)
             })
-            .map(|default| {
-                format!(
-                    " DEFAULT {}",
-                    self.render_default(default, &column.column_type_family())
-                )
-            })
+            .map(|default| render_column_default(column, default, self))
+            .transpose()?
+            .map(|default| format!(" DEFAULT {}", default))
             .unwrap_or_else(String::new);
         let foreign_key = column.table().foreign_key_for_column(column.name());
         let auto_increment_str = if column.is_autoincrement() {
@@ -148,80 +192,114 @@ impl SqlRenderer for MysqlFlavour {
         } else {
             ""
         };
+        let on_update_str = if column.is_on_update_current_timestamp()
+            && matches!(column.column_type_family(), ColumnTypeFamily::DateTime)
+        {
+            format!(" ON UPDATE {}", render_current_timestamp(column))
+        } else {
+            String::new()
+        };
+        let invisible_str = render_invisible(column.is_invisible());
 
-        match foreign_key {
+        Ok(match foreign_key {
             Some(_) => format!(
-                "{}{} {}{}{}",
-                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str
+                "{}{} {}{}{}{}{}",
+                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str, on_update_str, invisible_str
             ),
             None => format!(
-                "{}{} {}{}{}{}",
-                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str, auto_increment_str
+                "{}{} {}{}{}{}{}{}",
+                SQL_INDENTATION,
+                column_name,
+                tpe_str,
+                nullability_str,
+                default_str,
+                on_update_str,
+                invisible_str,
+                auto_increment_str
             ),
-        }
+        })
     }
 
-    fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String> {
+        validate_foreign_key_columns(foreign_key)?;
+
         let referenced_columns = foreign_key
             .referenced_column_names()
             .iter()
             .map(|col| self.quote(col))
             .join(",");
 
-        format!(
-            " REFERENCES `{table_name}`({column_names}) {on_delete} ON UPDATE CASCADE",
+        let on_delete = match foreign_key.on_delete_action() {
+            ForeignKeyAction::NoAction => "ON DELETE NO ACTION",
+            action => render_on_delete(action),
+        };
+
+        let on_update = match foreign_key.on_update_action() {
+            ForeignKeyAction::NoAction => "ON UPDATE NO ACTION",
+            action => render_on_update(action),
+        };
+
+        Ok(format!(
+            " REFERENCES `{table_name}`({column_names}) {on_delete} {on_update}",
             table_name = foreign_key.referenced_table().name(),
             column_names = referenced_columns,
-            on_delete = render_on_delete(foreign_key.on_delete_action())
-        )
+            on_delete = on_delete,
+            on_update = on_update,
+        ))
     }
 
-    fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
-        match (default, family) {
+    fn render_default<'a>(
+        &self,
+        default: &'a DefaultValue,
+        family: &ColumnTypeFamily,
+    ) -> ConnectorResult<Cow<'a, str>> {
+        Ok(match (default, family) {
             (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
             (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
             | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
                 format!("'{}'", escape_string_literal(&val)).into()
             }
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP(3)".into(),
-            (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
+            (DefaultValue::NOW, _) => return Err(now_default_on_non_datetime_column(family)),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultValue::VALUE(PrismaValue::BigInt(val)), _) => val.to_string().into(),
             (DefaultValue::VALUE(val), _) => format!("{}", val).into(),
             (DefaultValue::SEQUENCE(_), _) => "".into(),
-        }
+        })
     }
 
-    fn render_create_enum(&self, _create_enum: &EnumWalker<'_>) -> Vec<String> {
-        Vec::new() // enums are defined on each column that uses them on MySQL
+    fn render_create_enum(&self, _create_enum: &EnumWalker<'_>) -> ConnectorResult<Vec<String>> {
+        Ok(Vec::new()) // enums are defined on each column that uses them on MySQL
     }
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String {
-        let name = index.name();
-        let name = if name.len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
-            &name[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
-        } else {
-            &name
-        };
+        let name = truncate_index_name(index.name());
         let index_type = match index.index_type() {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
         };
-        let index_name = self.quote(&name);
+        let index_name = self.quote(name);
         let table_reference = self.quote(&index.table().name());
 
         let columns = index.columns().map(|c| self.quote(c.name()));
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns}){invisible}{comment}",
             index_type = index_type,
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", ")
+            columns = columns.join(", "),
+            invisible = render_invisible(index.is_invisible()),
+            comment = render_index_comment(index.comment()),
         )
     }
 
-    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
-        let columns: String = table.columns().map(|column| self.render_column(&column)).join(",\n");
+    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> ConnectorResult<String> {
+        let columns: String = table
+            .columns()
+            .map(|column| self.render_column(&column))
+            .collect::<ConnectorResult<Vec<_>>>()?
+            .join(",\n");
 
         let primary_columns = table.primary_key_column_names();
 
@@ -237,17 +315,15 @@ impl SqlRenderer for MysqlFlavour {
                 .indexes()
                 .map(|index| {
                     let tpe = if index.index_type().is_unique() { "UNIQUE " } else { "" };
-                    let index_name = if index.name().len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
-                        &index.name()[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
-                    } else {
-                        &index.name()
-                    };
+                    let index_name = truncate_index_name(index.name());
 
                     format!(
-                        "{}INDEX {}({})",
+                        "{}INDEX {}({}){}{}",
                         tpe,
-                        self.quote(&index_name),
-                        index.columns().map(|col| self.quote(col.name())).join(",\n")
+                        self.quote(index_name),
+                        index.columns().map(|col| self.quote(col.name())).join(",\n"),
+                        render_invisible(index.is_invisible()),
+                        render_index_comment(index.comment()),
                     )
                 })
                 .join(",\n");
@@ -257,33 +333,57 @@ impl SqlRenderer for MysqlFlavour {
             String::new()
         };
 
-        format!(
-            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+        let check_constraints: String = table
+            .check_constraints()
+            .iter()
+            .map(|check_constraint| {
+                format!(
+                    ",\n{indentation}{constraint_clause}CHECK ({expression})",
+                    indentation = SQL_INDENTATION,
+                    constraint_clause = check_constraint
+                        .name
+                        .as_ref()
+                        .map(|name| format!("CONSTRAINT {} ", self.quote(name)))
+                        .unwrap_or_default(),
+                    expression = check_constraint.expression,
+                )
+            })
+            .collect();
+
+        let collation = table.collation().unwrap_or_else(|| self.default_table_collation());
+        // MySQL collation names start with the name of their character set.
+        let charset = collation.split('_').next().unwrap_or(collation);
+
+        Ok(format!(
+            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}{check_constraints}\n) DEFAULT CHARACTER SET {charset} COLLATE {collation}",
             table_name = self.quote(table_name),
             columns = columns,
             indexes = indexes,
             primary_key = primary_key,
-        )
+            check_constraints = check_constraints,
+            charset = charset,
+            collation = collation,
+        ))
     }
 
-    fn render_drop_and_recreate_index(&self, indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
+    fn render_drop_and_recreate_index(&self, indexes: Pair<&IndexWalker<'_>>) -> ConnectorResult<Vec<String>> {
         // Order matters: dropping the old index first wouldn't work when foreign key constraints are still relying on it.
-        vec![
+        Ok(vec![
             self.render_create_index(indexes.next()),
             mysql_drop_index(indexes.previous().table().name(), indexes.previous().name()),
-        ]
+        ])
     }
 
-    fn render_drop_enum(&self, _: &EnumWalker<'_>) -> Vec<String> {
-        Vec::new()
+    fn render_drop_enum(&self, _: &EnumWalker<'_>) -> ConnectorResult<Vec<String>> {
+        Ok(Vec::new())
     }
 
-    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> String {
-        format!(
+    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> ConnectorResult<String> {
+        Ok(format!(
             "ALTER TABLE {table} DROP FOREIGN KEY {constraint_name}",
             table = self.quote(&drop_foreign_key.table),
-            constraint_name = Quoted::mysql_ident(&drop_foreign_key.constraint_name),
-        )
+            constraint_name = Quoted::mysql_ident(drop_foreign_key.required_constraint_name()?),
+        ))
     }
 
     fn render_drop_index(&self, drop_index: &DropIndex) -> String {
@@ -294,16 +394,32 @@ impl SqlRenderer for MysqlFlavour {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
     }
 
-    fn render_redefine_tables(&self, _names: &[RedefineTable], _schemas: &Pair<&SqlSchema>) -> Vec<String> {
-        unreachable!("render_redefine_table on MySQL")
+    fn render_redefine_tables(
+        &self,
+        _names: &[RedefineTable],
+        _schemas: &Pair<&SqlSchema>,
+    ) -> ConnectorResult<Vec<String>> {
+        Err(ConnectorError::unsupported_step("RedefineTables", self.flavour_name()))
     }
 
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
-        format!(
-            "ALTER TABLE {} RENAME TO {}",
-            self.quote(name),
-            new_name = self.quote(new_name),
-        )
+        // Unqualified names are resolved in the database of the connection, so the table stays
+        // in it.
+        format!("RENAME TABLE {} TO {}", self.quote(name), self.quote(new_name))
+    }
+
+    fn render_truncate_tables(&self, tables: &[TableWalker<'_>]) -> TruncateTables {
+        // TRUNCATE is refused on tables referenced by foreign keys, unless the checks are off. It
+        // commits implicitly, so it cannot run in a transaction.
+        TruncateTables {
+            disable_checks: vec!["SET FOREIGN_KEY_CHECKS=0".to_owned()],
+            truncate: tables
+                .iter()
+                .map(|table| format!("TRUNCATE TABLE {}", self.quote(table.name())))
+                .collect(),
+            enable_checks: vec!["SET FOREIGN_KEY_CHECKS=1".to_owned()],
+            transactional: false,
+        }
     }
 }
 
@@ -312,7 +428,7 @@ fn render_mysql_modify(
     new_default: Option<&sql_schema_describer::DefaultValue>,
     next_column: &ColumnWalker<'_>,
     renderer: &dyn SqlFlavour,
-) -> String {
+) -> ConnectorResult<String> {
     let column_type: Option<String> = if changes.type_changed() {
         Some(next_column.column_type().full_data_type.clone()).filter(|r| !r.is_empty() || r.contains("datetime"))
     // @default(now()) does not work with datetimes of certain sizes
@@ -325,12 +441,13 @@ fn render_mysql_modify(
         .unwrap_or_else(|| render_column_type(&next_column));
 
     let default = new_default
-        .map(|default| renderer.render_default(&default, &next_column.column_type().family))
+        .map(|default| render_column_default(next_column, default, renderer))
+        .transpose()?
         .filter(|expr| !expr.is_empty())
         .map(|expression| format!(" DEFAULT {}", expression))
         .unwrap_or_else(String::new);
 
-    format!(
+    Ok(format!(
         "MODIFY {column_name} {column_type}{nullability}{default}{sequence}",
         column_name = Quoted::mysql_ident(&next_column.name()),
         column_type = column_type,
@@ -345,7 +462,19 @@ fn render_mysql_modify(
         } else {
             ""
         },
-    )
+    ))
+}
+
+/// Datetime columns with a `now()` default get the precision of the column.
+fn render_column_default<'a>(
+    column: &ColumnWalker<'_>,
+    default: &'a DefaultValue,
+    renderer: &dyn SqlFlavour,
+) -> ConnectorResult<Cow<'a, str>> {
+    match (default, column.column_type_family()) {
+        (DefaultValue::NOW, ColumnTypeFamily::DateTime) => Ok(render_current_timestamp(column).into()),
+        (default, family) => renderer.render_default(default, family),
+    }
 }
 
 pub(crate) fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
@@ -380,6 +509,51 @@ pub(crate) fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str>
     }
 }
 
+/// Render the `FIRST` or `AFTER` clause that puts an added column at the same
+/// position as in the target table. Columns added at the end of the table need
+/// no positioning.
+/// `CURRENT_TIMESTAMP` with the fractional seconds precision of the column: MySQL rejects
+/// `DEFAULT` and `ON UPDATE` expressions whose precision differs from the column's.
+fn render_current_timestamp(column: &ColumnWalker<'_>) -> String {
+    let column_type = render_column_type(column);
+    let precision = column_type
+        .find('(')
+        .and_then(|start| column_type[start + 1..].split(')').next())
+        .map(str::trim)
+        .filter(|precision| !precision.is_empty());
+
+    match precision {
+        Some(precision) => format!("CURRENT_TIMESTAMP({})", precision),
+        None => "CURRENT_TIMESTAMP".to_owned(),
+    }
+}
+
+fn render_column_position(table: &TableWalker<'_>, column_index: usize) -> String {
+    if column_index + 1 >= table.columns().count() {
+        return String::new();
+    }
+
+    match column_index.checked_sub(1) {
+        None => " FIRST".to_owned(),
+        Some(previous_index) => format!(" AFTER {}", Quoted::mysql_ident(table.column_at(previous_index).name())),
+    }
+}
+
+/// Columns and indexes are visible by default, only invisible ones need a clause.
+fn render_invisible(invisible: bool) -> &'static str {
+    if invisible {
+        " INVISIBLE"
+    } else {
+        ""
+    }
+}
+
+fn render_index_comment(comment: Option<&str>) -> String {
+    comment
+        .map(|comment| format!(" COMMENT '{}'", escape_string_literal(comment)))
+        .unwrap_or_default()
+}
+
 fn escape_string_literal(s: &str) -> Cow<'_, str> {
     static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'"#).unwrap());
 
@@ -409,10 +583,6 @@ impl MysqlAlterColumn {
             return MysqlAlterColumn::DropDefault;
         }
 
-        if changes.column_was_renamed() {
-            unreachable!("MySQL column renaming.")
-        }
-
         // @default(dbgenerated()) does not give us the information in the prisma schema, so we have to
         // transfer it from the introspected current state of the database.
         let new_default = match (columns.previous().default(), columns.next().default()) {
@@ -430,3 +600,12 @@ impl MysqlAlterColumn {
         }
     }
 }
+
+/// MySQL refuses identifiers longer than the limit, so long index names are truncated.
+fn truncate_index_name(name: &str) -> &str {
+    if name.len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
+        &name[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
+    } else {
+        name
+    }
+}
@@ -1,31 +1,86 @@
-use super::{common::*, SqlRenderer};
+use super::{common::*, SqlRenderer, TruncateTables};
 use crate::{
     flavour::PostgresFlavour,
     pair::Pair,
     sql_migration::{
-        AddColumn, AlterColumn, AlterEnum, AlterTable, DropColumn, DropForeignKey, DropIndex, RedefineTable,
-        TableChange,
+        AddColumn, AlterColumn, AlterEnum, AlterTable, ColumnTypeChange, DropColumn, DropForeignKey, DropIndex,
+        RedefineTable, TableChange,
     },
     sql_schema_differ::{ColumnChange, ColumnChanges},
 };
+use migration_connector::{ConnectorError, ConnectorResult};
+use native_types::PostgresType;
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
 use regex::Regex;
 use sql_schema_describer::{walkers::*, *};
 use std::borrow::Cow;
 
+impl PostgresFlavour {
+    fn render_create_index_with_concurrency(&self, index: &IndexWalker<'_>, concurrently: bool) -> String {
+        let index_type = match index.index_type() {
+            IndexType::Unique => "UNIQUE ",
+            IndexType::Normal => "",
+        };
+
+        let index_name = self.quote(index.name());
+        let table_reference = self.quote(index.table().name());
+        let columns = index.keys().into_iter().enumerate().map(|(position, key)| {
+            let key = match key {
+                IndexKey::Column(column_name) => self.quote(column_name).to_string(),
+                IndexKey::Expression(expression) => format!("({})", expression),
+            };
+
+            match index.opclass_at(position) {
+                Some(opclass) => format!("{} {}", key, opclass),
+                None => key,
+            }
+        });
+        let storage_parameters = match index.storage_parameters() {
+            [] => String::new(),
+            params => format!(" WITH ({})", params.join(", ")),
+        };
+        let algorithm = index
+            .algorithm()
+            .map(|algorithm| format!(" USING {}", algorithm))
+            .unwrap_or_default();
+        // Only described on Postgres 15 and later, where the clause exists.
+        let nulls_not_distinct = if index.nulls_not_distinct() {
+            " NULLS NOT DISTINCT"
+        } else {
+            ""
+        };
+
+        format!(
+            "CREATE {index_type}INDEX {concurrently}{index_name} ON {table_reference}{algorithm}({columns}){nulls_not_distinct}{storage_parameters}",
+            index_type = index_type,
+            concurrently = if concurrently { "CONCURRENTLY " } else { "" },
+            index_name = index_name,
+            table_reference = table_reference,
+            algorithm = algorithm,
+            columns = columns.join(", "),
+            nulls_not_distinct = nulls_not_distinct,
+            storage_parameters = storage_parameters,
+        )
+    }
+}
+
 impl SqlRenderer for PostgresFlavour {
+    fn flavour_name(&self) -> &'static str {
+        "PostgreSQL"
+    }
+
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
         Quoted::postgres_ident(name)
     }
 
-    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String> {
         let constraint_clause = foreign_key
             .constraint_name()
             .map(|constraint_name| format!("CONSTRAINT {} ", self.quote(constraint_name)))
             .unwrap_or_else(String::new);
 
-        format!(
+        Ok(format!(
             "ALTER TABLE {table} ADD {constraint_clause}FOREIGN KEY({columns}){references}",
             table = self.quote(foreign_key.table().name()),
             constraint_clause = constraint_clause,
@@ -34,11 +89,11 @@ impl SqlRenderer for PostgresFlavour {
                 .iter()
                 .map(Quoted::postgres_ident)
                 .join(", "),
-            references = self.render_references(foreign_key),
-        )
+            references = self.render_references(foreign_key)?,
+        ))
     }
 
-    fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> Vec<String> {
+    fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> ConnectorResult<Vec<String>> {
         if alter_enum.dropped_variants.is_empty() {
             let stmts: Vec<String> = alter_enum
                 .created_variants
@@ -52,7 +107,7 @@ impl SqlRenderer for PostgresFlavour {
                 })
                 .collect();
 
-            return stmts;
+            return Ok(stmts);
         }
 
         let enums = schemas.enums(&alter_enum.index);
@@ -80,14 +135,18 @@ impl SqlRenderer for PostgresFlavour {
             let affected_columns = walk_columns(schemas.next()).filter(|column| matches!(&column.column_type().family, ColumnTypeFamily::Enum(name) if name.as_str() == enums.next().name()));
 
             for column in affected_columns {
+                // Enum arrays are cast element by element, through an array of text.
+                let array = if column.arity().is_list() { "[]" } else { "" };
+
                 let sql = format!(
                     "ALTER TABLE {schema_name}.{table_name} \
-                            ALTER COLUMN {column_name} TYPE {tmp_name} \
-                                USING ({column_name}::text::{tmp_name})",
+                            ALTER COLUMN {column_name} TYPE {tmp_name}{array} \
+                                USING ({column_name}::text{array}::{tmp_name}{array})",
                     schema_name = Quoted::postgres_ident(self.schema_name()),
                     table_name = Quoted::postgres_ident(column.table().name()),
                     column_name = Quoted::postgres_ident(column.name()),
                     tmp_name = Quoted::postgres_ident(&tmp_name),
+                    array = array,
                 );
 
                 stmts.push(sql);
@@ -128,18 +187,26 @@ impl SqlRenderer for PostgresFlavour {
 
         stmts.push("COMMIT".to_string());
 
-        stmts
+        Ok(stmts)
     }
 
-    fn render_alter_index(&self, indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
-        vec![format!(
+    fn render_alter_index(&self, indexes: Pair<&IndexWalker<'_>>) -> ConnectorResult<Vec<String>> {
+        Ok(vec![format!(
             "ALTER INDEX {} RENAME TO {}",
             self.quote(indexes.previous().name()),
             self.quote(indexes.next().name())
-        )]
+        )])
+    }
+
+    fn render_alter_row_level_security(&self, table: &TableWalker<'_>, enable: bool) -> ConnectorResult<String> {
+        Ok(format!(
+            "ALTER TABLE {} {} ROW LEVEL SECURITY",
+            self.quote(table.name()),
+            if enable { "ENABLE" } else { "DISABLE" }
+        ))
     }
 
-    fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> Vec<String> {
+    fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> ConnectorResult<Vec<String>> {
         let AlterTable { changes, table_index } = alter_table;
 
         let mut lines = Vec::new();
@@ -157,7 +224,7 @@ impl SqlRenderer for PostgresFlavour {
                             .previous()
                             .primary_key()
                             .and_then(|pk| pk.constraint_name.as_ref())
-                            .expect("Missing constraint name for DROP CONSTRAINT on Postgres.")
+                            .ok_or_else(|| missing_primary_key_constraint_name(tables.previous().name()))?
                     )
                 )),
                 TableChange::AddPrimaryKey { columns } => lines.push(format!(
@@ -166,18 +233,21 @@ impl SqlRenderer for PostgresFlavour {
                 )),
                 TableChange::AddColumn(AddColumn { column_index }) => {
                     let column = tables.next().column_at(*column_index);
-                    let col_sql = self.render_column(&column);
+                    let col_sql = self.render_column(&column)?;
 
                     lines.push(format!("ADD COLUMN {}", col_sql));
+                    after_statements.extend(self.render_column_storage(&column));
                 }
-                TableChange::DropColumn(DropColumn { index }) => {
+                TableChange::DropColumn(DropColumn { index, cascade }) => {
                     let name = self.quote(tables.previous().column_at(*index).name());
-                    lines.push(format!("DROP COLUMN {}", name));
+                    let cascade = if *cascade { " CASCADE" } else { "" };
+
+                    lines.push(format!("DROP COLUMN {}{}", name, cascade));
                 }
                 TableChange::AlterColumn(AlterColumn {
                     column_index,
                     changes,
-                    type_change: _,
+                    type_change,
                 }) => {
                     let columns = tables.columns(column_index);
 
@@ -185,10 +255,11 @@ impl SqlRenderer for PostgresFlavour {
                         self,
                         &columns,
                         changes,
+                        type_change.as_ref(),
                         &mut before_statements,
                         &mut lines,
                         &mut after_statements,
-                    );
+                    )?;
                 }
                 TableChange::DropAndRecreateColumn {
                     column_index,
@@ -199,14 +270,20 @@ impl SqlRenderer for PostgresFlavour {
 
                     lines.push(format!("DROP COLUMN {}", name));
 
-                    let col_sql = self.render_column(columns.next());
+                    let col_sql = self.render_column(columns.next())?;
                     lines.push(format!("ADD COLUMN {}", col_sql));
                 }
+                TableChange::AddIndex { .. } => {
+                    return Err(ConnectorError::unsupported_step("AddIndex", self.flavour_name()))
+                }
+                TableChange::AddForeignKey { .. } => {
+                    return Err(ConnectorError::unsupported_step("AddForeignKey", self.flavour_name()))
+                }
             };
         }
 
         if lines.is_empty() {
-            return Vec::new();
+            return Ok(Vec::new());
         }
 
         let alter_table = format!(
@@ -215,102 +292,194 @@ impl SqlRenderer for PostgresFlavour {
             lines.join(",\n")
         );
 
-        before_statements
+        Ok(before_statements
             .into_iter()
             .chain(std::iter::once(alter_table))
             .chain(after_statements.into_iter())
-            .collect()
+            .collect())
     }
 
-    fn render_column(&self, column: &ColumnWalker<'_>) -> String {
+    fn render_column(&self, column: &ColumnWalker<'_>) -> ConnectorResult<String> {
         let column_name = self.quote(column.name());
         let tpe_str = render_column_type(column.column_type());
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
             .filter(|default| !matches!(default, DefaultValue::DBGENERATED(_)))
-            .map(|default| format!(" DEFAULT {}", self.render_default(default, column.column_type_family())))
+            .map(|default| render_column_default(column, default))
+            .transpose()?
+            .map(|default| format!(" DEFAULT {}", default))
             .unwrap_or_else(String::new);
         let is_serial = column.is_autoincrement();
 
-        if is_serial {
-            format!("{} SERIAL", column_name)
+        if let Some(identity) = column.identity() {
+            let generation = match identity {
+                IdentityGeneration::Always => "ALWAYS",
+                IdentityGeneration::ByDefault => "BY DEFAULT",
+            };
+
+            let options = column
+                .identity_options()
+                .map(|options| format!(" (START WITH {} INCREMENT BY {})", options.start, options.increment))
+                .unwrap_or_default();
+
+            Ok(format!(
+                "{}{} {} NOT NULL GENERATED {} AS IDENTITY{}",
+                SQL_INDENTATION, column_name, tpe_str, generation, options
+            ))
+        } else if is_serial {
+            Ok(format!("{} SERIAL", column_name))
         } else {
-            format!(
-                "{}{} {}{}{}",
-                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str
-            )
+            Ok(format!(
+                "{}{} {}{}{}{}",
+                SQL_INDENTATION,
+                column_name,
+                tpe_str,
+                render_collation(column),
+                nullability_str,
+                default_str
+            ))
+        }
+    }
+
+    fn render_column_storage(&self, column: &ColumnWalker<'_>) -> Option<String> {
+        let storage = column.storage()?;
+        let alter_column_prefix = format!("ALTER COLUMN {}", self.quote(column.name()));
+        let mut clauses = Vec::new();
+
+        if let Some(strategy) = storage.strategy {
+            let strategy = match strategy {
+                StorageStrategy::Plain => "PLAIN",
+                StorageStrategy::External => "EXTERNAL",
+                StorageStrategy::Extended => "EXTENDED",
+                StorageStrategy::Main => "MAIN",
+            };
+
+            clauses.push(format!("{} SET STORAGE {}", alter_column_prefix, strategy));
         }
+
+        if let Some(compression) = &storage.compression {
+            clauses.push(format!("{} SET COMPRESSION {}", alter_column_prefix, compression));
+        }
+
+        if clauses.is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            "ALTER TABLE {} {}",
+            self.quote(column.table().name()),
+            clauses.join(",\n")
+        ))
     }
 
-    fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String> {
+        validate_foreign_key_columns(foreign_key)?;
+
         let referenced_columns = foreign_key
             .referenced_column_names()
             .iter()
             .map(Quoted::postgres_ident)
             .join(",");
 
-        format!(
-            "REFERENCES {}({}) {} ON UPDATE CASCADE",
+        let deferrable = match (foreign_key.is_deferrable(), foreign_key.is_initially_deferred()) {
+            (true, true) => " DEFERRABLE INITIALLY DEFERRED",
+            (true, false) => " DEFERRABLE",
+            (false, _) => "",
+        };
+
+        let match_type = if foreign_key.is_match_full() { " MATCH FULL" } else { "" };
+
+        Ok(format!(
+            "REFERENCES {}({}){} {} ON UPDATE CASCADE{}",
             self.quote(&foreign_key.referenced_table().name()),
             referenced_columns,
-            render_on_delete(&foreign_key.on_delete_action())
-        )
+            match_type,
+            render_on_delete(&foreign_key.on_delete_action()),
+            deferrable,
+        ))
     }
 
-    fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
-        match (default, family) {
-            (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
-            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
-            | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
-                format!("E'{}'", escape_string_literal(&val)).into()
-            }
-            (DefaultValue::VALUE(PrismaValue::Bytes(b)), ColumnTypeFamily::Binary) => {
-                format!("'{}'", format_hex(b)).into()
-            }
-            (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
-            (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
-            (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
-            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => format!("'{}'", val).into(),
-            (DefaultValue::VALUE(val), _) => val.to_string().into(),
-            (DefaultValue::SEQUENCE(_), _) => "".into(),
-        }
+    fn render_default<'a>(
+        &self,
+        default: &'a DefaultValue,
+        family: &ColumnTypeFamily,
+    ) -> ConnectorResult<Cow<'a, str>> {
+        // Without a column to look at, JSON defaults are cast to the type of calculated `Json` columns.
+        render_default_value(default, family, "jsonb")
     }
 
-    fn render_create_enum(&self, enm: &EnumWalker<'_>) -> Vec<String> {
+    fn render_create_enum(&self, enm: &EnumWalker<'_>) -> ConnectorResult<Vec<String>> {
         let sql = format!(
             r#"CREATE TYPE {enum_name} AS ENUM ({variants})"#,
             enum_name = QuotedWithSchema {
-                schema_name: &self.0.schema(),
+                schema_name: &self.url.schema(),
                 name: Quoted::postgres_ident(enm.name())
             },
             variants = enm.values().iter().map(Quoted::postgres_string).join(", "),
         );
 
-        vec![sql]
+        Ok(vec![sql])
     }
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String {
-        let index_type = match index.index_type() {
-            IndexType::Unique => "UNIQUE ",
-            IndexType::Normal => "",
-        };
+        self.render_create_index_with_concurrency(index, false)
+    }
 
-        let index_name = self.quote(index.name());
-        let table_reference = self.quote(index.table().name());
-        let columns = index.columns().map(|c| self.quote(c.name()));
+    fn render_create_index_concurrently(&self, index: &IndexWalker<'_>) -> String {
+        self.render_create_index_with_concurrency(index, true)
+    }
 
-        format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
-            index_type = index_type,
-            index_name = index_name,
-            table_reference = table_reference,
-            columns = columns.join(", ")
-        )
+    fn render_create_policy(&self, table: &TableWalker<'_>, policy: &Policy) -> ConnectorResult<String> {
+        // Omitted when permissive, since `AS` is not available before Postgres 10.
+        let restrictive = if policy.permissive { "" } else { " AS RESTRICTIVE" };
+        let command = match policy.command {
+            PolicyCommand::All => "ALL",
+            PolicyCommand::Select => "SELECT",
+            PolicyCommand::Insert => "INSERT",
+            PolicyCommand::Update => "UPDATE",
+            PolicyCommand::Delete => "DELETE",
+        };
+        let roles = match policy.roles.as_slice() {
+            [] => String::new(),
+            roles => format!(
+                " TO {}",
+                roles
+                    .iter()
+                    .map(|role| match role.as_str() {
+                        "public" => role.to_owned(),
+                        role => self.quote(role).to_string(),
+                    })
+                    .join(", ")
+            ),
+        };
+        let using = match &policy.using {
+            Some(expression) => format!(" USING ({})", expression),
+            None => String::new(),
+        };
+        let with_check = match &policy.with_check {
+            Some(expression) => format!(" WITH CHECK ({})", expression),
+            None => String::new(),
+        };
+
+        Ok(format!(
+            "CREATE POLICY {policy_name} ON {table_name}{restrictive} FOR {command}{roles}{using}{with_check}",
+            policy_name = self.quote(&policy.name),
+            table_name = self.quote(table.name()),
+            restrictive = restrictive,
+            command = command,
+            roles = roles,
+            using = using,
+            with_check = with_check,
+        ))
     }
 
-    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
-        let columns: String = table.columns().map(|column| self.render_column(&column)).join(",\n");
+    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> ConnectorResult<String> {
+        let columns: String = table
+            .columns()
+            .map(|column| self.render_column(&column))
+            .collect::<ConnectorResult<Vec<_>>>()?
+            .join(",\n");
 
         let primary_columns = table.primary_key_column_names();
         let pk_column_names = primary_columns
@@ -324,41 +493,73 @@ impl SqlRenderer for PostgresFlavour {
             String::new()
         };
 
-        format!(
-            "CREATE TABLE {table_name} (\n{columns}{primary_key}\n)",
+        let partitioning = match table.partitioning() {
+            Some(partitioning) => {
+                let strategy = match partitioning.strategy {
+                    PartitionStrategy::Range => "RANGE",
+                    PartitionStrategy::List => "LIST",
+                    PartitionStrategy::Hash => "HASH",
+                };
+
+                format!(" PARTITION BY {} ({})", strategy, partitioning.key)
+            }
+            None => String::new(),
+        };
+
+        Ok(format!(
+            "CREATE TABLE {table_name} (\n{columns}{primary_key}\n){partitioning}",
             table_name = self.quote(table_name),
             columns = columns,
             primary_key = pk,
-        )
+            partitioning = partitioning,
+        ))
     }
 
-    fn render_drop_enum(&self, dropped_enum: &EnumWalker<'_>) -> Vec<String> {
+    fn render_drop_enum(&self, dropped_enum: &EnumWalker<'_>) -> ConnectorResult<Vec<String>> {
         let sql = format!(
             "DROP TYPE {enum_name}",
             enum_name = Quoted::postgres_ident(dropped_enum.name()),
         );
 
-        vec![sql]
+        Ok(vec![sql])
     }
 
-    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> String {
-        format!(
+    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> ConnectorResult<String> {
+        Ok(format!(
             "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
             table = self.quote(&drop_foreign_key.table),
-            constraint_name = Quoted::postgres_ident(&drop_foreign_key.constraint_name),
-        )
+            constraint_name = Quoted::postgres_ident(drop_foreign_key.required_constraint_name()?),
+        ))
     }
 
     fn render_drop_index(&self, drop_index: &DropIndex) -> String {
-        format!("DROP INDEX {}", self.quote(&drop_index.name))
+        format!(
+            "DROP INDEX {}",
+            QuotedWithSchema {
+                schema_name: self.url.schema(),
+                name: self.quote(&drop_index.name),
+            }
+        )
+    }
+
+    fn render_drop_policy(&self, table: &TableWalker<'_>, policy: &Policy) -> ConnectorResult<String> {
+        Ok(format!(
+            "DROP POLICY {} ON {}",
+            self.quote(&policy.name),
+            self.quote(table.name())
+        ))
     }
 
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
     }
 
-    fn render_redefine_tables(&self, _names: &[RedefineTable], _schemas: &Pair<&SqlSchema>) -> Vec<String> {
-        unreachable!("render_redefine_table on Postgres")
+    fn render_redefine_tables(
+        &self,
+        _names: &[RedefineTable],
+        _schemas: &Pair<&SqlSchema>,
+    ) -> ConnectorResult<Vec<String>> {
+        Err(ConnectorError::unsupported_step("RedefineTables", self.flavour_name()))
     }
 
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
@@ -368,6 +569,17 @@ impl SqlRenderer for PostgresFlavour {
             new_name = self.quote(new_name),
         )
     }
+
+    fn render_truncate_tables(&self, tables: &[TableWalker<'_>]) -> TruncateTables {
+        // A single statement, so it is atomic without a transaction.
+        TruncateTables {
+            truncate: vec![format!(
+                "TRUNCATE TABLE {} RESTART IDENTITY CASCADE",
+                tables.iter().map(|table| self.quote(table.name())).join(", ")
+            )],
+            ..Default::default()
+        }
+    }
 }
 
 pub(crate) fn render_column_type(t: &ColumnType) -> String {
@@ -376,6 +588,10 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
         _ => "",
     };
 
+    if let Some(time_type) = render_time_type_with_precision(t) {
+        return format!("{}{}", time_type, array);
+    }
+
     if !t.full_data_type.is_empty() {
         return format!("{}{}", t.full_data_type, array);
     }
@@ -392,10 +608,108 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
         ColumnTypeFamily::Json => format!("JSONB{}", array),
         ColumnTypeFamily::Binary => format!("BYTEA{}", array),
         ColumnTypeFamily::Uuid => unimplemented!("Uuid not handled yet"),
+        ColumnTypeFamily::Unsupported(x) if x == "tsquery" => format!("TSQUERY{}", array),
+        ColumnTypeFamily::Unsupported(x) if x == "tsvector" => format!("TSVECTOR{}", array),
         ColumnTypeFamily::Unsupported(x) => unimplemented!("{} not handled yet", x),
     }
 }
 
+/// Introspected time columns only have the type name (e.g. `timestamptz`) in `full_data_type`. Their
+/// precision is in the native type, and it would be lost if we rendered the full data type alone.
+fn render_time_type_with_precision(t: &ColumnType) -> Option<String> {
+    if !matches!(
+        t.full_data_type.trim_start_matches('_'),
+        "timestamp" | "timestamptz" | "time" | "timetz"
+    ) {
+        return None;
+    }
+
+    let (name, precision) = match serde_json::from_value(t.native_type.clone()?).ok()? {
+        PostgresType::Timestamp(precision) => ("TIMESTAMP", precision),
+        PostgresType::Timestamptz(precision) => ("TIMESTAMPTZ", precision),
+        PostgresType::Time(precision) => ("TIME", precision),
+        PostgresType::Timetz(precision) => ("TIMETZ", precision),
+        _ => return None,
+    };
+
+    Some(match precision {
+        Some(precision) => format!("{}({})", name, precision),
+        None => name.to_owned(),
+    })
+}
+
+/// Columns without a described collation use the default collation of their type.
+fn render_collation(column: &ColumnWalker<'_>) -> String {
+    column
+        .collation()
+        .map(|collation| format!(" COLLATE {}", Quoted::postgres_ident(collation)))
+        .unwrap_or_default()
+}
+
+/// Changing the type of a column keeps its collation, so going back to the default collation has
+/// to be explicit.
+fn render_next_collation(columns: &Pair<ColumnWalker<'_>>) -> String {
+    match (columns.previous().collation(), columns.next().collation()) {
+        (Some(_), None) => r#" COLLATE "default""#.to_owned(),
+        _ => render_collation(columns.next()),
+    }
+}
+
+/// Render the default of a column. JSON defaults are cast to the type of the column, since `json`
+/// and `jsonb` columns can both be in the `Json` family.
+fn render_column_default<'a>(column: &ColumnWalker<'_>, default: &'a DefaultValue) -> ConnectorResult<Cow<'a, str>> {
+    let json_type = if column
+        .column_type()
+        .full_data_type
+        .trim_start_matches('_')
+        .eq_ignore_ascii_case("json")
+    {
+        "json"
+    } else {
+        "jsonb"
+    };
+
+    render_default_value(default, column.column_type_family(), json_type)
+}
+
+fn render_default_value<'a>(
+    default: &'a DefaultValue,
+    family: &ColumnTypeFamily,
+    json_type: &str,
+) -> ConnectorResult<Cow<'a, str>> {
+    Ok(match (default, family) {
+        (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
+        // The defaults of array columns.
+        (DefaultValue::VALUE(PrismaValue::List(values)), _) if values.is_empty() => "'{}'".into(),
+        (DefaultValue::VALUE(PrismaValue::List(values)), _) => format!(
+            "ARRAY[{}]",
+            values
+                .iter()
+                .map(|value| {
+                    render_default_value(&DefaultValue::VALUE(value.clone()), family, json_type).map(Cow::into_owned)
+                })
+                .collect::<ConnectorResult<Vec<_>>>()?
+                .join(", ")
+        )
+        .into(),
+        (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
+        | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
+            format!("E'{}'", escape_string_literal(&val)).into()
+        }
+        (DefaultValue::VALUE(PrismaValue::Bytes(b)), ColumnTypeFamily::Binary) => format!("'{}'", format_hex(b)).into(),
+        (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
+        (DefaultValue::NOW, _) => return Err(now_default_on_non_datetime_column(family)),
+        (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+        (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => format!("'{}'", val).into(),
+        (DefaultValue::VALUE(PrismaValue::Json(json)), ColumnTypeFamily::Json) => {
+            format!("'{}'::{}", json.replace('\'', "''"), json_type).into()
+        }
+        (DefaultValue::VALUE(PrismaValue::BigInt(val)), _) => val.to_string().into(),
+        (DefaultValue::VALUE(val), _) => val.to_string().into(),
+        (DefaultValue::SEQUENCE(_), _) => "".into(),
+    })
+}
+
 fn escape_string_literal(s: &str) -> Cow<'_, str> {
     static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'|\\"#).unwrap());
 
@@ -406,15 +720,16 @@ fn render_alter_column(
     renderer: &PostgresFlavour,
     columns: &Pair<ColumnWalker<'_>>,
     column_changes: &ColumnChanges,
+    type_change: Option<&ColumnTypeChange>,
     before_statements: &mut Vec<String>,
     clauses: &mut Vec<String>,
     after_statements: &mut Vec<String>,
-) {
+) -> ConnectorResult<()> {
     // Matches the sequence name from inside an autoincrement default expression.
     static SEQUENCE_DEFAULT_RE: Lazy<Regex> =
         Lazy::new(|| Regex::new(r#"nextval\('"?([^"]+)"?'::regclass\)"#).unwrap());
 
-    let steps = expand_alter_column(columns, column_changes);
+    let steps = expand_alter_column(columns, column_changes, renderer)?;
     let table_name = Quoted::postgres_ident(columns.previous().table().name());
     let column_name = Quoted::postgres_ident(columns.previous().name());
 
@@ -425,17 +740,30 @@ fn render_alter_column(
             PostgresAlterColumn::DropDefault => {
                 clauses.push(format!("{} DROP DEFAULT", &alter_column_prefix));
 
-                // We also need to drop the sequence, in case it isn't used by any other column.
+                // We also need to drop the sequence, in case it is owned by the column and isn't used
+                // by any other column. User-created sequences are never dropped.
                 if let Some(DefaultValue::SEQUENCE(sequence_expression)) = columns.previous().default() {
                     let sequence_name = SEQUENCE_DEFAULT_RE
                         .captures(sequence_expression)
                         .and_then(|captures| captures.get(1))
                         .map(|capture| capture.as_str())
-                        .unwrap_or_else(|| panic!("Failed to extract sequence name from `{}`", sequence_expression));
+                        .ok_or_else(|| {
+                            ConnectorError::generic(anyhow::anyhow!(
+                                "Failed to extract the sequence name from `{}`.",
+                                sequence_expression
+                            ))
+                        })?;
 
                     let sequence_is_still_used = walk_columns(columns.next().schema()).any(|column| matches!(column.default(), Some(DefaultValue::SEQUENCE(other_sequence)) if other_sequence == sequence_expression) && !column.is_same_column(columns.next()));
 
-                    if !sequence_is_still_used {
+                    let sequence_is_owned = columns
+                        .previous()
+                        .schema()
+                        .get_sequence(sequence_name)
+                        .map(|sequence| sequence.owned)
+                        .unwrap_or(false);
+
+                    if sequence_is_owned && !sequence_is_still_used {
                         after_statements.push(format!("DROP SEQUENCE {}", Quoted::postgres_ident(sequence_name)));
                     }
                 }
@@ -443,15 +771,32 @@ fn render_alter_column(
             PostgresAlterColumn::SetDefault(new_default) => clauses.push(format!(
                 "{} SET DEFAULT {}",
                 &alter_column_prefix,
-                renderer.render_default(&new_default, columns.next().column_type_family())
+                render_column_default(columns.next(), &new_default)?
             )),
             PostgresAlterColumn::DropNotNull => clauses.push(format!("{} DROP NOT NULL", &alter_column_prefix)),
             PostgresAlterColumn::SetNotNull => clauses.push(format!("{} SET NOT NULL", &alter_column_prefix)),
-            PostgresAlterColumn::SetType(ty) => clauses.push(format!(
-                "{} SET DATA TYPE {}",
-                &alter_column_prefix,
-                render_column_type(&ty)
-            )),
+            PostgresAlterColumn::SetType(ty) => {
+                let tpe_str = render_column_type(&ty);
+
+                // Risky casts have no implicit or assignment cast in Postgres,
+                // so they need an explicit one.
+                let using = match type_change {
+                    Some(ColumnTypeChange::RiskyCast)
+                        if columns.previous().arity().is_list() == columns.next().arity().is_list() =>
+                    {
+                        format!(" USING {}::{}", column_name, tpe_str)
+                    }
+                    _ => String::new(),
+                };
+
+                clauses.push(format!(
+                    "{} SET DATA TYPE {}{}{}",
+                    &alter_column_prefix,
+                    tpe_str,
+                    render_next_collation(columns),
+                    using
+                ))
+            }
             PostgresAlterColumn::AddSequence => {
                 // We imitate the sequence that would be automatically created on a `SERIAL` column.
                 //
@@ -475,16 +820,22 @@ fn render_alter_column(
                 after_statements.push(format!(
                     "ALTER SEQUENCE {sequence_name} OWNED BY {schema_name}.{table_name}.{column_name}",
                     sequence_name = Quoted::postgres_ident(sequence_name),
-                    schema_name = Quoted::postgres_ident(renderer.0.schema()),
+                    schema_name = Quoted::postgres_ident(renderer.url.schema()),
                     table_name = table_name,
                     column_name = column_name,
                 ));
             }
         }
     }
+
+    Ok(())
 }
 
-fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &ColumnChanges) -> Vec<PostgresAlterColumn> {
+fn expand_alter_column(
+    columns: &Pair<ColumnWalker<'_>>,
+    column_changes: &ColumnChanges,
+    renderer: &PostgresFlavour,
+) -> ConnectorResult<Vec<PostgresAlterColumn>> {
     let mut changes = Vec::new();
     let mut set_type = false;
 
@@ -512,7 +863,8 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
                 | (ColumnArity::Required, ColumnArity::Required)
                 | (ColumnArity::List, ColumnArity::List) => (),
             },
-            ColumnChange::TypeChanged => set_type = true,
+            // Postgres only changes the collation of a column together with its type.
+            ColumnChange::TypeChanged | ColumnChange::Collation => set_type = true,
             ColumnChange::Sequence => {
                 if columns.previous().is_autoincrement() {
                     // The sequence should be dropped.
@@ -522,7 +874,12 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
                     changes.push(PostgresAlterColumn::AddSequence)
                 }
             }
-            ColumnChange::Renaming => unreachable!("column renaming"),
+            ColumnChange::Renaming => {
+                return Err(ConnectorError::unsupported_step(
+                    "RenameColumn",
+                    renderer.flavour_name(),
+                ))
+            }
         }
     }
 
@@ -531,7 +888,7 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
         changes.push(PostgresAlterColumn::SetType(columns.next().column_type().clone()));
     }
 
-    changes
+    Ok(changes)
 }
 
 /// https://www.postgresql.org/docs/9.1/sql-altertable.html
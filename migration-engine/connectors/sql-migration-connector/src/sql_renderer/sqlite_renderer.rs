@@ -1,22 +1,114 @@
-use super::{common::*, SqlRenderer};
+use super::{common::*, SqlRenderer, TruncateTables};
 use crate::{
     flavour::SqliteFlavour,
     pair::Pair,
     sql_migration::{AddColumn, AlterEnum, AlterTable, DropForeignKey, DropIndex, RedefineTable, TableChange},
 };
+use migration_connector::{ConnectorError, ConnectorResult};
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
 use regex::Regex;
 use sql_schema_describer::{walkers::*, *};
 use std::borrow::Cow;
 
+impl SqliteFlavour {
+    /// Render the `CREATE TABLE` statement for the table. The CHECK constraints
+    /// are passed separately, since SQLite can only define them inline and the
+    /// redefine-table flow has to carry over the constraints of the previous table.
+    /// The same goes for the `WITHOUT ROWID` table option.
+    fn render_create_table_with_check_constraints(
+        &self,
+        table: &TableWalker<'_>,
+        table_name: &str,
+        check_constraints: &[CheckConstraint],
+        without_rowid: bool,
+    ) -> ConnectorResult<String> {
+        let columns: String = table
+            .columns()
+            .map(|column| self.render_column(&column))
+            .collect::<ConnectorResult<Vec<_>>>()?
+            .join(",\n");
+
+        let primary_key_is_already_set = columns.contains("PRIMARY KEY");
+        let primary_columns = table.primary_key_column_names().unwrap_or(&[]);
+
+        let primary_key = if !primary_columns.is_empty() && !primary_key_is_already_set {
+            let column_names = primary_columns.iter().map(Quoted::sqlite_ident).join(",");
+            format!(
+                ",\n{indentation}PRIMARY KEY ({column_names})",
+                indentation = SQL_INDENTATION,
+                column_names = column_names
+            )
+        } else {
+            String::new()
+        };
+
+        let foreign_keys = if !table.foreign_keys().next().is_none() {
+            let rendered_fks = table
+                .foreign_keys()
+                .map(|fk| -> ConnectorResult<String> {
+                    Ok(format!(
+                        "{constraint_clause}FOREIGN KEY ({constrained_columns}) {references}",
+                        constraint_clause = fk
+                            .constraint_name()
+                            .map(|name| format!("CONSTRAINT {} ", name))
+                            .unwrap_or_default(),
+                        constrained_columns = fk
+                            .constrained_column_names()
+                            .iter()
+                            .map(|col| format!(r#""{}""#, col))
+                            .join(","),
+                        references = self.render_references(&fk)?,
+                    ))
+                })
+                .collect::<ConnectorResult<Vec<_>>>()?
+                .into_iter()
+                .join_with(",\n", SQL_INDENTATION);
+
+            format!(",\n\n{fks}", fks = rendered_fks)
+        } else {
+            String::new()
+        };
+
+        let check_constraints: String = check_constraints
+            .iter()
+            .map(|check_constraint| {
+                format!(
+                    ",\n{indentation}{constraint_clause}CHECK ({expression})",
+                    indentation = SQL_INDENTATION,
+                    constraint_clause = check_constraint
+                        .name
+                        .as_ref()
+                        .map(|name| format!("CONSTRAINT {} ", self.quote(name)))
+                        .unwrap_or_default(),
+                    expression = check_constraint.expression,
+                )
+            })
+            .collect();
+
+        Ok(format!(
+            "CREATE TABLE {table_name} (\n{columns}{foreign_keys}{primary_key}{check_constraints}\n){table_options}",
+            table_name = self.quote(table_name),
+            columns = columns,
+            foreign_keys = foreign_keys,
+            primary_key = primary_key,
+            check_constraints = check_constraints,
+            table_options = if without_rowid { " WITHOUT ROWID" } else { "" },
+        ))
+    }
+}
+
 impl SqlRenderer for SqliteFlavour {
+    fn flavour_name(&self) -> &'static str {
+        "SQLite"
+    }
+
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
         Quoted::Double(name)
     }
 
-    fn render_alter_enum(&self, _alter_enum: &AlterEnum, _schemas: &Pair<&SqlSchema>) -> Vec<String> {
-        unreachable!("render_alter_enum on sqlite")
+    fn render_alter_enum(&self, _alter_enum: &AlterEnum, _schemas: &Pair<&SqlSchema>) -> ConnectorResult<Vec<String>> {
+        Err(ConnectorError::unsupported_step("AlterEnum", self.flavour_name()))
     }
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String {
@@ -26,7 +118,10 @@ impl SqlRenderer for SqliteFlavour {
         };
         let index_name = self.quote(index.name());
         let table_reference = self.quote(index.table().name());
-        let columns = index.columns().map(|c| self.quote(c.name()));
+        let columns = index.keys().into_iter().map(|key| match key {
+            IndexKey::Column(column_name) => self.quote(column_name).to_string(),
+            IndexKey::Expression(expression) => format!("({})", expression),
+        });
 
         format!(
             "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
@@ -37,14 +132,20 @@ impl SqlRenderer for SqliteFlavour {
         )
     }
 
-    fn render_column(&self, column: &ColumnWalker<'_>) -> String {
+    fn render_column(&self, column: &ColumnWalker<'_>) -> ConnectorResult<String> {
         let column_name = self.quote(column.name());
         let tpe_str = render_column_type(column.column_type());
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
             .filter(|default| !matches!(default, DefaultValue::DBGENERATED(_) | DefaultValue::SEQUENCE(_)))
-            .map(|default| format!(" DEFAULT {}", self.render_default(default, column.column_type_family())))
+            .map(|default| -> ConnectorResult<String> {
+                Ok(format!(
+                    " DEFAULT {}",
+                    self.render_default(default, column.column_type_family())?
+                ))
+            })
+            .transpose()?
             .unwrap_or_else(String::new);
         let auto_increment_str = if column.is_autoincrement() && column.is_single_primary_key() {
             " PRIMARY KEY AUTOINCREMENT"
@@ -52,7 +153,7 @@ impl SqlRenderer for SqliteFlavour {
             ""
         };
 
-        format!(
+        Ok(format!(
             "{indentation}{column_name} {tpe_str}{nullability_str}{default_str}{auto_increment}",
             indentation = SQL_INDENTATION,
             column_name = column_name,
@@ -60,26 +161,32 @@ impl SqlRenderer for SqliteFlavour {
             nullability_str = nullability_str,
             default_str = default_str,
             auto_increment = auto_increment_str
-        )
+        ))
     }
 
-    fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String> {
+        validate_foreign_key_columns(foreign_key)?;
+
         let referenced_fields = foreign_key
             .referenced_column_names()
             .iter()
             .map(Quoted::sqlite_ident)
             .join(",");
 
-        format!(
+        Ok(format!(
             "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} ON UPDATE CASCADE",
             referenced_table = self.quote(foreign_key.referenced_table().name()),
             referenced_fields = referenced_fields,
             on_delete_action = render_on_delete(foreign_key.on_delete_action())
-        )
+        ))
     }
 
-    fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
-        match (default, family) {
+    fn render_default<'a>(
+        &self,
+        default: &'a DefaultValue,
+        family: &ColumnTypeFamily,
+    ) -> ConnectorResult<Cow<'a, str>> {
+        Ok(match (default, family) {
             (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
             (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
             | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
@@ -89,18 +196,19 @@ impl SqlRenderer for SqliteFlavour {
                 format!("'{}'", format_hex(b)).into()
             }
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
-            (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
+            (DefaultValue::NOW, _) => return Err(now_default_on_non_datetime_column(family)),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultValue::VALUE(PrismaValue::BigInt(val)), _) => val.to_string().into(),
             (DefaultValue::VALUE(val), _) => format!("{}", val).into(),
             (DefaultValue::SEQUENCE(_), _) => "".into(),
-        }
+        })
     }
 
-    fn render_add_foreign_key(&self, _foreign_key: &ForeignKeyWalker<'_>) -> String {
-        unreachable!("AddForeignKey on SQLite")
+    fn render_add_foreign_key(&self, _foreign_key: &ForeignKeyWalker<'_>) -> ConnectorResult<String> {
+        Err(ConnectorError::unsupported_step("AddForeignKey", self.flavour_name()))
     }
 
-    fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> Vec<String> {
+    fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> ConnectorResult<Vec<String>> {
         let AlterTable { changes, table_index } = alter_table;
 
         let tables = schemas.tables(table_index);
@@ -114,7 +222,7 @@ impl SqlRenderer for SqliteFlavour {
             match change {
                 TableChange::AddColumn(AddColumn { column_index }) => {
                     let column = tables.next().column_at(*column_index);
-                    let col_sql = self.render_column(&column);
+                    let col_sql = self.render_column(&column)?;
 
                     statements.push(format!(
                         "ALTER TABLE {table_name} ADD COLUMN {column_definition}",
@@ -122,84 +230,55 @@ impl SqlRenderer for SqliteFlavour {
                         column_definition = col_sql,
                     ));
                 }
-                TableChange::AddPrimaryKey { .. } => unreachable!("AddPrimaryKey on SQLite"),
-                TableChange::AlterColumn(_) => unreachable!("AlterColumn on SQLite"),
-                TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on SQLite"),
-                TableChange::DropColumn(_) => unreachable!("DropColumn on SQLite"),
-                TableChange::DropPrimaryKey { .. } => unreachable!("DropPrimaryKey on SQLite"),
+                TableChange::AddPrimaryKey { .. } => {
+                    return Err(ConnectorError::unsupported_step("AddPrimaryKey", self.flavour_name()))
+                }
+                TableChange::AlterColumn(_) => {
+                    return Err(ConnectorError::unsupported_step("AlterColumn", self.flavour_name()))
+                }
+                TableChange::DropAndRecreateColumn { .. } => {
+                    return Err(ConnectorError::unsupported_step(
+                        "DropAndRecreateColumn",
+                        self.flavour_name(),
+                    ))
+                }
+                TableChange::DropColumn(_) => {
+                    return Err(ConnectorError::unsupported_step("DropColumn", self.flavour_name()))
+                }
+                TableChange::DropPrimaryKey { .. } => {
+                    return Err(ConnectorError::unsupported_step("DropPrimaryKey", self.flavour_name()))
+                }
+                TableChange::AddIndex { .. } => {
+                    return Err(ConnectorError::unsupported_step("AddIndex", self.flavour_name()))
+                }
+                TableChange::AddForeignKey { .. } => {
+                    return Err(ConnectorError::unsupported_step("AddForeignKey", self.flavour_name()))
+                }
             };
         }
 
-        statements
+        Ok(statements)
     }
 
-    fn render_create_enum(&self, _: &EnumWalker<'_>) -> Vec<String> {
-        Vec::new()
+    fn render_create_enum(&self, _: &EnumWalker<'_>) -> ConnectorResult<Vec<String>> {
+        Ok(Vec::new())
     }
 
-    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
-        use std::fmt::Write;
-
-        let columns: String = table.columns().map(|column| self.render_column(&column)).join(",\n");
-
-        let primary_key_is_already_set = columns.contains("PRIMARY KEY");
-        let primary_columns = table.primary_key_column_names().unwrap_or(&[]);
-
-        let primary_key = if !primary_columns.is_empty() && !primary_key_is_already_set {
-            let column_names = primary_columns.iter().map(Quoted::sqlite_ident).join(",");
-            format!(
-                ",\n{indentation}PRIMARY KEY ({column_names})",
-                indentation = SQL_INDENTATION,
-                column_names = column_names
-            )
-        } else {
-            String::new()
-        };
-
-        let foreign_keys = if !table.foreign_keys().next().is_none() {
-            let mut fks = table.foreign_keys().peekable();
-            let mut rendered_fks = String::new();
-
-            while let Some(fk) = fks.next() {
-                write!(
-                    rendered_fks,
-                    "{indentation}{constraint_clause}FOREIGN KEY ({constrained_columns}) {references}{comma}",
-                    constraint_clause = fk
-                        .constraint_name()
-                        .map(|name| format!("CONSTRAINT {} ", name))
-                        .unwrap_or_default(),
-                    indentation = SQL_INDENTATION,
-                    constrained_columns = fk
-                        .constrained_column_names()
-                        .iter()
-                        .map(|col| format!(r#""{}""#, col))
-                        .join(","),
-                    references = self.render_references(&fk),
-                    comma = if fks.peek().is_some() { ",\n" } else { "" },
-                )
-                .expect("Error formatting to string buffer.");
-            }
-
-            format!(",\n\n{fks}", fks = rendered_fks)
-        } else {
-            String::new()
-        };
-
-        format!(
-            "CREATE TABLE {table_name} (\n{columns}{foreign_keys}{primary_key}\n)",
-            table_name = self.quote(table_name),
-            columns = columns,
-            foreign_keys = foreign_keys,
-            primary_key = primary_key,
+    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> ConnectorResult<String> {
+        self.render_create_table_with_check_constraints(
+            table,
+            table_name,
+            table.check_constraints(),
+            table.without_rowid(),
         )
     }
 
-    fn render_drop_enum(&self, _: &EnumWalker<'_>) -> Vec<String> {
-        Vec::new()
+    fn render_drop_enum(&self, _: &EnumWalker<'_>) -> ConnectorResult<Vec<String>> {
+        Ok(Vec::new())
     }
 
-    fn render_drop_foreign_key(&self, _drop_foreign_key: &DropForeignKey) -> String {
-        unreachable!("render_drop_foreign_key on SQLite")
+    fn render_drop_foreign_key(&self, _drop_foreign_key: &DropForeignKey) -> ConnectorResult<String> {
+        Err(ConnectorError::unsupported_step("DropForeignKey", self.flavour_name()))
     }
 
     fn render_drop_index(&self, drop_index: &DropIndex) -> String {
@@ -218,7 +297,11 @@ impl SqlRenderer for SqliteFlavour {
         ]
     }
 
-    fn render_redefine_tables(&self, tables: &[RedefineTable], schemas: &Pair<&SqlSchema>) -> Vec<String> {
+    fn render_redefine_tables(
+        &self,
+        tables: &[RedefineTable],
+        schemas: &Pair<&SqlSchema>,
+    ) -> ConnectorResult<Vec<String>> {
         // Based on 'Making Other Kinds Of Table Schema Changes' from https://www.sqlite.org/lang_altertable.html
         let mut result: Vec<String> = Vec::new();
 
@@ -228,9 +311,20 @@ impl SqlRenderer for SqliteFlavour {
             let tables = schemas.tables(&redefine_table.table_index);
             let temporary_table_name = format!("new_{}", &tables.next().name());
 
-            result.push(self.render_create_table_as(tables.next(), &temporary_table_name));
+            let check_constraints = redefined_table_check_constraints(redefine_table, &tables);
 
-            copy_current_table_into_new_table(&mut result, redefine_table, &tables, &temporary_table_name, self);
+            // Like CHECK constraints, `WITHOUT ROWID` cannot be expressed in the Prisma schema:
+            // the table keeps it when it is redefined.
+            let without_rowid = tables.previous().without_rowid() || tables.next().without_rowid();
+
+            result.push(self.render_create_table_with_check_constraints(
+                tables.next(),
+                &temporary_table_name,
+                &check_constraints,
+                without_rowid,
+            )?);
+
+            copy_current_table_into_new_table(&mut result, redefine_table, &tables, &temporary_table_name, self)?;
 
             result.push(format!(r#"DROP TABLE "{}""#, tables.previous().name()));
 
@@ -248,12 +342,26 @@ impl SqlRenderer for SqliteFlavour {
         result.push("PRAGMA foreign_key_check".to_string());
         result.push("PRAGMA foreign_keys=ON".to_string());
 
-        result
+        Ok(result)
     }
 
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
         format!(r#"ALTER TABLE "{}" RENAME TO "{}""#, name, new_name)
     }
+
+    fn render_truncate_tables(&self, tables: &[TableWalker<'_>]) -> TruncateTables {
+        // There is no TRUNCATE on SQLite. An unqualified DELETE is optimized to the same effect.
+        // The pragma is a no-op in a transaction, so only the deletions run in one.
+        TruncateTables {
+            disable_checks: vec!["PRAGMA foreign_keys=off".to_owned()],
+            truncate: tables
+                .iter()
+                .map(|table| format!("DELETE FROM {}", self.quote(table.name())))
+                .collect(),
+            enable_checks: vec!["PRAGMA foreign_keys=on".to_owned()],
+            transactional: true,
+        }
+    }
 }
 
 fn render_column_type(t: &ColumnType) -> &'static str {
@@ -279,6 +387,46 @@ fn escape_quotes(s: &str) -> Cow<'_, str> {
     STRING_LITERAL_CHARACTER_TO_ESCAPE_RE.replace_all(s, "'$0")
 }
 
+/// The CHECK constraints of a redefined table. The next schema does not
+/// always describe CHECK constraints, so the constraints of the previous table
+/// are kept, unless they refer to a dropped column.
+fn redefined_table_check_constraints(
+    redefine_table: &RedefineTable,
+    tables: &Pair<TableWalker<'_>>,
+) -> Vec<CheckConstraint> {
+    let dropped_column_names: Vec<&str> = redefine_table
+        .dropped_columns
+        .iter()
+        .map(|column_index| tables.previous().column_at(*column_index).name())
+        .collect();
+
+    let mut check_constraints: Vec<CheckConstraint> = tables
+        .previous()
+        .check_constraints()
+        .iter()
+        .filter(|check_constraint| {
+            !dropped_column_names
+                .iter()
+                .any(|column_name| expression_mentions_identifier(&check_constraint.expression, column_name))
+        })
+        .cloned()
+        .collect();
+
+    for check_constraint in tables.next().check_constraints() {
+        if !check_constraints.contains(check_constraint) {
+            check_constraints.push(check_constraint.clone());
+        }
+    }
+
+    check_constraints
+}
+
+fn expression_mentions_identifier(expression: &str, identifier: &str) -> bool {
+    expression
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .any(|word| word.eq_ignore_ascii_case(identifier))
+}
+
 /// Copy the existing data into the new table.
 ///
 /// The process is complicated by the migrations that add make an optional column required with a
@@ -290,9 +438,9 @@ fn copy_current_table_into_new_table(
     tables: &Pair<TableWalker<'_>>,
     temporary_table_name: &str,
     flavour: &SqliteFlavour,
-) {
+) -> ConnectorResult<()> {
     if redefine_table.column_pairs.is_empty() {
-        return;
+        return Ok(());
     }
 
     let destination_columns = redefine_table
@@ -300,28 +448,32 @@ fn copy_current_table_into_new_table(
         .iter()
         .map(|(column_indexes, _, _)| tables.next().column_at(*column_indexes.next()).name());
 
-    let source_columns = redefine_table.column_pairs.iter().map(|(column_indexes, changes, _)| {
-        let columns = tables.columns(column_indexes);
-
-        let col_became_required_with_a_default =
-            changes.arity_changed() && columns.next().arity().is_required() && columns.next().default().is_some();
-
-        if col_became_required_with_a_default {
-            format!(
-                "coalesce({column_name}, {default_value}) AS {column_name}",
-                column_name = Quoted::sqlite_ident(columns.previous().name()),
-                default_value = flavour.render_default(
-                    columns
-                        .next()
-                        .default()
-                        .expect("default on required column with default"),
-                    &columns.next().column_type_family()
+    let source_columns = redefine_table
+        .column_pairs
+        .iter()
+        .map(|(column_indexes, changes, _)| -> ConnectorResult<String> {
+            let columns = tables.columns(column_indexes);
+
+            let col_became_required_with_a_default =
+                changes.arity_changed() && columns.next().arity().is_required() && columns.next().default().is_some();
+
+            Ok(if col_became_required_with_a_default {
+                format!(
+                    "coalesce({column_name}, {default_value}) AS {column_name}",
+                    column_name = Quoted::sqlite_ident(columns.previous().name()),
+                    default_value = flavour.render_default(
+                        columns
+                            .next()
+                            .default()
+                            .expect("default on required column with default"),
+                        &columns.next().column_type_family()
+                    )?
                 )
-            )
-        } else {
-            Quoted::sqlite_ident(columns.previous().name()).to_string()
-        }
-    });
+            } else {
+                Quoted::sqlite_ident(columns.previous().name()).to_string()
+            })
+        })
+        .collect::<ConnectorResult<Vec<_>>>()?;
 
     let query = format!(
         r#"INSERT INTO "{temporary_table_name}" ({destination_columns}) SELECT {source_columns} FROM "{previous_table_name}""#,
@@ -331,5 +483,7 @@ fn copy_current_table_into_new_table(
         previous_table_name = tables.previous().name(),
     );
 
-    steps.push(query)
+    steps.push(query);
+
+    Ok(())
 }
@@ -3,7 +3,10 @@ mod mysql;
 mod postgres;
 mod sqlite;
 
-use datamodel::{walkers::ModelWalker, walkers::ScalarFieldWalker, Datamodel, NativeTypeInstance, ScalarType};
+use datamodel::{
+    walkers::{ModelWalker, RelationFieldWalker, ScalarFieldWalker},
+    Datamodel, NativeTypeInstance, ScalarType,
+};
 use sql_schema_describer as sql;
 
 pub(crate) trait SqlSchemaCalculatorFlavour {
@@ -11,14 +14,74 @@ pub(crate) trait SqlSchemaCalculatorFlavour {
         Vec::new()
     }
 
+    /// The column type for a field with a native type. Native types the flavour does not know
+    /// about fall back to the default column type of the scalar type.
     fn column_type_for_native_type(
         &self,
         field: &ScalarFieldWalker<'_>,
         scalar_type: ScalarType,
         native_type_instance: &NativeTypeInstance,
-    ) -> sql::ColumnType;
+    ) -> sql::ColumnType {
+        column_type_for_unknown_native_type(field, scalar_type, native_type_instance)
+    }
+
+    /// The maximum length of identifiers, in bytes. Index names that are longer and would collide
+    /// once truncated are shortened with a hash suffix.
+    fn identifier_size_limit(&self) -> Option<usize> {
+        None
+    }
+
+    /// Whether index names must be unique in the whole schema, rather than in their table.
+    fn index_names_are_unique_per_schema(&self) -> bool {
+        false
+    }
 
+    /// The `ON DELETE` action of the foreign key of an inline relation: `CASCADE` when the
+    /// relation is required, `SET NULL` when it is optional.
+    fn relation_foreign_key_action(&self, relation_field: &RelationFieldWalker<'_>) -> sql::ForeignKeyAction {
+        if relation_field.arity().is_required() {
+            sql::ForeignKeyAction::Cascade
+        } else {
+            sql::ForeignKeyAction::SetNull
+        }
+    }
+
+    /// The `ON UPDATE` action of the foreign key of an inline relation.
+    fn relation_foreign_key_on_update_action(
+        &self,
+        _relation_field: &RelationFieldWalker<'_>,
+    ) -> sql::ForeignKeyAction {
+        sql::ForeignKeyAction::Cascade
+    }
+
+    /// The `ON DELETE` action of the foreign keys of implicit many-to-many relation tables.
     fn m2m_foreign_key_action(&self, _model_a: &ModelWalker<'_>, _model_b: &ModelWalker<'_>) -> sql::ForeignKeyAction {
         sql::ForeignKeyAction::Cascade
     }
+
+    /// The `ON UPDATE` action of the foreign keys of implicit many-to-many relation tables. It is
+    /// the same as the `ON DELETE` action by default.
+    fn m2m_foreign_key_on_update_action(
+        &self,
+        model_a: &ModelWalker<'_>,
+        model_b: &ModelWalker<'_>,
+    ) -> sql::ForeignKeyAction {
+        self.m2m_foreign_key_action(model_a, model_b)
+    }
+}
+
+fn column_type_for_unknown_native_type(
+    field: &ScalarFieldWalker<'_>,
+    scalar_type: ScalarType,
+    native_type_instance: &NativeTypeInstance,
+) -> sql::ColumnType {
+    tracing::warn!(
+        "Unknown native type `{}` on `{}.{}`, using the default column type for {:?}.",
+        native_type_instance.render(),
+        field.model().name(),
+        field.name(),
+        scalar_type,
+    );
+
+    super::column_type_for_scalar_type(&scalar_type, super::column_arity(field.arity()))
 }
@@ -1,21 +1,28 @@
-use super::SqlSchemaCalculatorFlavour;
-use crate::flavour::MssqlFlavour;
+use super::{column_type_for_unknown_native_type, SqlSchemaCalculatorFlavour};
+use crate::flavour::{MssqlFlavour, MSSQL_IDENTIFIER_SIZE_LIMIT};
 use datamodel::{
-    walkers::{ModelWalker, ScalarFieldWalker},
+    walkers::{ModelWalker, RelationFieldWalker, ScalarFieldWalker},
     FieldArity, NativeTypeInstance, ScalarType,
 };
 use native_types::{MsSqlType, MsSqlTypeParameter, NativeType};
 use sql_schema_describer::{ColumnArity, ColumnType, ColumnTypeFamily, ForeignKeyAction};
 
 impl SqlSchemaCalculatorFlavour for MssqlFlavour {
+    fn identifier_size_limit(&self) -> Option<usize> {
+        Some(MSSQL_IDENTIFIER_SIZE_LIMIT)
+    }
+
     fn column_type_for_native_type(
         &self,
         field: &ScalarFieldWalker<'_>,
-        _scalar_type: ScalarType,
+        scalar_type: ScalarType,
         native_type_instance: &NativeTypeInstance,
     ) -> ColumnType {
         use MsSqlType::*;
-        let mssql_type: MsSqlType = native_type_instance.deserialize_native_type();
+        let mssql_type: MsSqlType = match native_type_instance.try_deserialize_native_type() {
+            Some(mssql_type) => mssql_type,
+            None => return column_type_for_unknown_native_type(field, scalar_type, native_type_instance),
+        };
 
         let data_type = match mssql_type {
             TinyInt => "tinyint".to_string(),
@@ -78,6 +85,25 @@ impl SqlSchemaCalculatorFlavour for MssqlFlavour {
         }
     }
 
+    fn relation_foreign_key_action(&self, relation_field: &RelationFieldWalker<'_>) -> ForeignKeyAction {
+        // MSSQL rejects cascading actions on self-relations, because they would create a cycle.
+        if is_self_relation(relation_field) {
+            ForeignKeyAction::NoAction
+        } else if relation_field.arity().is_required() {
+            ForeignKeyAction::Cascade
+        } else {
+            ForeignKeyAction::SetNull
+        }
+    }
+
+    fn relation_foreign_key_on_update_action(&self, relation_field: &RelationFieldWalker<'_>) -> ForeignKeyAction {
+        if is_self_relation(relation_field) {
+            ForeignKeyAction::NoAction
+        } else {
+            ForeignKeyAction::Cascade
+        }
+    }
+
     fn m2m_foreign_key_action(&self, model_a: &ModelWalker<'_>, model_b: &ModelWalker<'_>) -> ForeignKeyAction {
         // MSSQL will crash when creating a cyclic cascade
         if model_a.name() == model_b.name() {
@@ -86,4 +112,19 @@ impl SqlSchemaCalculatorFlavour for MssqlFlavour {
             ForeignKeyAction::Cascade
         }
     }
+
+    fn m2m_foreign_key_on_update_action(
+        &self,
+        _model_a: &ModelWalker<'_>,
+        _model_b: &ModelWalker<'_>,
+    ) -> ForeignKeyAction {
+        // Cascading updates through both foreign keys of the relation table
+        // make multiple cascade paths, which SQL Server rejects as soon as the
+        // models are related in other ways.
+        ForeignKeyAction::NoAction
+    }
+}
+
+fn is_self_relation(relation_field: &RelationFieldWalker<'_>) -> bool {
+    relation_field.model().name() == relation_field.referenced_model().name()
 }
@@ -1,13 +1,37 @@
-use super::SqlSchemaCalculatorFlavour;
-use crate::flavour::MysqlFlavour;
+use super::{column_type_for_unknown_native_type, SqlSchemaCalculatorFlavour};
+use crate::flavour::{MysqlFlavour, MYSQL_IDENTIFIER_SIZE_LIMIT};
 use datamodel::{
-    walkers::{walk_scalar_fields, ScalarFieldWalker},
+    walkers::{walk_scalar_fields, RelationFieldWalker, ScalarFieldWalker},
     Datamodel, NativeTypeInstance, ScalarType,
 };
 use native_types::MySqlType;
 use sql_schema_describer::{self as sql};
 
 impl SqlSchemaCalculatorFlavour for MysqlFlavour {
+    fn identifier_size_limit(&self) -> Option<usize> {
+        Some(MYSQL_IDENTIFIER_SIZE_LIMIT)
+    }
+
+    fn relation_foreign_key_action(&self, relation_field: &RelationFieldWalker<'_>) -> sql::ForeignKeyAction {
+        // Cascading actions on self-relations can make MySQL reject the foreign key, or fail when
+        // the cascade recurses into the same table, so they do not cascade.
+        if is_self_relation(relation_field) {
+            sql::ForeignKeyAction::NoAction
+        } else if relation_field.arity().is_required() {
+            sql::ForeignKeyAction::Cascade
+        } else {
+            sql::ForeignKeyAction::SetNull
+        }
+    }
+
+    fn relation_foreign_key_on_update_action(&self, relation_field: &RelationFieldWalker<'_>) -> sql::ForeignKeyAction {
+        if is_self_relation(relation_field) {
+            sql::ForeignKeyAction::NoAction
+        } else {
+            sql::ForeignKeyAction::Cascade
+        }
+    }
+
     fn calculate_enums(&self, datamodel: &Datamodel) -> Vec<sql::Enum> {
         // This is a lower bound for the size of the generated enums (we assume
         // each enum is used at least once).
@@ -35,10 +59,13 @@ impl SqlSchemaCalculatorFlavour for MysqlFlavour {
     fn column_type_for_native_type(
         &self,
         field: &ScalarFieldWalker<'_>,
-        _scalar_type: ScalarType,
+        scalar_type: ScalarType,
         native_type_instance: &NativeTypeInstance,
     ) -> sql::ColumnType {
-        let mysql_type: MySqlType = native_type_instance.deserialize_native_type();
+        let mysql_type: MySqlType = match native_type_instance.try_deserialize_native_type() {
+            Some(mysql_type) => mysql_type,
+            None => return column_type_for_unknown_native_type(field, scalar_type, native_type_instance),
+        };
 
         fn render(input: Option<u32>) -> String {
             match input {
@@ -104,3 +131,7 @@ impl SqlSchemaCalculatorFlavour for MysqlFlavour {
         }
     }
 }
+
+fn is_self_relation(relation_field: &RelationFieldWalker<'_>) -> bool {
+    relation_field.model().name() == relation_field.referenced_model().name()
+}
@@ -1,10 +1,19 @@
-use super::SqlSchemaCalculatorFlavour;
-use crate::flavour::PostgresFlavour;
+use super::{column_type_for_unknown_native_type, SqlSchemaCalculatorFlavour};
+use crate::flavour::{PostgresFlavour, POSTGRES_IDENTIFIER_SIZE_LIMIT};
 use datamodel::{walkers::ScalarFieldWalker, Datamodel, NativeTypeInstance, ScalarType, WithDatabaseName};
 use native_types::PostgresType;
 use sql_schema_describer::{self as sql};
 
 impl SqlSchemaCalculatorFlavour for PostgresFlavour {
+    fn identifier_size_limit(&self) -> Option<usize> {
+        Some(POSTGRES_IDENTIFIER_SIZE_LIMIT)
+    }
+
+    // Indexes are relations, and share a namespace with tables.
+    fn index_names_are_unique_per_schema(&self) -> bool {
+        true
+    }
+
     fn calculate_enums(&self, datamodel: &Datamodel) -> Vec<sql::Enum> {
         datamodel
             .enums()
@@ -18,10 +27,13 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
     fn column_type_for_native_type(
         &self,
         field: &ScalarFieldWalker<'_>,
-        _scalar_type: ScalarType,
+        scalar_type: ScalarType,
         native_type_instance: &NativeTypeInstance,
     ) -> sql::ColumnType {
-        let postgres_type: PostgresType = native_type_instance.deserialize_native_type();
+        let postgres_type: PostgresType = match native_type_instance.try_deserialize_native_type() {
+            Some(postgres_type) => postgres_type,
+            None => return column_type_for_unknown_native_type(field, scalar_type, native_type_instance),
+        };
 
         fn render(input: Option<u32>) -> String {
             match input {
@@ -65,11 +77,20 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
             PostgresType::JSONB => "JSONB".to_owned(),
         };
 
+        let family = match postgres_type {
+            PostgresType::Timestamp(_)
+            | PostgresType::Timestamptz(_)
+            | PostgresType::Date
+            | PostgresType::Time(_)
+            | PostgresType::Timetz(_) => sql::ColumnTypeFamily::DateTime,
+            _ => sql::ColumnTypeFamily::String,
+        };
+
         sql::ColumnType {
             data_type: data_type.clone(),
             full_data_type: data_type,
             character_maximum_length: None,
-            family: sql::ColumnTypeFamily::String,
+            family,
             arity: match field.arity() {
                 datamodel::FieldArity::Required => sql::ColumnArity::Required,
                 datamodel::FieldArity::Optional => sql::ColumnArity::Nullable,
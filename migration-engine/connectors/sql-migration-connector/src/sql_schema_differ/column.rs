@@ -1,4 +1,4 @@
-use crate::flavour::SqlFlavour;
+use crate::{flavour::SqlFlavour, pair::Pair};
 use enumflags2::BitFlags;
 use prisma_value::PrismaValue;
 use sql_schema_describer::{walkers::ColumnWalker, ColumnTypeFamily, DefaultValue};
@@ -11,6 +11,17 @@ pub(crate) struct ColumnDiffer<'a> {
 }
 
 impl<'a> ColumnDiffer<'a> {
+    pub(crate) fn new(flavour: &'a dyn SqlFlavour, columns: Pair<ColumnWalker<'a>>) -> Self {
+        let (previous, next) = columns.into_tuple();
+
+        ColumnDiffer {
+            flavour,
+            previous,
+            next,
+        }
+    }
+
+    /// The changes between the previous and the next column, and how the type changed, if it did.
     pub(crate) fn all_changes(&self) -> (ColumnChanges, Option<ColumnTypeChange>) {
         let mut changes = BitFlags::empty();
         let column_type_change = self.column_type_change();
@@ -35,6 +46,10 @@ impl<'a> ColumnDiffer<'a> {
             changes |= ColumnChange::Sequence;
         };
 
+        if self.collation_changed() {
+            changes |= ColumnChange::Collation;
+        };
+
         (ColumnChanges { changes }, column_type_change)
     }
 
@@ -42,9 +57,15 @@ impl<'a> ColumnDiffer<'a> {
         self.previous.arity() != self.next.arity()
     }
 
+    /// Columns without a described collation use the default collation of their type.
+    fn collation_changed(&self) -> bool {
+        self.previous.collation() != self.next.collation()
+    }
+
     fn column_type_change(&self) -> Option<ColumnTypeChange> {
         match (self.previous.column_type_family(), self.next.column_type_family()) {
             (_, _) if self.arity_changed() => self.flavour.column_type_change(self),
+            (_, _) if self.full_data_types_match() => None,
             (ColumnTypeFamily::Decimal, ColumnTypeFamily::Decimal) => None,
             (ColumnTypeFamily::Decimal, ColumnTypeFamily::Float) => None,
             (ColumnTypeFamily::Float, ColumnTypeFamily::Decimal) => None,
@@ -53,6 +74,17 @@ impl<'a> ColumnDiffer<'a> {
         }
     }
 
+    /// Whether both columns have the same full data type, ignoring cosmetic
+    /// differences like case or type aliases.
+    fn full_data_types_match(&self) -> bool {
+        let previous = &self.previous.column_type().full_data_type;
+        let next = &self.next.column_type().full_data_type;
+
+        !previous.is_empty()
+            && !next.is_empty()
+            && self.flavour.canonical_full_data_type(previous) == self.flavour.canonical_full_data_type(next)
+    }
+
     /// There are workarounds to cope with current migration and introspection limitations.
     ///
     /// - We bail on a number of cases that are too complex to deal with right now or underspecified.
@@ -122,6 +154,7 @@ pub(crate) enum ColumnChange {
     Default = 0b0100,
     TypeChanged = 0b1000,
     Sequence = 0b0010000,
+    Collation = 0b0100000,
 }
 
 // This should be pub(crate), but SqlMigration is exported, so it has to be
@@ -132,6 +165,20 @@ pub struct ColumnChanges {
 }
 
 impl ColumnChanges {
+    /// Build a set of changes directly, for tests.
+    #[cfg(test)]
+    pub(crate) fn new(changes: impl IntoIterator<Item = ColumnChange>) -> Self {
+        let changes = changes
+            .into_iter()
+            .fold(BitFlags::empty(), |changes, change| changes | change);
+
+        ColumnChanges { changes }
+    }
+
+    pub(crate) fn contains(&self, change: ColumnChange) -> bool {
+        self.changes.contains(change)
+    }
+
     pub(crate) fn differs_in_something(&self) -> bool {
         !self.changes.is_empty()
     }
@@ -141,11 +188,11 @@ impl ColumnChanges {
     }
 
     pub(crate) fn type_changed(&self) -> bool {
-        self.changes.contains(ColumnChange::TypeChanged)
+        self.contains(ColumnChange::TypeChanged)
     }
 
     pub(crate) fn arity_changed(&self) -> bool {
-        self.changes.contains(ColumnChange::Arity)
+        self.contains(ColumnChange::Arity)
     }
 
     pub(crate) fn only_default_changed(&self) -> bool {
@@ -157,7 +204,7 @@ impl ColumnChanges {
     }
 
     pub(crate) fn column_was_renamed(&self) -> bool {
-        self.changes.contains(ColumnChange::Renaming)
+        self.contains(ColumnChange::Renaming)
     }
 }
 
@@ -167,3 +214,150 @@ pub enum ColumnTypeChange {
     RiskyCast,
     NotCastable,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flavour::from_connection_info;
+    use quaint::prelude::ConnectionInfo;
+    use sql_schema_describer::{walkers::SqlSchemaExt, Column, ColumnArity, ColumnType, SqlSchema, Table};
+
+    fn schema_with_column(column: Column) -> SqlSchema {
+        SqlSchema {
+            tables: vec![Table {
+                name: "Cat".to_owned(),
+                columns: vec![column],
+                indices: Vec::new(),
+                primary_key: None,
+                foreign_keys: Vec::new(),
+                ddl: None,
+                collation: None,
+                check_constraints: Vec::new(),
+                policies: Vec::new(),
+                partitioning: None,
+                row_level_security: false,
+                without_rowid: false,
+            }],
+            enums: Vec::new(),
+            sequences: Vec::new(),
+            synonyms: Vec::new(),
+            flavour: None,
+        }
+    }
+
+    fn column(family: ColumnTypeFamily, full_data_type: &str, arity: ColumnArity) -> Column {
+        Column {
+            name: "age".to_owned(),
+            tpe: ColumnType::with_full_data_type(family, arity, full_data_type.to_owned()),
+            default: None,
+            auto_increment: false,
+            identity: None,
+            identity_options: None,
+            invisible: false,
+            on_update_current_timestamp: false,
+            default_constraint_name: None,
+            collation: None,
+            storage: None,
+        }
+    }
+
+    fn changes(previous: Column, next: Column) -> (ColumnChanges, Option<ColumnTypeChange>) {
+        let flavour = from_connection_info(
+            &ConnectionInfo::from_url("postgresql://localhost:5432/prisma?schema=public").unwrap(),
+        );
+        let schemas = Pair::new(schema_with_column(previous), schema_with_column(next));
+        let columns = schemas.as_ref().map(|schema| schema.table_walker_at(0).column_at(0));
+
+        ColumnDiffer::new(flavour.as_ref(), columns).all_changes()
+    }
+
+    #[test]
+    fn identical_columns_have_no_changes() {
+        let (changes, type_change) = changes(
+            column(ColumnTypeFamily::Int, "integer", ColumnArity::Required),
+            column(ColumnTypeFamily::Int, "integer", ColumnArity::Required),
+        );
+
+        assert!(!changes.differs_in_something());
+        assert_eq!(changes, ColumnChanges::default());
+        assert_eq!(type_change, None);
+    }
+
+    #[test]
+    fn default_changes_are_detected_alone() {
+        let mut next = column(ColumnTypeFamily::Int, "integer", ColumnArity::Required);
+        next.default = Some(DefaultValue::VALUE(PrismaValue::Int(1)));
+
+        let (changes, type_change) = changes(column(ColumnTypeFamily::Int, "integer", ColumnArity::Required), next);
+
+        assert_eq!(changes, ColumnChanges::new(vec![ColumnChange::Default]));
+        assert!(changes.only_default_changed());
+        assert_eq!(type_change, None);
+    }
+
+    #[test]
+    fn arity_and_default_changes_are_detected_together() {
+        let mut next = column(ColumnTypeFamily::Int, "integer", ColumnArity::Nullable);
+        next.default = Some(DefaultValue::VALUE(PrismaValue::Int(1)));
+
+        let (changes, _) = changes(column(ColumnTypeFamily::Int, "integer", ColumnArity::Required), next);
+
+        assert_eq!(
+            changes,
+            ColumnChanges::new(vec![ColumnChange::Arity, ColumnChange::Default])
+        );
+        assert!(changes.arity_changed());
+        assert!(!changes.only_default_changed());
+        assert!(!changes.type_changed());
+    }
+
+    #[test]
+    fn type_changes_come_with_the_type_change() {
+        let (changes, type_change) = changes(
+            column(ColumnTypeFamily::Int, "integer", ColumnArity::Required),
+            column(ColumnTypeFamily::String, "text", ColumnArity::Required),
+        );
+
+        assert!(changes.only_type_changed());
+        assert_eq!(changes.iter().collect::<Vec<_>>(), &[ColumnChange::TypeChanged]);
+        assert_eq!(type_change, Some(ColumnTypeChange::SafeCast));
+    }
+
+    #[test]
+    fn renamings_and_sequence_changes_are_detected() {
+        let mut next = column(ColumnTypeFamily::Int, "integer", ColumnArity::Required);
+        next.name = "years".to_owned();
+        next.auto_increment = true;
+
+        let (changes, _) = changes(column(ColumnTypeFamily::Int, "integer", ColumnArity::Required), next);
+
+        assert!(changes.column_was_renamed());
+        assert!(changes.contains(ColumnChange::Sequence));
+        assert_eq!(
+            changes,
+            ColumnChanges::new(vec![ColumnChange::Renaming, ColumnChange::Sequence])
+        );
+    }
+
+    #[test]
+    fn collation_changes_are_detected_in_both_directions() {
+        let mut collated = column(ColumnTypeFamily::String, "text", ColumnArity::Required);
+        collated.collation = Some("C".to_owned());
+
+        let (collation_changes, type_change) = changes(
+            column(ColumnTypeFamily::String, "text", ColumnArity::Required),
+            collated.clone(),
+        );
+
+        assert_eq!(collation_changes, ColumnChanges::new(vec![ColumnChange::Collation]));
+        assert_eq!(type_change, None);
+
+        let (reset_changes, type_change) = changes(
+            collated,
+            column(ColumnTypeFamily::String, "text", ColumnArity::Required),
+        );
+
+        assert_eq!(reset_changes, ColumnChanges::new(vec![ColumnChange::Collation]));
+        assert_eq!(type_change, None);
+    }
+}
@@ -5,3 +5,11 @@ pub(super) fn index_covers_fk(table: &TableWalker<'_>, index: &IndexWalker<'_>)
         .foreign_keys()
         .any(|fk| fk.constrained_column_names() == index.column_names())
 }
+
+/// Whether the index is made of exactly the columns of the primary key. Such an
+/// index is redundant: the database already maintains one for the primary key.
+pub(super) fn index_duplicates_primary_key(table: &TableWalker<'_>, index: &IndexWalker<'_>) -> bool {
+    index.expressions().is_empty()
+        && index.algorithm().is_none()
+        && table.primary_key_column_names() == Some(index.column_names())
+}
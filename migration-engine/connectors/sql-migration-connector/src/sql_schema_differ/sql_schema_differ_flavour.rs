@@ -22,6 +22,20 @@ pub(crate) trait SqlSchemaDifferFlavour {
         true
     }
 
+    /// Whether columns can be dropped with `CASCADE`, dropping the objects
+    /// depending on them too.
+    fn can_cascade_column_drops(&self) -> bool {
+        false
+    }
+
+    /// Canonicalize a full data type, so that cosmetic differences like case or
+    /// aliases of the same type (`int` and `integer`) compare equal.
+    fn canonical_full_data_type(&self, full_data_type: &str) -> String {
+        let (name, rest) = split_full_data_type(full_data_type);
+
+        format!("{}{}", name, rest)
+    }
+
     /// Return whether a column's type needs to be migrated, and how.
     fn column_type_change(&self, differ: &ColumnDiffer<'_>) -> Option<ColumnTypeChange> {
         if differ.previous.column_type_family() != differ.next.column_type_family() {
@@ -36,12 +50,24 @@ pub(crate) trait SqlSchemaDifferFlavour {
         indexes.previous().name() != indexes.next().name()
     }
 
+    /// Whether consecutive AlterTable steps on the same table should be merged
+    /// into one, so they are rendered as a single `ALTER TABLE` statement.
+    fn should_combine_alter_tables(&self) -> bool {
+        false
+    }
+
     /// Whether the differ should produce CreateIndex steps for the indexes of
     /// new tables.
     fn should_create_indexes_from_created_tables(&self) -> bool {
         true
     }
 
+    /// Whether the foreign keys of dropped tables should be dropped before the
+    /// tables are dropped.
+    fn should_drop_foreign_keys_from_dropped_tables(&self) -> bool {
+        true
+    }
+
     /// Whether the indexes of dropped tables should be dropped before the table
     /// is dropped.
     fn should_drop_indexes_from_dropped_tables(&self) -> bool {
@@ -63,11 +89,6 @@ pub(crate) trait SqlSchemaDifferFlavour {
         false
     }
 
-    /// Whether a specific index should *not* be produced.
-    fn should_skip_index_for_new_table(&self, _index: &IndexWalker<'_>) -> bool {
-        false
-    }
-
     fn table_names_match(&self, names: Pair<&str>) -> bool {
         names.previous() == names.next()
     }
@@ -84,3 +105,19 @@ pub(crate) trait SqlSchemaDifferFlavour {
         false
     }
 }
+
+/// Split a full data type like `VARCHAR(191)` into its lowercased,
+/// whitespace-normalized name (`varchar`) and the rest (`(191)`), which is left
+/// untouched because it can contain case-sensitive values, like enum variants.
+pub(super) fn split_full_data_type(full_data_type: &str) -> (String, &str) {
+    let full_data_type = full_data_type.trim();
+    let (name, rest) = full_data_type.split_at(full_data_type.find('(').unwrap_or_else(|| full_data_type.len()));
+
+    let name = name
+        .split_whitespace()
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    (name, rest)
+}
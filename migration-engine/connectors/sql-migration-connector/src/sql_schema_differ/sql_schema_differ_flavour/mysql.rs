@@ -1,18 +1,42 @@
-use super::SqlSchemaDifferFlavour;
+use super::{split_full_data_type, SqlSchemaDifferFlavour};
 use crate::{
     flavour::MysqlFlavour, flavour::MYSQL_IDENTIFIER_SIZE_LIMIT, pair::Pair, sql_schema_differ::column::ColumnDiffer,
     sql_schema_differ::ColumnTypeChange,
 };
-use sql_schema_describer::{walkers::IndexWalker, ColumnTypeFamily};
+use sql_schema_describer::{
+    walkers::{ColumnWalker, IndexWalker},
+    ColumnTypeFamily,
+};
 
 /// On MariaDB, JSON is an alias for LONGTEXT. https://mariadb.com/kb/en/json-data-type/
 const MARIADB_ALIASES: &[ColumnTypeFamily] = &[ColumnTypeFamily::String, ColumnTypeFamily::Json];
 
+const INTEGER_TYPES: &[&str] = &["tinyint", "smallint", "mediumint", "int", "bigint"];
+
 impl SqlSchemaDifferFlavour for MysqlFlavour {
     fn can_alter_index(&self) -> bool {
         !self.is_mariadb() && !self.is_mysql_5_6()
     }
 
+    fn canonical_full_data_type(&self, full_data_type: &str) -> String {
+        let (name, rest) = split_full_data_type(full_data_type);
+
+        let canonical_name = match name.as_str() {
+            // BOOL is a synonym for TINYINT(1).
+            "bool" | "boolean" if rest.is_empty() => return "tinyint(1)".to_owned(),
+            "integer" => "int",
+            "dec" | "numeric" | "fixed" => "decimal",
+            "double precision" => "double",
+            other => other,
+        };
+
+        if INTEGER_TYPES.contains(&canonical_name) {
+            return canonical_integer_type(canonical_name, rest);
+        }
+
+        format!("{}{}", canonical_name, rest)
+    }
+
     fn column_type_change(&self, differ: &ColumnDiffer<'_>) -> Option<ColumnTypeChange> {
         if self.is_mariadb()
             && MARIADB_ALIASES.contains(&differ.previous.column_type_family())
@@ -29,6 +53,15 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
             };
         }
 
+        // INT and INT UNSIGNED share the same family, but they do not have the same range.
+        if matches!(
+            differ.previous.column_type_family(),
+            ColumnTypeFamily::Int | ColumnTypeFamily::BigInt
+        ) && is_unsigned(&differ.previous) != is_unsigned(&differ.next)
+        {
+            return Some(ColumnTypeChange::RiskyCast);
+        }
+
         if let (Some(previous_enum), Some(next_enum)) = (
             differ.previous.column_type_family_as_enum(),
             differ.next.column_type_family_as_enum(),
@@ -62,6 +95,11 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
         }
     }
 
+    // Each ALTER TABLE can rebuild the whole table on MySQL.
+    fn should_combine_alter_tables(&self) -> bool {
+        true
+    }
+
     fn should_create_indexes_from_created_tables(&self) -> bool {
         false
     }
@@ -82,3 +120,44 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
         }
     }
 }
+
+/// The display width of integer types, as in `INT(11)`, is cosmetic, and MySQL 8
+/// does not report it anymore. It is only kept when it matters: for the padding
+/// of `ZEROFILL` columns, and in `TINYINT(1)`, which is how MySQL spells
+/// booleans.
+fn canonical_integer_type(name: &str, rest: &str) -> String {
+    let rest = rest.to_ascii_lowercase();
+    let (display_width, attributes) = match (rest.starts_with('('), rest.find(')')) {
+        (true, Some(closing_paren)) => (Some(rest[1..closing_paren].trim()), &rest[closing_paren + 1..]),
+        _ => (None, rest.as_str()),
+    };
+
+    let attributes: Vec<&str> = attributes.split_whitespace().collect();
+    let is_zerofill = attributes.contains(&"zerofill");
+
+    let mut canonical = name.to_owned();
+
+    match display_width {
+        Some(display_width) if is_zerofill || (name == "tinyint" && display_width == "1") => {
+            canonical.push('(');
+            canonical.push_str(display_width);
+            canonical.push(')');
+        }
+        _ => (),
+    }
+
+    for attribute in attributes {
+        canonical.push(' ');
+        canonical.push_str(attribute);
+    }
+
+    canonical
+}
+
+fn is_unsigned(column: &ColumnWalker<'_>) -> bool {
+    column
+        .column_type()
+        .full_data_type
+        .to_ascii_lowercase()
+        .contains("unsigned")
+}
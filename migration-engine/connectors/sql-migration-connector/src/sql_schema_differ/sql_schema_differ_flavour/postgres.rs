@@ -1,6 +1,6 @@
-use super::SqlSchemaDifferFlavour;
+use super::{split_full_data_type, SqlSchemaDifferFlavour};
 use crate::{
-    flavour::PostgresFlavour,
+    flavour::{PostgresFlavour, POSTGRES_IDENTIFIER_SIZE_LIMIT},
     pair::Pair,
     sql_migration::AlterEnum,
     sql_schema_differ::column::{ColumnDiffer, ColumnTypeChange},
@@ -10,11 +10,6 @@ use once_cell::sync::Lazy;
 use regex::RegexSet;
 use sql_schema_describer::{walkers::IndexWalker, ColumnTypeFamily};
 
-/// The maximum length of postgres identifiers, in bytes.
-///
-/// Reference: https://www.postgresql.org/docs/12/limits.html
-const POSTGRES_IDENTIFIER_SIZE_LIMIT: usize = 63;
-
 impl SqlSchemaDifferFlavour for PostgresFlavour {
     fn alter_enums(&self, differ: &SqlSchemaDiffer<'_>) -> Vec<AlterEnum> {
         differ
@@ -35,6 +30,33 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
             .collect()
     }
 
+    fn can_cascade_column_drops(&self) -> bool {
+        true
+    }
+
+    fn canonical_full_data_type(&self, full_data_type: &str) -> String {
+        let (name, rest) = split_full_data_type(full_data_type);
+
+        let canonical_name = match name.as_str() {
+            "int" | "int4" => "integer",
+            "int2" => "smallint",
+            "int8" => "bigint",
+            "bool" => "boolean",
+            "float4" => "real",
+            "float8" => "double precision",
+            "decimal" => "numeric",
+            "varchar" => "character varying",
+            "char" | "bpchar" => "character",
+            "timestamp" => "timestamp without time zone",
+            "timestamptz" => "timestamp with time zone",
+            "time" => "time without time zone",
+            "timetz" => "time with time zone",
+            other => other,
+        };
+
+        format!("{}{}", canonical_name, rest)
+    }
+
     fn column_type_change(&self, differ: &ColumnDiffer<'_>) -> Option<ColumnTypeChange> {
         // List to scalar
         if differ.previous.arity().is_list() && !differ.next.arity().is_list() {
@@ -62,9 +84,8 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
 
         match (differ.previous.column_type_family(), differ.next.column_type_family()) {
             (_, ColumnTypeFamily::String) => Some(ColumnTypeChange::SafeCast),
-            (ColumnTypeFamily::String, ColumnTypeFamily::Int)
-            | (ColumnTypeFamily::DateTime, ColumnTypeFamily::Float)
-            | (ColumnTypeFamily::String, ColumnTypeFamily::Float) => Some(ColumnTypeChange::NotCastable),
+            (ColumnTypeFamily::DateTime, ColumnTypeFamily::Float) => Some(ColumnTypeChange::NotCastable),
+            // Rendered with an explicit `USING` cast.
             (_, _) => Some(ColumnTypeChange::RiskyCast),
         }
     }
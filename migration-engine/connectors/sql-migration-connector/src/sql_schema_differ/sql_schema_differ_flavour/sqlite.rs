@@ -17,6 +17,12 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
         }
     }
 
+    fn should_drop_foreign_keys_from_dropped_tables(&self) -> bool {
+        // Dropping a foreign key is not possible on SQLite. The tables are
+        // dropped with foreign keys disabled instead.
+        false
+    }
+
     fn should_drop_indexes_from_dropped_tables(&self) -> bool {
         true
     }
@@ -18,11 +18,7 @@ impl<'schema> TableDiffer<'schema> {
                     .find(|next_column| columns_match(&previous_column, next_column))
                     .map(|next_column| (previous_column, next_column))
             })
-            .map(move |(previous, next)| ColumnDiffer {
-                flavour: self.flavour,
-                previous,
-                next,
-            })
+            .map(move |(previous, next)| ColumnDiffer::new(self.flavour, Pair::new(previous, next)))
     }
 
     pub(crate) fn dropped_columns<'a>(&'a self) -> impl Iterator<Item = ColumnWalker<'schema>> + 'a {
@@ -81,18 +77,37 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    /// The positions of the row-level security policies of the previous table that are not
+    /// in the next table, or differ there.
+    pub(crate) fn dropped_policies<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        let next_policies = self.next().policies();
+
+        self.previous()
+            .policies()
+            .iter()
+            .enumerate()
+            .filter(move |(_, policy)| !next_policies.contains(policy))
+            .map(|(position, _)| position)
+    }
+
+    /// The positions of the row-level security policies of the next table that are not in the
+    /// previous table, or differ there.
+    pub(crate) fn created_policies<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        let previous_policies = self.previous().policies();
+
+        self.next()
+            .policies()
+            .iter()
+            .enumerate()
+            .filter(move |(_, policy)| !previous_policies.contains(policy))
+            .map(|(position, _)| position)
+    }
+
     /// The primary key present in `next` but not `previous`, if applicable.
     pub(crate) fn created_primary_key(&self) -> Option<&'schema PrimaryKey> {
         match self.tables.as_ref().map(|t| t.primary_key()).as_tuple() {
             (None, Some(pk)) => Some(pk),
-            (Some(previous_pk), Some(next_pk)) if previous_pk.columns != next_pk.columns => Some(next_pk),
-            (Some(previous_pk), Some(next_pk)) => {
-                if self.primary_key_column_changed(previous_pk) {
-                    Some(next_pk)
-                } else {
-                    None
-                }
-            }
+            (Some(previous_pk), Some(next_pk)) if self.primary_key_changed(previous_pk, next_pk) => Some(next_pk),
             _ => None,
         }
     }
@@ -101,18 +116,18 @@ impl<'schema> TableDiffer<'schema> {
     pub(crate) fn dropped_primary_key(&self) -> Option<&'schema PrimaryKey> {
         match self.tables.as_ref().map(|t| t.primary_key()).as_tuple() {
             (Some(pk), None) => Some(pk),
-            (Some(previous_pk), Some(next_pk)) if previous_pk.columns != next_pk.columns => Some(previous_pk),
-            (Some(previous_pk), Some(_next_pk)) => {
-                if self.primary_key_column_changed(previous_pk) {
-                    Some(previous_pk)
-                } else {
-                    None
-                }
-            }
+            (Some(previous_pk), Some(next_pk)) if self.primary_key_changed(previous_pk, next_pk) => Some(previous_pk),
             _ => None,
         }
     }
 
+    /// Returns true if the primary key has to be dropped and recreated. The
+    /// comparison of the columns is order-sensitive: reordering the columns of
+    /// a compound primary key changes the underlying index.
+    fn primary_key_changed(&self, previous_pk: &PrimaryKey, next_pk: &PrimaryKey) -> bool {
+        previous_pk.columns != next_pk.columns || self.primary_key_column_changed(previous_pk)
+    }
+
     /// Returns true if any of the columns of the primary key changed type.
     fn primary_key_column_changed(&self, previous_pk: &PrimaryKey) -> bool {
         self.column_pairs()
@@ -163,6 +178,18 @@ pub(crate) fn columns_match(a: &ColumnWalker<'_>, b: &ColumnWalker<'_>) -> bool
 }
 
 /// Compare two SQL indexes and return whether they only differ by name.
-fn indexes_match(first: &IndexWalker<'_>, second: &IndexWalker<'_>) -> bool {
-    first.column_names() == second.column_names() && first.index_type() == second.index_type()
+///
+/// The indexes calculated from a datamodel never have the attributes below, so these are only
+/// compared when the next index has them. Otherwise, every migration would recreate the indexes
+/// that were tuned outside of Prisma.
+fn indexes_match(previous: &IndexWalker<'_>, next: &IndexWalker<'_>) -> bool {
+    previous.column_names() == next.column_names()
+        && previous.expressions() == next.expressions()
+        && previous.index_type() == next.index_type()
+        && (next.storage_parameters().is_empty() || previous.storage_parameters() == next.storage_parameters())
+        && (next.algorithm().is_none() || previous.algorithm() == next.algorithm())
+        && (!next.is_invisible() || previous.is_invisible())
+        && (next.comment().is_none() || previous.comment() == next.comment())
+        && (!next.nulls_not_distinct() || previous.nulls_not_distinct())
+        && (next.opclasses().is_empty() || previous.opclasses() == next.opclasses())
 }
@@ -0,0 +1,140 @@
+//! Validation of the target datamodel against the capabilities of the connector, before any
+//! migration is calculated or rendered.
+
+use datamodel::{
+    walkers::{walk_scalar_fields, TypeWalker},
+    Datamodel, FieldArity, ScalarType,
+};
+use datamodel_connector::Connector;
+use migration_connector::{ConnectorError, ConnectorResult};
+use quaint::prelude::SqlFamily;
+use sql_datamodel_connector::SqlDatamodelConnectors;
+
+/// Check that the datamodel does not use features the connector does not support, like scalar
+/// lists on MySQL or enums on SQLite. The datamodel parser only validates datamodels with a
+/// datasource, so this catches the datamodels that reach the migration connector without one,
+/// instead of panicking in the renderers.
+pub(crate) fn validate_for_connector(datamodel: &Datamodel, sql_family: SqlFamily) -> ConnectorResult<()> {
+    let (connector_name, connector) = datamodel_connector(sql_family);
+    let mut errors = Vec::new();
+
+    if !connector.supports_enums() {
+        for r#enum in &datamodel.enums {
+            errors.push(format!(
+                "The enum `{}` can't be used: enums are not supported.",
+                r#enum.name
+            ));
+        }
+    }
+
+    for field in walk_scalar_fields(datamodel) {
+        if field.arity() == FieldArity::List && !connector.supports_scalar_lists() {
+            errors.push(format!(
+                "The field `{}` in model `{}` can't be a list: scalar lists are not supported.",
+                field.name(),
+                field.model().name()
+            ));
+        }
+
+        if let TypeWalker::Base(ScalarType::Json) = field.field_type() {
+            if !connector.supports_json() {
+                errors.push(format!(
+                    "The field `{}` in model `{}` can't be of type Json: the Json type is not supported.",
+                    field.name(),
+                    field.model().name()
+                ));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    Err(ConnectorError::generic(anyhow::anyhow!(
+        "The schema uses features the {} connector does not support.\n{}",
+        connector_name,
+        errors.join("\n")
+    )))
+}
+
+fn datamodel_connector(sql_family: SqlFamily) -> (&'static str, Box<dyn Connector>) {
+    let connector: Box<dyn Connector> = match sql_family {
+        SqlFamily::Postgres => Box::new(SqlDatamodelConnectors::postgres()),
+        SqlFamily::Mysql => Box::new(SqlDatamodelConnectors::mysql()),
+        SqlFamily::Sqlite => Box::new(SqlDatamodelConnectors::sqlite()),
+        SqlFamily::Mssql => Box::new(SqlDatamodelConnectors::mssql()),
+    };
+
+    let connector_name = match sql_family {
+        SqlFamily::Postgres => "postgresql",
+        SqlFamily::Mysql => "mysql",
+        SqlFamily::Sqlite => "sqlite",
+        SqlFamily::Mssql => "sqlserver",
+    };
+
+    (connector_name, connector)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(dm: &str) -> Datamodel {
+        datamodel::parse_datamodel(dm).unwrap().subject
+    }
+
+    #[test]
+    fn scalar_lists_on_sqlite_are_a_validation_error() {
+        let dm = parse(
+            r#"
+            datasource db {
+                provider = "postgresql"
+                url = "postgresql://localhost:5432/prisma"
+            }
+
+            model Cat {
+                id Int @id
+                names String[]
+            }
+            "#,
+        );
+
+        let err = validate_for_connector(&dm, SqlFamily::Sqlite).unwrap_err().to_string();
+
+        assert!(err.contains("sqlite connector does not support"), "{}", err);
+        assert!(
+            err.contains("The field `names` in model `Cat` can't be a list"),
+            "{}",
+            err
+        );
+        assert!(validate_for_connector(&dm, SqlFamily::Postgres).is_ok());
+    }
+
+    #[test]
+    fn enums_on_sqlite_are_a_validation_error() {
+        let dm = parse(
+            r#"
+            datasource db {
+                provider = "postgresql"
+                url = "postgresql://localhost:5432/prisma"
+            }
+
+            model Cat {
+                id Int @id
+                mood Mood
+            }
+
+            enum Mood {
+                HAPPY
+                HUNGRY
+            }
+            "#,
+        );
+
+        let err = validate_for_connector(&dm, SqlFamily::Sqlite).unwrap_err().to_string();
+
+        assert!(err.contains("The enum `Mood` can't be used"), "{}", err);
+        assert!(validate_for_connector(&dm, SqlFamily::Mysql).is_ok());
+    }
+}
@@ -33,6 +33,26 @@ where
         Ok(E::execute(input, &self.engine).await?)
     }
 
+    /// Handle a command while holding the connector's migration lock, so that concurrent
+    /// migration engines wait for each other. The lock is released even when the command fails.
+    pub async fn handle_command_with_lock<'a, E>(&'a self, input: &'a E::Input) -> CoreResult<E::Output>
+    where
+        E: MigrationCommand,
+    {
+        let connector = self.engine.connector();
+
+        connector.acquire_lock().await?;
+
+        let result = self.handle_command::<E>(input).await;
+        let released = connector.release_lock().await;
+
+        // The error of the command, if any, is more relevant than the one of the release.
+        let output = result?;
+        released?;
+
+        Ok(output)
+    }
+
     pub fn connector(&self) -> &C {
         self.engine.connector()
     }
@@ -48,6 +68,10 @@ pub trait GenericApi: Send + Sync + 'static {
         &self,
         input: &CalculateDatabaseStepsInput,
     ) -> CoreResult<MigrationStepsResultOutput>;
+    async fn calculate_database_steps_from_snapshot(
+        &self,
+        input: &CalculateDatabaseStepsFromSnapshotInput,
+    ) -> CoreResult<CalculateDatabaseStepsFromSnapshotOutput>;
     async fn calculate_datamodel(&self, input: &CalculateDatamodelInput) -> CoreResult<CalculateDatamodelOutput>;
     async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput>;
     async fn debug_panic(&self, input: &()) -> CoreResult<()>;
@@ -73,6 +97,7 @@ pub trait GenericApi: Send + Sync + 'static {
     async fn plan_migration(&self, input: &PlanMigrationInput) -> CoreResult<PlanMigrationOutput>;
     async fn reset(&self, input: &()) -> CoreResult<()>;
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput>;
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput>;
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput>;
 }
 
@@ -98,7 +123,7 @@ where
     }
 
     async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput> {
-        self.handle_command::<ApplyMigrationsCommand>(input)
+        self.handle_command_with_lock::<ApplyMigrationsCommand>(input)
             .instrument(tracing::info_span!("ApplyMigrations"))
             .await
     }
@@ -118,6 +143,15 @@ where
             .await
     }
 
+    async fn calculate_database_steps_from_snapshot(
+        &self,
+        input: &CalculateDatabaseStepsFromSnapshotInput,
+    ) -> CoreResult<CalculateDatabaseStepsFromSnapshotOutput> {
+        self.handle_command::<CalculateDatabaseStepsFromSnapshotCommand>(input)
+            .instrument(tracing::info_span!("CalculateDatabaseStepsFromSnapshot"))
+            .await
+    }
+
     async fn calculate_datamodel(&self, input: &CalculateDatamodelInput) -> CoreResult<CalculateDatamodelOutput> {
         self.handle_command::<CalculateDatamodelCommand>(input)
             .instrument(tracing::info_span!("CalculateDatamodel"))
@@ -125,7 +159,7 @@ where
     }
 
     async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput> {
-        self.handle_command::<CreateMigrationCommand>(input)
+        self.handle_command_with_lock::<CreateMigrationCommand>(input)
             .instrument(tracing::info_span!(
                 "CreateMigration",
                 migration_name = input.migration_name.as_str(),
@@ -239,6 +273,15 @@ where
             .await
     }
 
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput> {
+        self.handle_command_with_lock::<SquashMigrationsCommand>(input)
+            .instrument(tracing::info_span!(
+                "SquashMigrations",
+                migration_name = input.migration_name.as_str()
+            ))
+            .await
+    }
+
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput> {
         self.handle_command::<UnapplyMigrationCommand<'_>>(input)
             .instrument(tracing::info_span!("UnapplyMigration"))
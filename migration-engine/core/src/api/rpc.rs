@@ -16,6 +16,7 @@ enum RpcCommand {
     ApplyMigrations,
     ApplyScript,
     CalculateDatabaseSteps,
+    CalculateDatabaseStepsFromSnapshot,
     CalculateDatamodel,
     CreateMigration,
     DebugPanic,
@@ -32,6 +33,7 @@ enum RpcCommand {
     PlanMigration,
     Reset,
     SchemaPush,
+    SquashMigrations,
     UnapplyMigration,
 }
 
@@ -42,6 +44,7 @@ impl RpcCommand {
             RpcCommand::ApplyMigrations => "applyMigrations",
             RpcCommand::ApplyScript => "applyScript",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
+            RpcCommand::CalculateDatabaseStepsFromSnapshot => "calculateDatabaseStepsFromSnapshot",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CreateMigration => "createMigration",
             RpcCommand::DebugPanic => "debugPanic",
@@ -58,6 +61,7 @@ impl RpcCommand {
             RpcCommand::PlanMigration => "planMigration",
             RpcCommand::Reset => "reset",
             RpcCommand::SchemaPush => "schemaPush",
+            RpcCommand::SquashMigrations => "squashMigrations",
             RpcCommand::UnapplyMigration => "unapplyMigration",
         }
     }
@@ -68,6 +72,7 @@ const AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::ApplyMigrations,
     RpcCommand::ApplyScript,
     RpcCommand::CalculateDatabaseSteps,
+    RpcCommand::CalculateDatabaseStepsFromSnapshot,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CreateMigration,
     RpcCommand::DebugPanic,
@@ -84,6 +89,7 @@ const AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::PlanMigration,
     RpcCommand::Reset,
     RpcCommand::SchemaPush,
+    RpcCommand::SquashMigrations,
     RpcCommand::UnapplyMigration,
 ];
 
@@ -141,6 +147,11 @@ impl RpcApi {
             RpcCommand::DebugPanic => render(executor.debug_panic(&()).await?),
             RpcCommand::ApplyMigration => render(executor.apply_migration(&params.parse()?).await?),
             RpcCommand::CalculateDatabaseSteps => render(executor.calculate_database_steps(&params.parse()?).await?),
+            RpcCommand::CalculateDatabaseStepsFromSnapshot => render(
+                executor
+                    .calculate_database_steps_from_snapshot(&params.parse()?)
+                    .await?,
+            ),
             RpcCommand::CalculateDatamodel => render(executor.calculate_datamodel(&params.parse()?).await?),
             RpcCommand::DiagnoseMigrationHistory => {
                 render(executor.diagnose_migration_history(&params.parse()?).await?)
@@ -159,6 +170,7 @@ impl RpcApi {
             RpcCommand::PlanMigration => render(executor.plan_migration(&params.parse()?).await?),
             RpcCommand::Reset => render(executor.reset(&()).await?),
             RpcCommand::SchemaPush => render(executor.schema_push(&params.parse()?).await?),
+            RpcCommand::SquashMigrations => render(executor.squash_migrations(&params.parse()?).await?),
             RpcCommand::UnapplyMigration => render(executor.unapply_migration(&params.parse()?).await?),
         }
     }
@@ -9,6 +9,7 @@ mod apply_migrations;
 mod apply_script;
 #[allow(missing_docs)]
 mod calculate_database_steps;
+mod calculate_database_steps_from_snapshot;
 #[allow(missing_docs)]
 mod calculate_datamodel;
 mod command;
@@ -30,6 +31,7 @@ mod migration_progress;
 mod plan_migration;
 mod reset;
 mod schema_push;
+mod squash_migrations;
 #[allow(missing_docs)]
 mod unapply_migration;
 
@@ -37,6 +39,10 @@ pub use apply_migration::*;
 pub use apply_migrations::{ApplyMigrationsCommand, ApplyMigrationsInput, ApplyMigrationsOutput};
 pub use apply_script::{ApplyScriptCommand, ApplyScriptInput, ApplyScriptOutput};
 pub use calculate_database_steps::*;
+pub use calculate_database_steps_from_snapshot::{
+    CalculateDatabaseStepsFromSnapshotCommand, CalculateDatabaseStepsFromSnapshotInput,
+    CalculateDatabaseStepsFromSnapshotOutput,
+};
 pub use calculate_datamodel::*;
 pub use command::MigrationCommand;
 pub use create_migration::{CreateMigrationCommand, CreateMigrationInput, CreateMigrationOutput};
@@ -59,6 +65,7 @@ pub use migration_progress::*;
 pub use plan_migration::{PlanMigrationCommand, PlanMigrationInput, PlanMigrationOutput};
 pub use reset::ResetCommand;
 pub use schema_push::{SchemaPushCommand, SchemaPushInput, SchemaPushOutput};
+pub use squash_migrations::{SquashMigrationsCommand, SquashMigrationsInput, SquashMigrationsOutput};
 pub use unapply_migration::*;
 
 use migration_connector::{MigrationStep, MigrationWarning, PrettyDatabaseMigrationStep, UnexecutableMigration};
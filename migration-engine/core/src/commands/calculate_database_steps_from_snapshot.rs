@@ -0,0 +1,71 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, parse_datamodel, CoreResult};
+use migration_connector::{
+    DatabaseMigrationMarker, DestructiveChangeDiagnostics, MigrationConnector, MigrationWarning,
+    PrettyDatabaseMigrationStep, UnexecutableMigration,
+};
+use serde::{Deserialize, Serialize};
+
+/// Calculate the database steps taking a database from a snapshot of its
+/// schema to the prisma schema, without looking at the database. This is
+/// meant for generating migrations offline, for example in CI.
+pub struct CalculateDatabaseStepsFromSnapshotCommand;
+
+/// The input to the `calculateDatabaseStepsFromSnapshot` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculateDatabaseStepsFromSnapshotInput {
+    /// The prisma schema the database should be migrated to.
+    pub prisma_schema: String,
+    /// The serialized schema of the database to migrate from. For SQL
+    /// connectors, this is the output of `SqlSchema::to_versioned_json()`.
+    pub schema_snapshot: String,
+}
+
+/// The output of the `calculateDatabaseStepsFromSnapshot` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculateDatabaseStepsFromSnapshotOutput {
+    /// The database steps, with their rendered SQL.
+    pub database_steps: Vec<PrettyDatabaseMigrationStep>,
+    /// The destructive change warnings for the steps.
+    pub warnings: Vec<MigrationWarning>,
+    /// The steps that cannot be executed, for example because they would add a
+    /// required column to a table without a default.
+    pub unexecutable_migrations: Vec<UnexecutableMigration>,
+}
+
+#[async_trait::async_trait]
+impl MigrationCommand for CalculateDatabaseStepsFromSnapshotCommand {
+    type Input = CalculateDatabaseStepsFromSnapshotInput;
+
+    type Output = CalculateDatabaseStepsFromSnapshotOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CoreResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let target_schema = parse_datamodel(&input.prisma_schema)?;
+
+        let database_migration = connector
+            .database_migration_inferrer()
+            .infer_from_snapshot(&input.schema_snapshot, &target_schema)?;
+
+        let DestructiveChangeDiagnostics {
+            warnings,
+            unexecutable_migrations,
+        } = connector.destructive_change_checker().pure_check(&database_migration);
+
+        let database_steps = connector
+            .database_migration_step_applier()
+            .render_steps_pretty(&database_migration)?;
+
+        Ok(CalculateDatabaseStepsFromSnapshotOutput {
+            database_steps,
+            warnings,
+            unexecutable_migrations,
+        })
+    }
+}
@@ -1,5 +1,6 @@
 use super::MigrationCommand;
 use crate::{migration_engine::MigrationEngine, parse_datamodel, CoreError, CoreResult};
+use migration_connector::CheckerMode;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
@@ -18,6 +19,15 @@ pub struct CreateMigrationInput {
     pub migration_name: String,
     /// If true, always generate a migration, but do not apply.
     pub draft: bool,
+    /// Whether the destructive change checks may query the database. Defaults
+    /// to offline, since the generated migration can be applied to other
+    /// databases.
+    #[serde(default = "default_checker_mode")]
+    pub checker_mode: CheckerMode,
+}
+
+fn default_checker_mode() -> CheckerMode {
+    CheckerMode::Offline
 }
 
 /// The output of the `createMigration` command.
@@ -57,9 +67,9 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
             });
         }
 
-        let destructive_change_diagnostics = checker.pure_check(&migration);
+        let destructive_change_diagnostics = checker.check_with_mode(&migration, input.checker_mode).await?;
 
-        let migration_script = applier.render_script(&migration, &destructive_change_diagnostics);
+        let migration_script = applier.render_script(&migration, &destructive_change_diagnostics)?;
 
         // Write the migration script to a file.
         let directory = migration_connector::create_migration_directory(
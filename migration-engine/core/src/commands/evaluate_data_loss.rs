@@ -18,6 +18,10 @@ pub struct EvaluateDataLossInput {
     pub migrations_directory_path: String,
     /// The prisma schema to migrate to.
     pub prisma_schema: String,
+    /// Count the rows affected by each warning in the database. This is off by
+    /// default, because it can require additional queries.
+    #[serde(default)]
+    pub include_row_counts: bool,
 }
 
 /// The output of the `evaluateDataLoss` command.
@@ -43,6 +47,10 @@ pub struct MigrationFeedback {
     pub message: String,
     /// The index of the step this pertains to.
     pub step_index: usize,
+    /// The number of rows affected by the step, when `includeRowCounts` was
+    /// passed to the command.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affected_rows_count: Option<i64>,
 }
 
 #[async_trait::async_trait]
@@ -73,7 +81,11 @@ impl MigrationCommand for EvaluateDataLoss {
             .map(|pretty_step| pretty_step.raw)
             .collect();
 
-        let diagnostics = checker.check(&migration).await?;
+        let diagnostics = if input.include_row_counts {
+            checker.check_with_row_counts(&migration).await?
+        } else {
+            checker.check(&migration).await?
+        };
 
         let warnings = diagnostics
             .warnings
@@ -81,6 +93,7 @@ impl MigrationCommand for EvaluateDataLoss {
             .map(|warning| MigrationFeedback {
                 message: warning.description,
                 step_index: warning.step_index,
+                affected_rows_count: warning.affected_rows_count,
             })
             .collect();
 
@@ -90,6 +103,7 @@ impl MigrationCommand for EvaluateDataLoss {
             .map(|unexecutable| MigrationFeedback {
                 message: unexecutable.description,
                 step_index: unexecutable.step_index,
+                affected_rows_count: None,
             })
             .collect();
 
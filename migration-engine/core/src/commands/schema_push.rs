@@ -1,6 +1,6 @@
 use super::MigrationCommand;
 use crate::{parse_datamodel, CoreResult};
-use migration_connector::{DatabaseMigrationMarker, MigrationConnector};
+use migration_connector::{CheckerMode, DatabaseMigrationMarker, MigrationConnector};
 use serde::{Deserialize, Serialize};
 
 /// Command to bring the local database in sync with the prisma schema, without
@@ -32,22 +32,25 @@ impl MigrationCommand for SchemaPushCommand {
             inferrer.infer(&schema, &schema, &[]).await?
         };
 
-        let checks = checker.check(&database_migration).await?;
+        let checks = checker.check_with_mode(&database_migration, input.checker_mode).await?;
 
         let mut step = 0u32;
 
-        match (checks.unexecutable_migrations.len(), checks.warnings.len(), input.force) {
-            (unexecutable, _, _) if unexecutable > 0 => {
+        let has_unexecutable = !checks.unexecutable_migrations.is_empty();
+        let has_warnings = !checks.warnings.is_empty();
+
+        match (has_unexecutable, has_warnings) {
+            (true, _) if !input.force => {
                 tracing::warn!(unexecutable = ?checks.unexecutable_migrations, "Aborting migration because at least one unexecutable step was detected.")
             }
-            (0, 0, _) | (0, _, true) => {
+            (false, true) if !input.force && !input.accept_data_loss => tracing::info!(
+                "The migration was not applied because it triggered warnings and neither the force nor the accept data loss flag was passed."
+            ),
+            _ => {
                 while applier.apply_step(&database_migration, step as usize).await? {
                     step += 1
                 }
             }
-            _ => tracing::info!(
-                "The migration was not applied because it triggered warnings and the force flag was not passed."
-            ),
         }
 
         Ok(SchemaPushOutput {
@@ -68,11 +71,20 @@ impl MigrationCommand for SchemaPushCommand {
 pub struct SchemaPushInput {
     /// The prisma schema.
     pub schema: String,
-    /// Push the schema ignoring destructive change warnings.
+    /// Push the schema ignoring destructive change warnings and unexecutable
+    /// steps.
     pub force: bool,
+    /// Push the schema even if it triggers destructive change warnings.
+    /// Unexecutable steps still abort the push.
+    #[serde(default)]
+    pub accept_data_loss: bool,
     /// Expect the schema to be empty, skipping describing the existing schema.
     #[serde(default)]
     pub assume_empty: bool,
+    /// Whether the destructive change checks may query the database. Defaults
+    /// to online.
+    #[serde(default)]
+    pub checker_mode: CheckerMode,
 }
 
 /// Output of the `schemaPush` command.
@@ -0,0 +1,147 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, parse_datamodel, CoreError, CoreResult};
+use migration_connector::PersistenceNotInitializedError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Replace the applied migrations in the migrations directory with a single
+/// migration creating the whole schema.
+pub struct SquashMigrationsCommand;
+
+/// The input to the `squashMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsInput {
+    /// The filesystem path of the migrations directory to use.
+    pub migrations_directory_path: String,
+    /// The current prisma schema, matching the state of the database after the
+    /// applied migrations.
+    pub prisma_schema: String,
+    /// The user-given name for the squashed migration. This will be used in the
+    /// migration directory.
+    pub migration_name: String,
+}
+
+/// The output of the `squashMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsOutput {
+    /// The name of the migration directory replacing the squashed migrations.
+    pub generated_migration_name: String,
+    /// The names of the migrations that were squashed.
+    pub squashed_migration_names: Vec<String>,
+}
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for SquashMigrationsCommand {
+    type Input = SquashMigrationsInput;
+
+    type Output = SquashMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CoreResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let persistence = connector.new_migration_persistence();
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+
+        let migrations_from_filesystem = migration_connector::list_migrations(migrations_directory_path)?;
+        let migrations_from_database = persistence
+            .list_migrations()
+            .await?
+            .map_err(PersistenceNotInitializedError::into_connector_error)?;
+
+        // Only a fully applied history can be squashed, otherwise the squashed
+        // migration would not describe the state of the database.
+        for migration in &migrations_from_filesystem {
+            let is_applied = migrations_from_database.iter().any(|record| {
+                record.migration_name == migration.migration_name()
+                    && record.finished_at.is_some()
+                    && record.rolled_back_at.is_none()
+            });
+
+            if !is_applied {
+                return Err(CoreError::Generic(anyhow::anyhow!(
+                    "The migration `{}` is not applied. Apply all migrations before squashing them.",
+                    migration.migration_name()
+                )));
+            }
+        }
+
+        // The squashed migration is rendered from the schema, so the schema has
+        // to describe the state at the end of the migrations history.
+        let target_schema = parse_datamodel(&input.prisma_schema)?;
+        let inferrer = connector.database_migration_inferrer();
+        let unmigrated_changes = inferrer
+            .infer_next_migration(&migrations_from_filesystem, &target_schema)
+            .await?;
+
+        if !unmigrated_changes.is_empty() {
+            return Err(CoreError::Generic(anyhow::anyhow!(
+                "The Prisma schema does not match the state of the database at the end of the migrations history. Create a migration for the changes before squashing."
+            )));
+        }
+
+        // Render the whole schema as a single migration.
+        let migration = inferrer.infer_from_empty(&target_schema)?;
+        let destructive_change_diagnostics = connector.destructive_change_checker().pure_check(&migration);
+        let migration_script = connector
+            .database_migration_step_applier()
+            .render_script(&migration, &destructive_change_diagnostics)?;
+
+        // Write the new migration first, so the squashed migrations can always
+        // be recovered from either the filesystem or the migrations table.
+        let directory =
+            migration_connector::create_migration_directory(migrations_directory_path, &input.migration_name)
+                .map_err(|_| CoreError::Generic(anyhow::anyhow!("Failed to create a new migration directory.")))?;
+
+        if let Err(err) = directory.write_migration_script(&migration_script, D::FILE_EXTENSION) {
+            std::fs::remove_dir_all(directory.path()).ok();
+
+            return Err(CoreError::Generic(anyhow::Error::new(err).context(format!(
+                "Failed to write the migration script to `{:?}`",
+                directory.path(),
+            ))));
+        }
+
+        let squashed_migration_names: Vec<String> = migrations_from_filesystem
+            .iter()
+            .map(|migration| migration.migration_name().to_owned())
+            .collect();
+
+        let squashed_migration_ids: Vec<String> = migrations_from_database
+            .iter()
+            .filter(|record| squashed_migration_names.contains(&record.migration_name))
+            .map(|record| record.id.clone())
+            .collect();
+
+        // Replace the squashed migrations in the migrations table, in a single
+        // transaction.
+        if let Err(err) = persistence
+            .mark_migrations_squashed(&squashed_migration_ids, directory.migration_name(), &migration_script)
+            .await
+        {
+            std::fs::remove_dir_all(directory.path()).ok();
+
+            return Err(err.into());
+        }
+
+        // Only then remove the squashed migration directories.
+        for migration in &migrations_from_filesystem {
+            std::fs::remove_dir_all(migration.path()).map_err(|err| {
+                CoreError::Generic(anyhow::Error::new(err).context(format!(
+                    "The migrations were squashed into `{}`, but removing the squashed migration directory at `{:?}` failed. Remove it manually.",
+                    directory.migration_name(),
+                    migration.path(),
+                )))
+            })?;
+        }
+
+        Ok(SquashMigrationsOutput {
+            generated_migration_name: directory.migration_name().to_owned(),
+            squashed_migration_names,
+        })
+    }
+}
@@ -19,7 +19,10 @@ pub use commands::{ApplyMigrationInput, InferMigrationStepsInput, MigrationSteps
 pub use core_error::{CoreError, CoreResult};
 pub use gate_keeper::GateKeeper;
 
-use commands::{MigrationCommand, SchemaPushCommand};
+use commands::{
+    CalculateDatabaseStepsFromSnapshotInput, CalculateDatabaseStepsFromSnapshotOutput, MigrationCommand,
+    SchemaPushCommand,
+};
 use datamodel::{
     common::provider_names::{MSSQL_SOURCE_NAME, MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     dml::Datamodel,
@@ -163,12 +166,53 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
         schema: prisma_schema.to_string(),
         assume_empty: true,
         force: true,
+        accept_data_loss: false,
+        checker_mode: Default::default(),
     };
     SchemaPushCommand::execute(&schema_push_input, &engine).await?;
 
     Ok(())
 }
 
+/// Calculate the database steps from a snapshot of the database schema to the Prisma schema,
+/// without connecting to the database referenced by the Prisma schema.
+pub fn calculate_database_steps_from_snapshot(
+    input: &CalculateDatabaseStepsFromSnapshotInput,
+) -> CoreResult<CalculateDatabaseStepsFromSnapshotOutput> {
+    let config = parse_configuration(&input.prisma_schema)?;
+    let target_schema = parse_datamodel(&input.prisma_schema)?;
+
+    let source = config
+        .datasources
+        .first()
+        .ok_or_else(|| CoreError::Generic(anyhow!("There is no datasource in the schema.")))?;
+
+    match &source.active_provider {
+        provider
+            if [
+                MYSQL_SOURCE_NAME,
+                POSTGRES_SOURCE_NAME,
+                SQLITE_SOURCE_NAME,
+                MSSQL_SOURCE_NAME,
+            ]
+            .contains(&provider.as_str()) =>
+        {
+            let (database_steps, diagnostics) = SqlMigrationConnector::calculate_database_steps_from_snapshot(
+                &source.url().value,
+                &input.schema_snapshot,
+                &target_schema,
+            )?;
+
+            Ok(CalculateDatabaseStepsFromSnapshotOutput {
+                database_steps,
+                warnings: diagnostics.warnings,
+                unexecutable_migrations: diagnostics.unexecutable_migrations,
+            })
+        }
+        x => unimplemented!("Connector {} is not supported yet", x),
+    }
+}
+
 fn parse_configuration(datamodel: &str) -> CoreResult<Configuration> {
     datamodel::parse_configuration(&datamodel)
         .map(|validated_config| validated_config.subject)
@@ -339,6 +339,20 @@ impl<'a> ColumnAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_default_constraint_name(self, name: &str) -> AssertionResult<Self> {
+        let found = self.0.default_constraint_name.as_deref();
+
+        anyhow::ensure!(
+            found == Some(name),
+            "Assertion failed: expected the default constraint of the `{}` column to be named `{}`, found {:?}",
+            self.0.name,
+            name,
+            found
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_has_no_default(self) -> AssertionResult<Self> {
         self.assert_default(None)
     }
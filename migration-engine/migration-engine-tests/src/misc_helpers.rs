@@ -20,6 +20,12 @@ pub(super) async fn postgres_migration_connector(url_str: &str) -> SqlMigrationC
     SqlMigrationConnector::new(url_str).await.unwrap()
 }
 
+pub(super) async fn mssql_migration_connector(url_str: &str, schema_name: &str) -> SqlMigrationConnector {
+    let conn = create_mssql_database(url_str).await.unwrap();
+    connectors::mssql::reset_schema(&conn, schema_name).await.unwrap();
+    SqlMigrationConnector::new(url_str).await.unwrap()
+}
+
 pub(super) async fn sqlite_migration_connector(db_name: &str) -> SqlMigrationConnector {
     let database_url = sqlite_test_url(db_name);
     SqlMigrationConnector::new(&database_url).await.unwrap()
@@ -1,6 +1,7 @@
 mod apply;
 mod apply_migrations;
 mod calculate_database_steps;
+mod calculate_database_steps_from_snapshot;
 mod create_migration;
 mod diagnose_migration_history;
 mod evaluate_data_loss;
@@ -11,11 +12,13 @@ mod mark_migration_applied;
 mod mark_migration_rolled_back;
 mod reset;
 mod schema_push;
+mod squash_migrations;
 mod unapply_migration;
 
 pub use apply::Apply;
 pub use apply_migrations::ApplyMigrations;
 pub use calculate_database_steps::CalculateDatabaseSteps;
+pub use calculate_database_steps_from_snapshot::CalculateDatabaseStepsFromSnapshot;
 pub use create_migration::CreateMigration;
 pub use diagnose_migration_history::DiagnoseMigrationHistory;
 pub use evaluate_data_loss::EvaluateDataLoss;
@@ -24,6 +27,7 @@ pub use infer_apply::InferApply;
 pub use mark_migration_applied::MarkMigrationApplied;
 pub use reset::Reset;
 pub use schema_push::SchemaPush;
+pub use squash_migrations::SquashMigrations;
 pub use unapply_migration::UnapplyMigration;
 
 use crate::AssertionResult;
@@ -32,7 +36,10 @@ use self::mark_migration_rolled_back::MarkMigrationRolledBack;
 
 use super::assertions::SchemaAssertion;
 use super::{
-    misc_helpers::{mysql_migration_connector, postgres_migration_connector, sqlite_migration_connector, test_api},
+    misc_helpers::{
+        mssql_migration_connector, mysql_migration_connector, postgres_migration_connector, sqlite_migration_connector,
+        test_api,
+    },
     sql::barrel_migration_executor::BarrelMigrationExecutor,
     InferAndApplyOutput,
 };
@@ -44,14 +51,16 @@ use migration_connector::{
 };
 use migration_core::{
     api::{GenericApi, MigrationApi},
-    commands::{ApplyMigrationInput, ApplyScriptInput},
+    commands::{ApplyMigrationInput, ApplyScriptInput, DiagnoseMigrationHistoryOutput, DriftDiagnostic},
 };
+use prisma_value::PrismaValue;
 use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
     single::Quaint,
 };
 use sql_migration_connector::{SqlMigration, SqlMigrationConnector, MIGRATION_TABLE_NAME};
 use sql_schema_describer::*;
+use std::collections::BTreeMap;
 use tempfile::TempDir;
 use test_setup::*;
 
@@ -88,6 +97,10 @@ impl TestApi {
         self.tags.contains(Tags::Mariadb)
     }
 
+    pub fn is_mssql(&self) -> bool {
+        self.tags.intersects(Tags::Mssql2017 | Tags::Mssql2019)
+    }
+
     pub async fn migration_persistence(&self) -> &dyn MigrationPersistence {
         let persistence = self.api.connector().migration_persistence();
 
@@ -256,6 +269,16 @@ impl TestApi {
         SchemaPush::new(&self.api, dm.into())
     }
 
+    /// Builder and assertions to call the SquashMigrations command.
+    pub fn squash_migrations<'a>(
+        &'a self,
+        name: &'a str,
+        prisma_schema: &'a str,
+        migrations_directory: &'a TempDir,
+    ) -> SquashMigrations<'a> {
+        SquashMigrations::new(&self.api, name, prisma_schema, migrations_directory)
+    }
+
     pub fn barrel(&self) -> BarrelMigrationExecutor<'_> {
         BarrelMigrationExecutor {
             api: self,
@@ -288,6 +311,14 @@ impl TestApi {
         Ok(result)
     }
 
+    pub async fn truncate_all(&self) -> anyhow::Result<()> {
+        Ok(self.api.connector().truncate_all().await?)
+    }
+
+    pub async fn installed_extensions(&self) -> anyhow::Result<Vec<String>> {
+        Ok(self.api.connector().installed_extensions().await?)
+    }
+
     pub async fn assert_schema(&self) -> Result<SchemaAssertion, anyhow::Error> {
         let schema = self.describe_database().await?;
         Ok(SchemaAssertion(schema))
@@ -300,6 +331,35 @@ impl TestApi {
         self.database.query(select_star.into()).await
     }
 
+    /// Like `dump_table()`, but the values are converted to `PrismaValue`s according to the type
+    /// family of their column in the described schema. Each row maps column names to values.
+    pub async fn dump_table_typed(&self, table_name: &str) -> anyhow::Result<Vec<BTreeMap<String, PrismaValue>>> {
+        let schema = self.describe_database().await?;
+        let table = schema
+            .table(table_name)
+            .map_err(|_| anyhow::anyhow!("Could not find the `{}` table.", table_name))?;
+        let rows = self.dump_table(table_name).await?;
+
+        rows.into_iter()
+            .map(|row| {
+                table
+                    .columns
+                    .iter()
+                    .map(|column| {
+                        let value = row.get(column.name.as_str()).ok_or_else(|| {
+                            anyhow::anyhow!("Column `{}` missing in the dump of `{}`.", column.name, table_name)
+                        })?;
+
+                        Ok((
+                            column.name.clone(),
+                            quaint_value_to_prisma_value(value, &column.tpe.family),
+                        ))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
     pub fn insert<'a>(&'a self, table_name: &'a str) -> SingleRowInsert<'a> {
         SingleRowInsert {
             insert: quaint::ast::Insert::single_into(self.render_table_name(table_name)),
@@ -317,6 +377,64 @@ impl TestApi {
     pub fn calculate_database_steps(&self) -> CalculateDatabaseSteps<'_> {
         CalculateDatabaseSteps::new(&self.api)
     }
+
+    /// Builder and assertions to call the CalculateDatabaseStepsFromSnapshot command.
+    pub fn calculate_database_steps_from_snapshot<'a>(
+        &'a self,
+        prisma_schema: &'a str,
+        schema_snapshot: &'a str,
+    ) -> CalculateDatabaseStepsFromSnapshot<'a> {
+        CalculateDatabaseStepsFromSnapshot::new(&self.api, prisma_schema, schema_snapshot)
+    }
+
+    /// A test API on the same database, through a connector created from `url`. Useful to test
+    /// the settings read from the connection string.
+    pub async fn with_connection_string(&self, url: &str) -> anyhow::Result<TestApi> {
+        let connector = SqlMigrationConnector::new(url).await?;
+
+        Ok(TestApi {
+            database: connector.quaint().clone(),
+            api: test_api(connector).await,
+            tags: self.tags,
+        })
+    }
+}
+
+/// Apply `migration_sql` as a migration, run `drop_sql` behind the migration history's back, and
+/// assert that the drift rollback script contains all of `expected`.
+///
+/// Returns the schema as described before `drop_sql` ran, and the rollback script.
+pub async fn assert_drift_rollback_contains(
+    api: &TestApi,
+    migration_sql: &str,
+    drop_sql: &str,
+    expected: &[&str],
+) -> anyhow::Result<(SqlSchema, String)> {
+    let directory = api.create_migrations_directory()?;
+    let migration_directory = directory.path().join("20201014000000_drift");
+
+    std::fs::create_dir_all(&migration_directory)?;
+    std::fs::write(migration_directory.join("migration.sql"), migration_sql)?;
+
+    api.apply_migrations(&directory).send().await?;
+
+    let schema = api.describe_database().await?;
+
+    api.database().raw_cmd(drop_sql).await?;
+
+    let DiagnoseMigrationHistoryOutput { drift, .. } =
+        api.diagnose_migration_history(&directory).send().await?.into_output();
+
+    let rollback = match drift {
+        Some(DriftDiagnostic::DriftDetected { rollback }) => rollback,
+        other => panic!("Expected drift, got {:?}", other),
+    };
+
+    for snippet in expected {
+        assert!(rollback.contains(snippet), "Expected `{}` in:\n{}", snippet, rollback);
+    }
+
+    Ok((schema, rollback))
 }
 
 pub struct SingleRowInsert<'a> {
@@ -365,6 +483,30 @@ impl<'a> TestApiSelect<'a> {
     }
 }
 
+pub async fn mssql_2017_test_api(args: TestAPIArgs) -> TestApi {
+    let schema_name = args.test_function_name;
+    let url = format!("{};schema={}", mssql_2017_url("master"), schema_name);
+    let connector = mssql_migration_connector(&url, schema_name).await;
+
+    TestApi {
+        database: connector.quaint().clone(),
+        api: test_api(connector).await,
+        tags: args.test_tag,
+    }
+}
+
+pub async fn mssql_2019_test_api(args: TestAPIArgs) -> TestApi {
+    let schema_name = args.test_function_name;
+    let url = format!("{};schema={}", mssql_2019_url("master"), schema_name);
+    let connector = mssql_migration_connector(&url, schema_name).await;
+
+    TestApi {
+        database: connector.quaint().clone(),
+        api: test_api(connector).await,
+        tags: args.test_tag,
+    }
+}
+
 pub async fn mysql_8_test_api(args: TestAPIArgs) -> TestApi {
     let db_name = args.test_function_name;
     let url = mysql_8_url(db_name);
@@ -528,3 +670,34 @@ impl MigrationsAssertions for MigrationRecord {
         Ok(self)
     }
 }
+
+fn quaint_value_to_prisma_value(value: &quaint::Value<'_>, family: &ColumnTypeFamily) -> PrismaValue {
+    use chrono::TimeZone;
+
+    if value.is_null() {
+        return PrismaValue::Null;
+    }
+
+    let converted = match family {
+        ColumnTypeFamily::Int => value.as_i64().map(PrismaValue::Int),
+        ColumnTypeFamily::BigInt => value.as_i64().map(PrismaValue::BigInt),
+        ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => value.as_numeric().map(PrismaValue::Float),
+        ColumnTypeFamily::Boolean => value.as_bool().map(PrismaValue::Boolean),
+        // SQLite stores datetimes as milliseconds since the epoch.
+        ColumnTypeFamily::DateTime => value
+            .as_datetime()
+            .or_else(|| value.as_i64().map(|millis| chrono::Utc.timestamp_millis(millis)))
+            .map(|datetime| PrismaValue::DateTime(datetime.into())),
+        ColumnTypeFamily::Enum(_) => value.to_string().map(PrismaValue::Enum),
+        ColumnTypeFamily::Json => value.to_string().map(PrismaValue::Json),
+        ColumnTypeFamily::Binary => match value {
+            quaint::Value::Bytes(Some(bytes)) => Some(PrismaValue::Bytes(bytes.to_vec())),
+            _ => None,
+        },
+        ColumnTypeFamily::String | ColumnTypeFamily::Uuid | ColumnTypeFamily::Unsupported(_) => {
+            value.to_string().map(PrismaValue::String)
+        }
+    };
+
+    converted.unwrap_or_else(|| panic!("Could not convert {:?} to a {:?} value.", value, family))
+}
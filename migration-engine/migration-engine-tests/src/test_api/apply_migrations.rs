@@ -63,4 +63,8 @@ impl<'a> ApplyMigrationsAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn into_output(self) -> ApplyMigrationsOutput {
+        self.output
+    }
 }
@@ -0,0 +1,46 @@
+use migration_core::{
+    commands::{CalculateDatabaseStepsFromSnapshotInput, CalculateDatabaseStepsFromSnapshotOutput},
+    CoreResult, GenericApi,
+};
+
+#[must_use = "This struct does nothing on its own. See CalculateDatabaseStepsFromSnapshot::send()"]
+pub struct CalculateDatabaseStepsFromSnapshot<'a> {
+    api: &'a dyn GenericApi,
+    schema: &'a str,
+    snapshot: &'a str,
+}
+
+impl<'a> CalculateDatabaseStepsFromSnapshot<'a> {
+    pub fn new(api: &'a dyn GenericApi, schema: &'a str, snapshot: &'a str) -> Self {
+        CalculateDatabaseStepsFromSnapshot { api, schema, snapshot }
+    }
+
+    pub async fn send(self) -> CoreResult<CalculateDatabaseStepsFromSnapshotAssertion<'a>> {
+        let output = self
+            .api
+            .calculate_database_steps_from_snapshot(&CalculateDatabaseStepsFromSnapshotInput {
+                prisma_schema: self.schema.to_owned(),
+                schema_snapshot: self.snapshot.to_owned(),
+            })
+            .await?;
+
+        Ok(CalculateDatabaseStepsFromSnapshotAssertion { output, _api: self.api })
+    }
+}
+
+pub struct CalculateDatabaseStepsFromSnapshotAssertion<'a> {
+    output: CalculateDatabaseStepsFromSnapshotOutput,
+    _api: &'a dyn GenericApi,
+}
+
+impl std::fmt::Debug for CalculateDatabaseStepsFromSnapshotAssertion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CalculateDatabaseStepsFromSnapshotAssertion {{ .. }}")
+    }
+}
+
+impl<'a> CalculateDatabaseStepsFromSnapshotAssertion<'a> {
+    pub fn into_output(self) -> CalculateDatabaseStepsFromSnapshotOutput {
+        self.output
+    }
+}
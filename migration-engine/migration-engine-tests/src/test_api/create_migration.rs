@@ -1,4 +1,5 @@
 use anyhow::Context;
+use migration_connector::CheckerMode;
 use migration_core::{commands::CreateMigrationInput, commands::CreateMigrationOutput, GenericApi};
 use pretty_assertions::assert_eq;
 use std::path::Path;
@@ -39,6 +40,7 @@ impl<'a> CreateMigration<'a> {
                 prisma_schema: self.schema.to_owned(),
                 draft: self.draft,
                 migration_name: self.name.to_owned(),
+                checker_mode: CheckerMode::Offline,
             })
             .await?;
 
@@ -11,6 +11,7 @@ pub struct EvaluateDataLoss<'a> {
     api: &'a dyn GenericApi,
     migrations_directory: &'a TempDir,
     prisma_schema: String,
+    include_row_counts: bool,
 }
 
 impl<'a> EvaluateDataLoss<'a> {
@@ -19,15 +20,23 @@ impl<'a> EvaluateDataLoss<'a> {
             api,
             migrations_directory,
             prisma_schema,
+            include_row_counts: false,
         }
     }
 
+    pub fn include_row_counts(mut self, include_row_counts: bool) -> Self {
+        self.include_row_counts = include_row_counts;
+
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<EvaluateDataLossAssertion<'a>> {
         let output = self
             .api
             .evaluate_data_loss(&EvaluateDataLossInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
                 prisma_schema: self.prisma_schema,
+                include_row_counts: self.include_row_counts,
             })
             .await?;
 
@@ -106,6 +115,19 @@ impl<'a> EvaluateDataLossAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_warnings_row_counts(self, row_counts: &[Option<i64>]) -> AssertionResult<Self> {
+        let actual_row_counts: Vec<Option<i64>> = self
+            .output
+            .warnings
+            .iter()
+            .map(|warning| warning.affected_rows_count)
+            .collect();
+
+        assert_eq!(actual_row_counts, row_counts);
+
+        Ok(self)
+    }
+
     pub fn assert_unexecutable(self, unexecutable_steps: &[Cow<'_, str>]) -> AssertionResult<Self> {
         anyhow::ensure!(
             self.output.unexecutable_steps.len() == unexecutable_steps.len(),
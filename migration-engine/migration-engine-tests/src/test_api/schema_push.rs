@@ -1,4 +1,5 @@
 use super::super::assertions::AssertionResult;
+use migration_connector::CheckerMode;
 use migration_core::{
     api::GenericApi,
     commands::{SchemaPushInput, SchemaPushOutput},
@@ -9,6 +10,8 @@ pub struct SchemaPush<'a> {
     api: &'a dyn GenericApi,
     schema: String,
     force: bool,
+    accept_data_loss: bool,
+    checker_mode: CheckerMode,
 }
 
 impl<'a> SchemaPush<'a> {
@@ -17,6 +20,8 @@ impl<'a> SchemaPush<'a> {
             api,
             schema,
             force: false,
+            accept_data_loss: false,
+            checker_mode: CheckerMode::Online,
         }
     }
 
@@ -25,11 +30,23 @@ impl<'a> SchemaPush<'a> {
         self
     }
 
+    pub fn accept_data_loss(mut self, accept_data_loss: bool) -> Self {
+        self.accept_data_loss = accept_data_loss;
+        self
+    }
+
+    pub fn checker_mode(mut self, checker_mode: CheckerMode) -> Self {
+        self.checker_mode = checker_mode;
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<SchemaPushAssertion<'a>> {
         let input = SchemaPushInput {
             schema: self.schema,
             force: self.force,
+            accept_data_loss: self.accept_data_loss,
             assume_empty: false,
+            checker_mode: self.checker_mode,
         };
 
         let output = self.api.schema_push(&input).await?;
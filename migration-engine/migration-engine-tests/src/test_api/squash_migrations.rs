@@ -0,0 +1,56 @@
+use migration_core::{commands::SquashMigrationsInput, commands::SquashMigrationsOutput, CoreResult, GenericApi};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See SquashMigrations::send()"]
+pub struct SquashMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    schema: &'a str,
+    name: &'a str,
+}
+
+impl<'a> SquashMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, name: &'a str, schema: &'a str, migrations_directory: &'a TempDir) -> Self {
+        SquashMigrations {
+            api,
+            migrations_directory,
+            schema,
+            name,
+        }
+    }
+
+    pub async fn send(self) -> CoreResult<SquashMigrationsAssertion<'a>> {
+        let output = self
+            .api
+            .squash_migrations(&SquashMigrationsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                prisma_schema: self.schema.to_owned(),
+                migration_name: self.name.to_owned(),
+            })
+            .await?;
+
+        Ok(SquashMigrationsAssertion {
+            output,
+            _api: self.api,
+            _migrations_directory: self.migrations_directory,
+        })
+    }
+}
+
+pub struct SquashMigrationsAssertion<'a> {
+    output: SquashMigrationsOutput,
+    _api: &'a dyn GenericApi,
+    _migrations_directory: &'a TempDir,
+}
+
+impl std::fmt::Debug for SquashMigrationsAssertion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SquashMigrationsAssertion {{ .. }}")
+    }
+}
+
+impl<'a> SquashMigrationsAssertion<'a> {
+    pub fn into_output(self) -> SquashMigrationsOutput {
+        self.output
+    }
+}
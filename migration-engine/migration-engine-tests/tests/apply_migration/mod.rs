@@ -174,3 +174,53 @@ async fn applying_an_already_applied_migration_must_return_an_error(api: &TestAp
 
     Ok(())
 }
+
+/// Collects the formatted logs of a test in memory.
+#[derive(Clone, Default)]
+struct CapturedLogs(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test_each_connector]
+async fn applied_steps_are_logged_with_structured_fields(api: &TestApi) -> TestResult {
+    use tracing_futures::WithSubscriber;
+
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_max_level(tracing::Level::DEBUG)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    let steps = vec![
+        create_model_step("Test"),
+        create_field_step("Test", "id", "Int"),
+        create_id_directive_step("Test", "id"),
+    ];
+
+    api.apply_migration(steps, "logged-migration")
+        .with_subscriber(subscriber)
+        .await;
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone())?;
+
+    assert!(logs.contains(r#"migration_name="logged-migration""#), "{}", logs);
+    assert!(
+        logs.contains(r#"apply_step{step_index=0 step_kind="CreateTable"}"#),
+        "{}",
+        logs
+    );
+    assert!(logs.contains("Migration step applied."), "{}", logs);
+
+    Ok(())
+}
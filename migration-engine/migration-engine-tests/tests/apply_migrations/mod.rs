@@ -1,5 +1,8 @@
 use crate::*;
+use migration_connector::{ImperativeMigrationsPersistence, MigrationConnector};
 use pretty_assertions::assert_eq;
+use sql_migration_connector::SqlMigrationConnector;
+use std::time::Duration;
 use user_facing_errors::UserFacingError;
 
 #[test_each_connector]
@@ -219,3 +222,163 @@ async fn migrations_should_fail_on_an_uninitialized_nonempty_database(api: &Test
 
     Ok(())
 }
+
+#[tokio::test]
+async fn apply_migrations_with_a_custom_migrations_table_name_works() -> TestResult {
+    let url = format!(
+        "{}&migrations_table=_custom_migrations",
+        sqlite_test_url("apply_migrations_with_a_custom_migrations_table_name_works")
+    );
+    let connector = SqlMigrationConnector::new(&url).await?;
+
+    connector.reset().await?;
+
+    let api = test_api(connector).await;
+
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let directory = tempfile::tempdir()?;
+
+    CreateMigration::new(&api, "01-init", dm, &directory).send().await?;
+
+    ApplyMigrations::new(&api, &directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["01-init"])?;
+
+    ApplyMigrations::new(&api, &directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let schema = api.connector().describe_schema().await?;
+
+    assert!(schema.table("_custom_migrations").is_ok());
+    assert!(schema.table("_prisma_migrations").is_err());
+    assert!(schema.table("Cat").is_ok());
+
+    let migrations = api.connector().list_migrations().await?.unwrap();
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].migration_name, "01-init");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_apply_migrations_wait_for_each_other() -> TestResult {
+    let url = sqlite_test_url("concurrent_apply_migrations_wait_for_each_other");
+    let first_connector = SqlMigrationConnector::new(&url).await?;
+
+    first_connector.reset().await?;
+
+    let first_api = test_api(first_connector).await;
+    let second_api = test_api(SqlMigrationConnector::new(&url).await?).await;
+
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let directory = tempfile::tempdir()?;
+
+    CreateMigration::new(&first_api, "01-init", dm, &directory)
+        .send()
+        .await?;
+
+    let (first_result, second_result) = tokio::join!(
+        ApplyMigrations::new(&first_api, &directory).send(),
+        ApplyMigrations::new(&second_api, &directory).send(),
+    );
+
+    let first_output = first_result?.into_output();
+    let second_output = second_result?.into_output();
+
+    // Exactly one of the two runs applies the migration, the other one finds it already applied.
+    let mut applied_migrations_counts = vec![
+        first_output.applied_migration_names.len(),
+        second_output.applied_migration_names.len(),
+    ];
+
+    applied_migrations_counts.sort();
+
+    assert_eq!(applied_migrations_counts, &[0, 1]);
+
+    let migrations = first_api.connector().list_migrations().await?.unwrap();
+
+    assert_eq!(migrations.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sqlite_migration_locks_make_the_second_engine_wait() -> TestResult {
+    let url = sqlite_test_url("sqlite_migration_locks_make_the_second_engine_wait");
+    let first_connector = SqlMigrationConnector::new(&url).await?;
+
+    first_connector.reset().await?;
+
+    let second_connector = SqlMigrationConnector::new(&url).await?;
+
+    first_connector.acquire_lock().await?;
+
+    let mut second_lock = second_connector.acquire_lock();
+
+    // The second engine waits while the first one holds the lock...
+    assert!(tokio::time::timeout(Duration::from_millis(500), &mut second_lock)
+        .await
+        .is_err());
+
+    first_connector.release_lock().await?;
+
+    // ...and gets it once it is released.
+    tokio::time::timeout(Duration::from_secs(10), &mut second_lock).await??;
+
+    // An engine that goes away without releasing the lock does not keep it.
+    drop(second_lock);
+    drop(second_connector);
+
+    tokio::time::timeout(Duration::from_secs(10), first_connector.acquire_lock()).await??;
+    first_connector.release_lock().await?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn postgres_migration_locks_do_not_block_other_schemas(api: &TestApi) -> TestResult {
+    let other_schema_url = postgres_12_url(api.connection_info().dbname().unwrap()).replace(
+        &format!("schema={}", api.schema_name()),
+        "schema=postgres_migration_locks_other_schema",
+    );
+    let other_connector = SqlMigrationConnector::new(&other_schema_url).await?;
+
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    // A migration of another schema of the same database is running.
+    other_connector.acquire_lock().await?;
+
+    api.create_migration("init", dm, &migrations_directory).send().await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["init"])?;
+
+    other_connector.release_lock().await?;
+
+    Ok(())
+}
@@ -32,6 +32,19 @@ async fn basic_create_migration_works(api: &TestApi) -> TestResult {
                         "#
                     }
                 }
+                SqlFamily::Mysql if api.is_mysql_8() => {
+                    indoc! {
+                        r#"
+                        -- CreateTable
+                        CREATE TABLE `Cat` (
+                            `id` INT NOT NULL,
+                            `name` VARCHAR(191) NOT NULL,
+
+                            PRIMARY KEY (`id`)
+                        ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_0900_ai_ci;
+                        "#
+                    }
+                }
                 SqlFamily::Mysql => {
                     indoc! {
                         r#"
@@ -116,6 +129,19 @@ async fn creating_a_second_migration_should_have_the_previous_sql_schema_as_base
                         "#
                     }
                 }
+                SqlFamily::Mysql if api.is_mysql_8() => {
+                    indoc! {
+                        r#"
+                        -- CreateTable
+                        CREATE TABLE `Dog` (
+                            `id` INT NOT NULL,
+                            `name` VARCHAR(191) NOT NULL,
+
+                            PRIMARY KEY (`id`)
+                        ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_0900_ai_ci;
+                        "#
+                    }
+                }
                 SqlFamily::Mysql => {
                     indoc! {
                         r#"
@@ -296,6 +322,19 @@ async fn create_enum_step_only_rendered_when_needed(api: &TestApi) -> TestResult
                         "#
                     }
                 }
+                SqlFamily::Mysql if api.is_mysql_8() => {
+                    indoc! {
+                        r#"
+                        -- CreateTable
+                        CREATE TABLE `Cat` (
+                            `id` INT NOT NULL,
+                            `mood` ENUM('HUNGRY', 'SLEEPY') NOT NULL,
+
+                            PRIMARY KEY (`id`)
+                        ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_0900_ai_ci;
+                        "#
+                    }
+                }
                 SqlFamily::Mysql => {
                     indoc! {
                         r#"
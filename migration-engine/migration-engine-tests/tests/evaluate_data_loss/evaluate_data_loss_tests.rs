@@ -289,3 +289,52 @@ async fn evaluate_data_loss_maps_warnings_to_the_right_steps(api: &TestApi) -> T
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn evaluate_data_loss_can_count_the_rows_affected_by_warnings(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let directory = api.create_migrations_directory()?;
+    api.create_migration("1-initial", dm1, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    for (id, name) in &[(1, "Felix"), (2, "Garfield"), (3, "Tom")] {
+        api.insert("Cat")
+            .value("id", *id)
+            .value("name", *name)
+            .result_raw()
+            .await?;
+    }
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.evaluate_data_loss(&directory, dm2)
+        .send()
+        .await?
+        .assert_warnings(&[
+            "You are about to drop the column `name` on the `Cat` table, which still contains 3 non-null values."
+                .into(),
+        ])?
+        .assert_warnings_row_counts(&[None])?;
+
+    api.evaluate_data_loss(&directory, dm2)
+        .include_row_counts(true)
+        .send()
+        .await?
+        .assert_warnings(&[
+            "You are about to drop the column `name` on the `Cat` table, which still contains 3 non-null values."
+                .into(),
+        ])?
+        .assert_warnings_row_counts(&[Some(3)])?;
+
+    Ok(())
+}
@@ -51,3 +51,57 @@ async fn changing_a_column_from_optional_to_required_with_a_default_is_safe(api:
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn typed_values_survive_a_table_redefinition(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+            age Int?
+            weight Float
+            isHungry Boolean
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let insert = Insert::multi_into(
+        api.render_table_name("Cat"),
+        &["id", "name", "age", "weight", "isHungry"],
+    )
+    .values(("felix", "Felix", 3, 4.5, true))
+    .values(("garfield", "Garfield", Value::Integer(None), 12.0, false));
+
+    api.database().query(insert.into()).await?;
+
+    // Making the column required redefines the table on SQLite.
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            name String
+            age Int @default(1)
+            weight Float
+            isHungry Boolean
+        }
+    "#;
+
+    api.schema_push(dm2).force(true).send().await?.assert_green()?;
+
+    let rows = api.dump_table_typed("Cat").await?;
+
+    assert_eq!(rows.len(), 2);
+
+    assert_eq!(rows[0]["id"], PrismaValue::String("felix".to_owned()));
+    assert_eq!(rows[0]["name"], PrismaValue::String("Felix".to_owned()));
+    assert_eq!(rows[0]["age"], PrismaValue::Int(3));
+    assert_eq!(rows[0]["weight"], PrismaValue::new_float(4.5));
+    assert_eq!(rows[0]["isHungry"], PrismaValue::Boolean(true));
+
+    assert_eq!(rows[1]["id"], PrismaValue::String("garfield".to_owned()));
+    assert_eq!(rows[1]["age"], PrismaValue::Int(1));
+    assert_eq!(rows[1]["weight"], PrismaValue::new_float(12.0));
+    assert_eq!(rows[1]["isHungry"], PrismaValue::Boolean(false));
+
+    Ok(())
+}
@@ -331,3 +331,79 @@ async fn watch_migrations_must_be_returned_in_addition_to_regular_inferred_steps
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn transitioning_out_of_watch_mode_reads_the_names_of_dropped_foreign_keys_from_the_database(
+    api: &TestApi,
+) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id     Int     @id
+            humans Human[]
+        }
+
+        model Human {
+            id    Int @id
+            catId Int
+            cat   Cat @relation(fields: [catId], references: [id])
+        }
+    "#;
+
+    api.infer_apply(dm)
+        .migration_id(Some("mig00"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let dm = r#"
+        model Cat {
+            id     Int     @id
+            humans Human[]
+        }
+
+        model Human {
+            id    Int @id
+            catId Int
+            cat   Cat @relation(fields: [catId], references: [id])
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm)
+        .migration_id(Some("watch01"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    // The steps since `mig00` are inferred from the datamodels, where the
+    // foreign key has no name.
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Human {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let output = api.infer(dm).migration_id(Some("mig02")).send().await?;
+
+    assert!(
+        output
+            .database_steps
+            .iter()
+            .any(|step| step.raw == r#"ALTER TABLE "Human" DROP CONSTRAINT "Human_catId_fkey""#),
+        "{:#?}",
+        output.database_steps
+    );
+
+    Ok(())
+}
@@ -202,3 +202,40 @@ async fn multiple_successive_migrations_work(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn finding_a_migration_by_checksum_works(api: &TestApi) -> TestResult {
+    let persistence = api.imperative_migration_persistence();
+
+    assert_eq!(
+        persistence
+            .find_migration_by_checksum("e0c9674d3b332d71b8bc304aae5b7b8a8bb8ec72e772429fb20d8cc69a864")
+            .await?,
+        None
+    );
+
+    persistence.initialize(false).await?;
+
+    let script_1 = "CREATE ENUM MyBoolean ( \"TRUE\", \"FALSE\" )";
+    let id_1 = persistence
+        .record_migration_started("initial_migration", script_1)
+        .await?;
+
+    let script_2 = "DROP ENUM MyBoolean";
+    persistence
+        .record_migration_started("second_migration", script_2)
+        .await?;
+
+    let found = persistence
+        .find_migration_by_checksum("e0c9674d3b332d71b8bc304aae5b7b8a8bb8ec72e772429fb20d8cc69a864")
+        .await?
+        .unwrap();
+
+    assert_eq!(found.id, id_1);
+    assert_eq!(found.migration_name, "initial_migration");
+    assert_eq!(found.script, script_1);
+
+    assert_eq!(persistence.find_migration_by_checksum("not-a-checksum").await?, None);
+
+    Ok(())
+}
@@ -636,6 +636,9 @@ async fn changing_a_relation_field_to_a_scalar_field_must_work(api: &TestApi) ->
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
                 on_update_action: ForeignKeyAction::NoAction,
+                deferrable: false,
+                initially_deferred: false,
+                match_full: false,
             })
     })?;
 
@@ -708,6 +711,9 @@ async fn changing_a_scalar_field_to_a_relation_field_must_work(api: &TestApi) {
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
+            initially_deferred: false,
+            match_full: false,
         }]
     );
 }
@@ -818,6 +824,9 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade, // required relations can't set ON DELETE SET NULL
                 on_update_action: ForeignKeyAction::NoAction,
+                deferrable: false,
+                initially_deferred: false,
+                match_full: false,
             },
             ForeignKey {
                 constraint_name: match api.sql_family() {
@@ -831,6 +840,9 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
                 on_update_action: ForeignKeyAction::NoAction,
+                deferrable: false,
+                initially_deferred: false,
+                match_full: false,
             }
         ]
     );
@@ -870,6 +882,9 @@ async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) {
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
+            initially_deferred: false,
+            match_full: false,
         }]
     );
 }
@@ -905,6 +920,9 @@ async fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type(api: &TestA
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
+            initially_deferred: false,
+            match_full: false,
         }]
     );
 }
@@ -979,6 +997,9 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
+            initially_deferred: false,
+            match_full: false,
         }]
     );
 
@@ -1009,6 +1030,9 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
+            initially_deferred: false,
+            match_full: false,
         }]
     );
 
@@ -1359,6 +1383,13 @@ async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(ap
         name: "Box_cat_id_unique".into(),
         columns: vec!["cat_id".into()],
         tpe: IndexType::Unique,
+        storage_parameters: Vec::new(),
+        expressions: Vec::new(),
+        algorithm: None,
+        invisible: false,
+        comment: None,
+        nulls_not_distinct: false,
+        opclasses: Vec::new(),
     }];
 
     assert_eq!(box_table.indices, expected_indexes);
@@ -518,3 +518,32 @@ async fn new_index_with_same_name_as_index_from_dropped_table_works(api: &TestAp
 
     Ok(())
 }
+
+#[test_each_connector_mssql]
+async fn new_tables_do_not_get_redundant_index_steps(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            name String
+            email String
+
+            @@index([name])
+            @@unique([email])
+        }
+    "#;
+
+    let result = api.infer_and_apply(dm).await;
+
+    result.sql_schema.assert_table("Cat")?.assert_indexes_count(2)?;
+
+    // MySQL and SQL Server create the indexes inline, in the CREATE TABLE statement.
+    let expected_steps: &[&str] = if api.is_mysql() || api.is_mssql() {
+        &["CreateTable"]
+    } else {
+        &["CreateTable", "CreateIndex", "CreateIndex"]
+    };
+
+    assert_eq!(result.migration_output.describe_steps(), expected_steps);
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ mod json;
 mod mariadb;
 mod mark_migration_applied_tests;
 mod mark_migration_rolled_back_tests;
+mod mssql;
 mod mysql;
 mod postgres;
 mod sql;
@@ -0,0 +1,75 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector_mssql(tags("mssql_2019"))]
+async fn default_constraint_names_are_stable(api: &TestApi) -> TestResult {
+    let long_field_name = "a".repeat(120);
+
+    let dm = format!(
+        r#"
+        model Cat {{
+            id Int @id
+            name String @default("Felix")
+            {} Int @default(0)
+        }}
+        "#,
+        long_field_name
+    );
+
+    api.schema_push(&dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let long_constraint_name = schema
+        .table_bang("Cat")
+        .column_bang(&long_field_name)
+        .default_constraint_name
+        .clone()
+        .unwrap();
+
+    assert_eq!(long_constraint_name.len(), 128);
+    assert!(long_constraint_name.starts_with("DF__Cat__"));
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("name", |col| col.assert_default_constraint_name("DF__Cat__name"))
+    })?;
+
+    // The described names must match the calculated ones, or the defaults would be recreated.
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector_mssql(tags("mssql_2019"))]
+async fn adding_multiple_columns_at_once_works(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int     @id
+            name String?
+            age  Int     @default(0)
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_columns_count(3)?
+            .assert_column("name", |col| col.assert_is_nullable())?
+            .assert_column("age", |col| col.assert_is_required())
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
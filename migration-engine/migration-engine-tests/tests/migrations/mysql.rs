@@ -1,4 +1,6 @@
 use migration_engine_tests::sql::*;
+use quaint::prelude::Queryable;
+use sql_schema_describer::DefaultValue;
 use std::fmt::Write as _;
 
 /// We need to test this specifically for mysql, because foreign keys are indexes, and they are
@@ -286,3 +288,391 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql_8"))]
+async fn unsigned_integer_columns_round_trip(api: &TestApi) -> TestResult {
+    let dm = r#"
+        datasource mysql {
+            provider = "mysql"
+            url = "mysql://localhost/test"
+        }
+
+        generator client {
+          provider = "prisma-client-js"
+          previewFeatures = ["nativeTypes"]
+        }
+
+        model A {
+            id Int @id
+            counter Int @mysql.UnsignedInt
+            bigCounter BigInt @mysql.UnsignedBigInt
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("A", |table| {
+        table
+            .assert_column("counter", |col| col.assert_full_data_type("int unsigned"))?
+            .assert_column("bigCounter", |col| col.assert_full_data_type("bigint unsigned"))
+    })?;
+
+    let signed_dm = r#"
+        model A {
+            id Int @id
+            counter Int
+            bigCounter BigInt
+        }
+    "#;
+
+    // INT UNSIGNED and INT must not be considered equal by the differ.
+    api.schema_push(signed_dm)
+        .force(true)
+        .send()
+        .await?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("A", |table| {
+        table
+            .assert_column("counter", |col| col.assert_full_data_type("int"))?
+            .assert_column("bigCounter", |col| col.assert_full_data_type("bigint"))
+    })?;
+
+    api.schema_push(signed_dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn columns_added_in_the_middle_of_a_table_keep_their_position(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            age Int
+            name String
+        }
+    "#;
+
+    let result = api.infer_apply(dm2).send().await?.assert_green()?.into_inner();
+    let raw = result
+        .database_steps
+        .iter()
+        .map(|step| step.raw.as_str())
+        .collect::<Vec<_>>();
+
+    assert!(
+        raw.iter()
+            .any(|sql| sql.contains("ADD COLUMN `age`") && sql.contains("AFTER `id`")),
+        "{:?}",
+        raw
+    );
+
+    let schema = api.describe_database().await?;
+    let column_names: Vec<&str> = schema
+        .table_bang("Cat")
+        .columns
+        .iter()
+        .map(|column| column.name.as_str())
+        .collect();
+
+    assert_eq!(column_names, &["id", "age", "name"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn table_collations_round_trip(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd("CREATE TABLE `Cat` (id INT PRIMARY KEY) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_bin")
+        .await?;
+
+    let schema = api.describe_database().await?;
+
+    assert_eq!(schema.table_bang("Cat").collation.as_deref(), Some("utf8mb4_bin"));
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    // The explicit collation must not cause drift.
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    let schema = api.describe_database().await?;
+
+    assert_eq!(schema.table_bang("Cat").collation.as_deref(), Some("utf8mb4_bin"));
+    assert_eq!(
+        schema.table_bang("Dog").collation.as_deref(),
+        Some("utf8mb4_0900_ai_ci")
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn invisible_indexes_round_trip(api: &TestApi) -> TestResult {
+    let migration = r#"
+        CREATE TABLE `Cat` (
+            `id` INTEGER PRIMARY KEY,
+            `name` VARCHAR(191) NOT NULL,
+            INDEX `Cat_name_idx` (`name`) INVISIBLE
+        );
+    "#;
+
+    // Drop the table behind the migration history's back, so the drift
+    // rollback script has to render the index again.
+    let (schema, _) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!("DROP TABLE `{}`.`Cat`", api.schema_name()),
+        &["INDEX `Cat_name_idx`(`name`) INVISIBLE"],
+    )
+    .await?;
+
+    let table = schema.table_bang("Cat");
+
+    assert_eq!(table.indices.len(), 1);
+    assert!(table.indices[0].invisible);
+    assert!(!table.column_bang("name").invisible);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn on_update_current_timestamp_round_trips(api: &TestApi) -> TestResult {
+    let migration = r#"
+        CREATE TABLE `Cat` (
+            `id` INTEGER PRIMARY KEY,
+            `createdAt` DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+            `updatedAt` DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3) ON UPDATE CURRENT_TIMESTAMP(3),
+            `updatedAtMicros` DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6)
+        );
+    "#;
+
+    // Drop the table behind the migration history's back, so the drift
+    // rollback script has to render the columns again.
+    let (schema, rollback) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!("DROP TABLE `{}`.`Cat`", api.schema_name()),
+        &[
+            "DEFAULT CURRENT_TIMESTAMP(3) ON UPDATE CURRENT_TIMESTAMP(3)",
+            "DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6)",
+        ],
+    )
+    .await?;
+
+    let table = schema.table_bang("Cat");
+
+    assert_eq!(table.column_bang("createdAt").default, Some(DefaultValue::NOW));
+    assert!(!table.column_bang("createdAt").on_update_current_timestamp);
+    assert_eq!(table.column_bang("updatedAt").default, Some(DefaultValue::NOW));
+    assert!(table.column_bang("updatedAt").on_update_current_timestamp);
+    assert!(table.column_bang("updatedAtMicros").on_update_current_timestamp);
+    assert_eq!(
+        rollback.matches("ON UPDATE CURRENT_TIMESTAMP").count(),
+        2,
+        "{}",
+        rollback
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn index_comments_round_trip(api: &TestApi) -> TestResult {
+    let migration = r#"
+        CREATE TABLE `Cat` (
+            `id` INTEGER PRIMARY KEY,
+            `name` VARCHAR(100) NOT NULL,
+            `age` INTEGER NOT NULL,
+            INDEX `Cat_name_idx` (`name`) COMMENT 'Cats'' names',
+            INDEX `Cat_age_idx` (`age`)
+        );
+    "#;
+
+    // Drop the table behind the migration history's back, so the drift
+    // rollback script has to render the indexes again.
+    let (schema, rollback) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!("DROP TABLE `{}`.`Cat`", api.schema_name()),
+        &["COMMENT 'Cats'' names'"],
+    )
+    .await?;
+
+    let table = schema.table_bang("Cat");
+    let comment_of = |index_name: &str| {
+        table
+            .indices
+            .iter()
+            .find(|index| index.name == index_name)
+            .and_then(|index| index.comment.clone())
+    };
+
+    assert_eq!(comment_of("Cat_name_idx").as_deref(), Some("Cats' names"));
+    assert_eq!(comment_of("Cat_age_idx"), None);
+    assert_eq!(rollback.matches("COMMENT").count(), 1, "{}", rollback);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn self_relations_do_not_cascade(api: &TestApi) -> TestResult {
+    use sql_schema_describer::ForeignKeyAction;
+
+    let dm = r#"
+        model Cat {
+            id       Int   @id
+            motherId Int?
+            mother   Cat?  @relation("MotherOf", fields: [motherId], references: [id])
+            kittens  Cat[] @relation("MotherOf")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_fk_on_columns(&["motherId"], |fk| fk.assert_references("Cat", &["id"]))
+    })?;
+
+    let schema = api.describe_database().await?;
+    let fk = &schema.table_bang("Cat").foreign_keys[0];
+
+    assert_eq!(fk.on_delete_action, ForeignKeyAction::NoAction);
+    assert_eq!(fk.on_update_action, ForeignKeyAction::NoAction);
+
+    // Pushing the same schema again is a no-op.
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn added_columns_are_rendered_in_a_single_alter_table(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+            age  Int
+        }
+    "#;
+
+    let output = api
+        .create_migration("add-columns", dm2, &directory)
+        .send()
+        .await?
+        .into_output();
+
+    let script = std::fs::read_to_string(
+        directory
+            .path()
+            .join(output.generated_migration_name.unwrap())
+            .join("migration.sql"),
+    )?;
+
+    assert_eq!(script.matches("ALTER TABLE").count(), 1, "{}", script);
+    assert!(script.contains("ADD COLUMN `name`"), "{}", script);
+    assert!(script.contains("ADD COLUMN `age`"), "{}", script);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn added_indexes_and_foreign_keys_are_rendered_in_the_alter_table_of_their_table(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Owner {
+            id Int @id
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int    @id
+            name    String
+            ownerId Int
+            owner   Owner  @relation(fields: [ownerId], references: [id])
+
+            @@index([name])
+        }
+
+        model Owner {
+            id   Int   @id
+            cats Cat[]
+        }
+    "#;
+
+    let output = api
+        .create_migration("add-index-and-foreign-key", dm2, &directory)
+        .send()
+        .await?
+        .into_output();
+
+    let script = std::fs::read_to_string(
+        directory
+            .path()
+            .join(output.generated_migration_name.unwrap())
+            .join("migration.sql"),
+    )?;
+
+    assert_eq!(script.matches("ALTER TABLE").count(), 1, "{}", script);
+    assert!(!script.contains("CREATE INDEX"), "{}", script);
+    assert!(script.contains("ADD INDEX `Cat.name_index`(`name`)"), "{}", script);
+    assert!(script.contains("ADD FOREIGN KEY (`ownerId`)"), "{}", script);
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_index_on_columns(&["name"], |index| Ok(index))?
+            .assert_fk_on_columns(&["ownerId"], |fk| fk.assert_references("Owner", &["id"]))
+    })?;
+
+    Ok(())
+}
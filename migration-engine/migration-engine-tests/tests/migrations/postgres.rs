@@ -1,4 +1,4 @@
-use migration_engine_tests::*;
+use migration_engine_tests::sql::*;
 use quaint::prelude::Queryable;
 use sql_schema_describer::{ColumnArity, ColumnTypeFamily};
 use std::fmt::Write;
@@ -163,3 +163,844 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn interval_columns_round_trip(api: &TestApi) -> TestResult {
+    // Drop the table behind the migration history's back, so the drift
+    // rollback script has to render the interval column again.
+    let (schema, _) = assert_drift_rollback_contains(
+        api,
+        r#"CREATE TABLE "Timer" (id SERIAL PRIMARY KEY, duration interval NOT NULL);"#,
+        &format!(r#"DROP TABLE "{}"."Timer""#, api.schema_name()),
+        &[r#""duration" interval NOT NULL"#],
+    )
+    .await?;
+
+    SchemaAssertion(schema).assert_table("Timer", |table| {
+        table.assert_column("duration", |col| {
+            col.assert_full_data_type("interval")?
+                .assert_type_family(ColumnTypeFamily::Unsupported("interval".into()))
+        })
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn deferrable_foreign_keys_round_trip(api: &TestApi) -> TestResult {
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, "bestFriendId" INTEGER NOT NULL);
+        CREATE TABLE "Dog" (id INTEGER PRIMARY KEY, "bestFriendId" INTEGER NOT NULL);
+
+        ALTER TABLE "Cat" ADD CONSTRAINT "Cat_bestFriendId_fkey" FOREIGN KEY ("bestFriendId") REFERENCES "Dog"(id) DEFERRABLE INITIALLY DEFERRED;
+        ALTER TABLE "Dog" ADD CONSTRAINT "Dog_bestFriendId_fkey" FOREIGN KEY ("bestFriendId") REFERENCES "Cat"(id) DEFERRABLE;
+    "#;
+
+    // Drop the tables behind the migration history's back, so the drift
+    // rollback script has to render the constraints again.
+    let (schema, _) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!(r#"DROP TABLE "{0}"."Cat", "{0}"."Dog" CASCADE"#, api.schema_name()),
+        &[
+            "ON UPDATE CASCADE DEFERRABLE INITIALLY DEFERRED",
+            "ON UPDATE CASCADE DEFERRABLE;",
+        ],
+    )
+    .await?;
+
+    let cat_fk = &schema.table_bang("Cat").foreign_keys[0];
+    let dog_fk = &schema.table_bang("Dog").foreign_keys[0];
+
+    assert!(cat_fk.deferrable && cat_fk.initially_deferred);
+    assert!(dog_fk.deferrable && !dog_fk.initially_deferred);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn index_storage_parameters_round_trip(api: &TestApi) -> TestResult {
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+        CREATE INDEX "Cat_name_index" ON "Cat"(name) WITH (fillfactor = 70);
+    "#;
+
+    // Drop the table behind the migration history's back, so the drift
+    // rollback script has to render the index again.
+    let (schema, _) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!(r#"DROP TABLE "{}"."Cat""#, api.schema_name()),
+        &[r#"CREATE INDEX "Cat_name_index" ON "Cat"("name") WITH (fillfactor=70)"#],
+    )
+    .await?;
+
+    assert_eq!(
+        schema.table_bang("Cat").indices[0].storage_parameters,
+        &["fillfactor=70"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn identity_generation_modes_round_trip(api: &TestApi) -> TestResult {
+    use sql_schema_describer::IdentityGeneration;
+
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY);
+        CREATE TABLE "Dog" (id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY);
+    "#;
+
+    // Drop the tables behind the migration history's back, so the drift
+    // rollback script has to render the identity columns again.
+    let (schema, _) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!(
+            r#"DROP TABLE "{schema}"."Cat"; DROP TABLE "{schema}"."Dog""#,
+            schema = api.schema_name()
+        ),
+        &[
+            r#""id" int4 NOT NULL GENERATED ALWAYS AS IDENTITY"#,
+            r#""id" int4 NOT NULL GENERATED BY DEFAULT AS IDENTITY"#,
+        ],
+    )
+    .await?;
+
+    assert_eq!(
+        schema.table_bang("Cat").column_bang("id").identity,
+        Some(IdentityGeneration::Always)
+    );
+    assert_eq!(
+        schema.table_bang("Dog").column_bang("id").identity,
+        Some(IdentityGeneration::ByDefault)
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn identity_sequence_options_round_trip(api: &TestApi) -> TestResult {
+    use sql_schema_describer::IdentityOptions;
+
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER GENERATED ALWAYS AS IDENTITY (START WITH 100 INCREMENT BY 10) PRIMARY KEY);
+        CREATE TABLE "Dog" (id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY);
+    "#;
+
+    // Drop the tables behind the migration history's back, so the drift
+    // rollback script has to render the identity columns again.
+    let (schema, rollback) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!(
+            r#"DROP TABLE "{schema}"."Cat"; DROP TABLE "{schema}"."Dog""#,
+            schema = api.schema_name()
+        ),
+        &[r#""id" int4 NOT NULL GENERATED ALWAYS AS IDENTITY (START WITH 100 INCREMENT BY 10)"#],
+    )
+    .await?;
+
+    assert_eq!(
+        schema.table_bang("Cat").column_bang("id").identity_options,
+        Some(IdentityOptions {
+            start: 100,
+            increment: 10
+        })
+    );
+    assert_eq!(schema.table_bang("Dog").column_bang("id").identity_options, None);
+
+    // The identity column with the default options is rendered without them.
+    assert_eq!(rollback.matches("START WITH").count(), 1, "{}", rollback);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn jsonb_defaults_round_trip(api: &TestApi) -> TestResult {
+    use prisma_value::PrismaValue;
+    use sql_schema_describer::DefaultValue;
+
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, traits JSONB NOT NULL DEFAULT '{"name": "it''s", "lives": 9}');
+    "#;
+
+    // Drop the table behind the migration history's back, so the drift
+    // rollback script has to render the described default.
+    let (schema, rollback) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!(r#"DROP TABLE "{}"."Cat""#, api.schema_name()),
+        &["'::jsonb"],
+    )
+    .await?;
+
+    let default = schema.table_bang("Cat").column_bang("traits").default.clone();
+
+    assert!(
+        matches!(default, Some(DefaultValue::VALUE(PrismaValue::Json(_)))),
+        "{:?}",
+        default
+    );
+
+    api.apply_script(rollback).await?;
+
+    let schema = api.describe_database().await?;
+
+    assert_eq!(schema.table_bang("Cat").column_bang("traits").default, default);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn json_defaults_are_cast_to_json(api: &TestApi) -> TestResult {
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, traits JSON NOT NULL DEFAULT '{"lives": 9}');
+    "#;
+
+    let (schema, rollback) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!(r#"DROP TABLE "{}"."Cat""#, api.schema_name()),
+        &["'::json"],
+    )
+    .await?;
+
+    assert!(!rollback.contains("::jsonb"), "{}", rollback);
+
+    let default = schema.table_bang("Cat").column_bang("traits").default.clone();
+
+    api.apply_script(rollback).await?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("traits", |col| {
+            col.assert_full_data_type("json")?.assert_default(default)
+        })
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn expression_indexes_round_trip(api: &TestApi) -> TestResult {
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+        CREATE INDEX "Cat_lower_name_idx" ON "Cat" (id, lower(name));
+    "#;
+
+    // Drop the index behind the migration history's back, so the drift
+    // rollback script has to render it from its description.
+    let (schema, rollback) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!(r#"DROP INDEX "{}"."Cat_lower_name_idx""#, api.schema_name()),
+        &[r#"CREATE INDEX "Cat_lower_name_idx" ON "Cat"("id", (lower(name)))"#],
+    )
+    .await?;
+
+    api.apply_script(rollback).await?;
+
+    api.assert_schema().await?.assert_equals(&schema)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn text_to_int_type_changes_are_rendered_with_a_using_cast(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id  Int @id
+            tag String
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+    api.insert("Cat").value("id", 1).value("tag", "20").result_raw().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id  Int @id
+            tag Int
+        }
+    "#;
+
+    let output = api
+        .create_migration("tag-to-int", dm2, &directory)
+        .send()
+        .await?
+        .into_output();
+
+    let migration_script = std::fs::read_to_string(
+        directory
+            .path()
+            .join(output.generated_migration_name.unwrap())
+            .join("migration.sql"),
+    )?;
+
+    assert!(
+        migration_script.contains(r#"ALTER COLUMN "tag" SET DATA TYPE INTEGER USING "tag"::INTEGER"#),
+        "{}",
+        migration_script
+    );
+
+    api.apply_migrations(&directory).send().await?;
+
+    api.dump_table("Cat")
+        .await?
+        .assert_single_row(|row| row.assert_int_value("tag", 20))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn tsvector_columns_with_gin_indexes_round_trip(api: &TestApi) -> TestResult {
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, search TSVECTOR NOT NULL);
+        CREATE INDEX "Cat_search_index" ON "Cat" USING gin (search);
+    "#;
+
+    // Drop the table behind the migration history's back, so the drift
+    // rollback script has to render the column and the index again.
+    let (schema, _) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!(r#"DROP TABLE "{}"."Cat""#, api.schema_name()),
+        &[
+            r#""search" tsvector NOT NULL"#,
+            r#"CREATE INDEX "Cat_search_index" ON "Cat" USING gin("search")"#,
+        ],
+    )
+    .await?;
+
+    let table = schema.table_bang("Cat");
+    let column = table.column_bang("search");
+
+    assert_eq!(column.tpe.family, ColumnTypeFamily::Unsupported("tsvector".into()));
+    assert_eq!(column.tpe.full_data_type, "tsvector");
+    assert_eq!(table.indices[0].algorithm.as_deref(), Some("gin"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn dropping_a_column_referenced_by_a_view_requires_cascade(api: &TestApi) -> TestResult {
+    api.apply_script(
+        r#"
+            CREATE TABLE "Cat" ("id" INTEGER PRIMARY KEY, "name" TEXT, "color" TEXT);
+            CREATE VIEW "CatName" AS SELECT "name" FROM "Cat";
+        "#,
+    )
+    .await?;
+
+    let dm = r#"
+        model Cat {
+            id    Int @id
+            color String?
+        }
+    "#;
+
+    let err = api.schema_push(dm).force(true).send().await.unwrap_err().to_string();
+
+    assert!(err.contains("other objects depend on it"), "{}", err);
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?;
+
+    let url = format!(
+        "{}&{}=true",
+        postgres_12_url(api.connection_info().dbname().unwrap()),
+        sql_migration_connector::CASCADE_COLUMN_DROPS_PARAM
+    );
+    let connector = sql_migration_connector::SqlMigrationConnector::new(&url).await?;
+    let cascading_api = test_api(connector).await;
+
+    SchemaPush::new(&cascading_api, dm.to_owned())
+        .force(true)
+        .send()
+        .await?
+        .assert_executable()?
+        .assert_warnings(&["You are about to drop the column `name` on the `Cat` table with CASCADE. The views and other database objects depending on the column will be dropped too.".into()])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_does_not_have_column("name"))?;
+    assert!(api.database().raw_cmd(r#"SELECT * FROM "CatName""#).await.is_err());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn indexes_can_be_created_concurrently(api: &TestApi) -> TestResult {
+    let url = format!(
+        "{}&{}=true",
+        postgres_12_url(api.connection_info().dbname().unwrap()),
+        sql_migration_connector::CONCURRENT_INDEX_CREATION_PARAM
+    );
+    let connector = sql_migration_connector::SqlMigrationConnector::new(&url).await?;
+    let concurrent_api = test_api(connector).await;
+
+    let dm1 = r#"
+        model Cat {
+            id   Int @id
+            name String
+        }
+    "#;
+
+    SchemaPush::new(&concurrent_api, dm1.to_owned())
+        .send()
+        .await?
+        .assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int @id
+            name String
+
+            @@index([name])
+        }
+    "#;
+
+    // Postgres refuses to run `CREATE INDEX CONCURRENTLY` in a transaction, so
+    // this only succeeds if the step is applied on its own.
+    let output = InferApply::new(&concurrent_api, dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .into_inner();
+
+    let raw: Vec<&str> = output.database_steps.iter().map(|step| step.raw.as_str()).collect();
+
+    assert_eq!(raw, &[r#"CREATE INDEX CONCURRENTLY "Cat.name_index" ON "Cat"("name")"#]);
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_index_on_columns(&["name"], Ok))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn migration_scripts_do_not_create_indexes_concurrently(api: &TestApi) -> TestResult {
+    let url = format!(
+        "{}&{}=true",
+        postgres_12_url(api.connection_info().dbname().unwrap()),
+        sql_migration_connector::CONCURRENT_INDEX_CREATION_PARAM
+    );
+    let connector = sql_migration_connector::SqlMigrationConnector::new(&url).await?;
+    let concurrent_api = test_api(connector).await;
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id   Int @id
+            name String
+        }
+    "#;
+
+    CreateMigration::new(&concurrent_api, "initial", dm1, &directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int @id
+            name String
+
+            @@index([name])
+        }
+    "#;
+
+    CreateMigration::new(&concurrent_api, "add-index", dm2, &directory)
+        .send()
+        .await?
+        .assert_migration("add-index", |migration| {
+            migration.assert_contents("-- CreateIndex\nCREATE INDEX \"Cat.name_index\" ON \"Cat\"(\"name\");\n")
+        })?;
+
+    // The script is applied as a single batch, in a transaction.
+    ApplyMigrations::new(&concurrent_api, &directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-index"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_index_on_columns(&["name"], Ok))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn tables_linked_by_foreign_keys_can_be_dropped_together(api: &TestApi) -> TestResult {
+    // Migrations inferred from datamodels cannot drop the foreign keys first,
+    // since they have no constraint name. The referenced table comes first, so
+    // dropping the tables in schema order would fail.
+    let dm = r#"
+        model Cat {
+            id     Int     @id
+            humans Human[]
+        }
+
+        model Human {
+            id    Int @id
+            catId Int
+            cat   Cat @relation(fields: [catId], references: [id])
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+    api.assert_schema()
+        .await?
+        .assert_table("Human", |table| table.assert_foreign_keys_count(1))?;
+
+    api.infer_apply("").send().await?.assert_green()?;
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn index_opclasses_round_trip(api: &TestApi) -> TestResult {
+    use sql_schema_describer::IndexOpclass;
+
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, name TEXT NOT NULL, color TEXT NOT NULL);
+        CREATE INDEX "Cat_name_color_index" ON "Cat" (name text_pattern_ops, color);
+    "#;
+
+    // Drop the table behind the migration history's back, so the drift
+    // rollback script has to render the index again.
+    let (schema, _) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!(r#"DROP TABLE "{}"."Cat""#, api.schema_name()),
+        &[r#"CREATE INDEX "Cat_name_color_index" ON "Cat"("name" text_pattern_ops, "color")"#],
+    )
+    .await?;
+
+    assert_eq!(
+        schema.table_bang("Cat").indices[0].opclasses,
+        &[IndexOpclass {
+            position: 0,
+            name: "text_pattern_ops".to_owned()
+        }]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn timestamptz_columns_round_trip(api: &TestApi) -> TestResult {
+    // Drop the table behind the migration history's back, so the drift
+    // rollback script has to render the timestamptz column again.
+    let (schema, _) = assert_drift_rollback_contains(
+        api,
+        r#"CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, born_at TIMESTAMPTZ(3) NOT NULL);"#,
+        &format!(r#"DROP TABLE "{}"."Cat""#, api.schema_name()),
+        &[r#""born_at" TIMESTAMPTZ(3) NOT NULL"#],
+    )
+    .await?;
+
+    SchemaAssertion(schema).assert_table("Cat", |table| {
+        table.assert_column("born_at", |col| {
+            col.assert_full_data_type("timestamptz")?
+                .assert_type_family(ColumnTypeFamily::DateTime)
+        })
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn timestamptz_native_types_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgresql://localhost/test"
+        }
+
+        generator client {
+          provider = "prisma-client-js"
+          previewFeatures = ["nativeTypes"]
+        }
+
+        model Cat {
+            id Int @id
+            bornAt DateTime @pg.Timestamptz
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("bornAt", |col| {
+            col.assert_full_data_type("timestamptz")?
+                .assert_type_family(ColumnTypeFamily::DateTime)
+        })
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn match_full_foreign_keys_round_trip(api: &TestApi) -> TestResult {
+    let migration = r#"
+        CREATE TABLE "Owner" ("firstName" TEXT, "lastName" TEXT, PRIMARY KEY ("firstName", "lastName"));
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, "ownerFirstName" TEXT, "ownerLastName" TEXT);
+        CREATE TABLE "Dog" (id INTEGER PRIMARY KEY, "ownerFirstName" TEXT, "ownerLastName" TEXT);
+
+        ALTER TABLE "Cat" ADD CONSTRAINT "Cat_owner_fkey" FOREIGN KEY ("ownerFirstName", "ownerLastName") REFERENCES "Owner"("firstName", "lastName") MATCH FULL;
+        ALTER TABLE "Dog" ADD CONSTRAINT "Dog_owner_fkey" FOREIGN KEY ("ownerFirstName", "ownerLastName") REFERENCES "Owner"("firstName", "lastName") MATCH SIMPLE;
+    "#;
+
+    // Drop the tables behind the migration history's back, so the drift
+    // rollback script has to render the constraints again.
+    let (schema, rollback) = assert_drift_rollback_contains(
+        api,
+        migration,
+        &format!(
+            r#"DROP TABLE "{0}"."Cat", "{0}"."Dog", "{0}"."Owner" CASCADE"#,
+            api.schema_name()
+        ),
+        &[r#"REFERENCES "Owner"("firstName","lastName") MATCH FULL ON DELETE"#],
+    )
+    .await?;
+
+    assert!(schema.table_bang("Cat").foreign_keys[0].match_full);
+    assert!(!schema.table_bang("Dog").foreign_keys[0].match_full);
+    assert_eq!(rollback.matches("MATCH FULL").count(), 1, "{}", rollback);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn user_created_sequences_are_not_dropped_with_column_defaults(api: &TestApi) -> TestResult {
+    api.apply_script(
+        r#"
+            CREATE SEQUENCE "shared_seq";
+            CREATE TABLE "Cat" ("id" INTEGER PRIMARY KEY DEFAULT nextval('shared_seq'));
+            CREATE TABLE "Dog" ("id" INTEGER PRIMARY KEY DEFAULT nextval('shared_seq'));
+            CREATE TABLE "Mouse" ("id" SERIAL PRIMARY KEY);
+        "#,
+    )
+    .await?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(!schema.get_sequence("shared_seq").unwrap().owned);
+    assert!(schema.get_sequence("Mouse_id_seq").unwrap().owned);
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+        }
+
+        model Mouse {
+            id Int @id @default(autoincrement())
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| {
+            table.assert_column("id", |col| col.assert_has_no_default())
+        })?
+        .assert_table("Dog", |table| {
+            table.assert_column("id", |col| col.assert_has_no_default())
+        })?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.get_sequence("shared_seq").is_some());
+    assert!(schema.get_sequence("Mouse_id_seq").is_some());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn column_collation_changes_are_rendered(api: &TestApi) -> TestResult {
+    // Change the collation behind the migration history's back, so the drift
+    // rollback script has to change it again.
+    let (schema, _) = assert_drift_rollback_contains(
+        api,
+        r#"CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, name TEXT COLLATE "C" NOT NULL);"#,
+        &format!(
+            r#"ALTER TABLE "{}"."Cat" ALTER COLUMN "name" SET DATA TYPE TEXT COLLATE "default""#,
+            api.schema_name()
+        ),
+        &[r#"ALTER COLUMN "name" SET DATA TYPE text COLLATE "C""#],
+    )
+    .await?;
+
+    assert_eq!(
+        schema.table_bang("Cat").column_bang("name").collation.as_deref(),
+        Some("C")
+    );
+
+    let schema = api.describe_database().await?;
+
+    assert_eq!(schema.table_bang("Cat").column_bang("name").collation, None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn column_storage_settings_round_trip_when_enabled(api: &TestApi) -> TestResult {
+    use sql_schema_describer::StorageStrategy;
+
+    let url = format!(
+        "{}&{}=true",
+        postgres_12_url(api.connection_info().dbname().unwrap()),
+        sql_migration_connector::COLUMN_STORAGE_PARAM
+    );
+    let storage_api = api.with_connection_string(&url).await?;
+
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, bio TEXT NOT NULL);
+        ALTER TABLE "Cat" ALTER COLUMN "bio" SET STORAGE EXTERNAL;
+    "#;
+
+    // Drop the table behind the migration history's back, so the drift
+    // rollback script has to render the storage settings again.
+    let (schema, rollback) = assert_drift_rollback_contains(
+        &storage_api,
+        migration,
+        &format!(r#"DROP TABLE "{}"."Cat""#, api.schema_name()),
+        &[r#"ALTER TABLE "Cat" ALTER COLUMN "bio" SET STORAGE EXTERNAL"#],
+    )
+    .await?;
+
+    let storage = schema.table_bang("Cat").column_bang("bio").storage.clone().unwrap();
+
+    assert_eq!(storage.strategy, Some(StorageStrategy::External));
+    assert_eq!(schema.table_bang("Cat").column_bang("id").storage, None);
+
+    // Column storage is opt-in.
+    api.apply_script(rollback).await?;
+
+    let schema = api.describe_database().await?;
+
+    assert_eq!(schema.table_bang("Cat").column_bang("bio").storage, None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn variants_can_be_removed_from_an_enum_used_in_an_array_column(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            moods CatMood[]
+        }
+
+        enum CatMood {
+            HAPPY
+            HUNGRY
+            SLEEPY
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.database()
+        .raw_cmd(&format!(
+            r#"INSERT INTO "{}"."Cat" ("id", "moods") VALUES (1, '{{HUNGRY,SLEEPY}}')"#,
+            api.schema_name()
+        ))
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            moods CatMood[]
+        }
+
+        enum CatMood {
+            HUNGRY
+            SLEEPY
+        }
+    "#;
+
+    api.schema_push(dm2).force(true).send().await?.assert_executable()?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum("CatMood", |enm| enm.assert_values(&["HUNGRY", "SLEEPY"]))?
+        .assert_table("Cat", |table| {
+            table.assert_column("moods", |col| {
+                col.assert_type_family(ColumnTypeFamily::Enum("CatMood".into()))?
+                    .assert_is_list()
+            })
+        })?;
+
+    let moods = api
+        .database()
+        .query_raw(
+            &format!(
+                r#"SELECT array_to_string("moods", ',') AS "moods" FROM "{}"."Cat""#,
+                api.schema_name()
+            ),
+            &[],
+        )
+        .await?;
+
+    assert_eq!(
+        moods.into_single()?.get("moods").and_then(|moods| moods.to_string()),
+        Some("HUNGRY,SLEEPY".to_owned())
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn column_collation_resets_to_the_default_are_rendered(api: &TestApi) -> TestResult {
+    use migration_core::commands::{DiagnoseMigrationHistoryOutput, DriftDiagnostic};
+
+    let directory = api.create_migrations_directory()?;
+    let migration_directory = directory.path().join("20201014000000_no_collations");
+
+    std::fs::create_dir_all(&migration_directory)?;
+    std::fs::write(
+        migration_directory.join("migration.sql"),
+        r#"
+            CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+        "#,
+    )?;
+
+    api.apply_migrations(&directory).send().await?;
+
+    api.database()
+        .raw_cmd(&format!(
+            r#"ALTER TABLE "{}"."Cat" ALTER COLUMN "name" SET DATA TYPE TEXT COLLATE "C""#,
+            api.schema_name()
+        ))
+        .await?;
+
+    let DiagnoseMigrationHistoryOutput { drift, .. } =
+        api.diagnose_migration_history(&directory).send().await?.into_output();
+
+    match drift {
+        Some(DriftDiagnostic::DriftDetected { rollback }) => {
+            assert!(
+                rollback.contains(r#"ALTER COLUMN "name" SET DATA TYPE text COLLATE "default""#),
+                "{}",
+                rollback
+            );
+        }
+        other => panic!("Expected drift, got {:?}", other),
+    }
+
+    Ok(())
+}
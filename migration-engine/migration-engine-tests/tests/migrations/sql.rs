@@ -292,6 +292,45 @@ async fn remapped_multi_field_id_as_part_of_relation_must_work(api: &TestApi) ->
     Ok(())
 }
 
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn reordering_the_columns_of_a_compound_primary_key_recreates_it(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Dog {
+            name String
+            weight Int
+
+            @@id([name, weight])
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Dog", |table| {
+        table.assert_pk(|pk| pk.assert_columns(&["name", "weight"]))
+    })?;
+
+    let dm2 = r#"
+        model Dog {
+            name String
+            weight Int
+
+            @@id([weight, name])
+        }
+    "#;
+
+    let result = api.infer_apply(dm2).send().await?.assert_green()?.into_inner();
+
+    assert!(!result.database_steps.is_empty());
+
+    api.assert_schema().await?.assert_table("Dog", |table| {
+        table.assert_pk(|pk| pk.assert_columns(&["weight", "name"]))
+    })?;
+
+    api.infer_apply(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
 #[test_each_connector(tags("sql"))]
 async fn unique_constraints_on_composite_relation_fields(api: &TestApi) -> TestResult {
     let dm = r##"
@@ -389,3 +428,28 @@ async fn dropping_mutually_referencing_tables_works(api: &TestApi) -> TestResult
 
     Ok(())
 }
+
+#[test_each_connector(tags("sql"))]
+async fn bigint_defaults_work(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            population BigInt @default(9007199254740993)
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.insert("Cat").value("id", 1).result_raw().await?;
+
+    let populations: Vec<i64> = api
+        .dump_table("Cat")
+        .await?
+        .into_iter()
+        .map(|row| row.get("population").unwrap().as_i64().unwrap())
+        .collect();
+
+    assert_eq!(populations, &[9007199254740993]);
+
+    Ok(())
+}
@@ -88,3 +88,126 @@ async fn creating_a_model_with_a_non_autoincrement_id_column_is_idempotent(api:
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn check_constraints_survive_table_redefinitions(api: &TestApi) -> TestResult {
+    api.apply_script(
+        r#"
+            CREATE TABLE "Cat" (
+                "id" INTEGER NOT NULL PRIMARY KEY,
+                "name" TEXT NOT NULL,
+                "age" INTEGER NOT NULL CHECK ("age" >= 0),
+                CONSTRAINT "Cat_name_check" CHECK (length("name") > 0)
+            )
+        "#,
+    )
+    .await?;
+
+    let dm = r#"
+        model Cat {
+            id    Int    @id
+            name  String
+            age   Int
+            color String
+        }
+    "#;
+
+    // Adding a column redefines the table.
+    api.schema_push(dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    let schema = api.describe_database().await?;
+    let check_constraints: Vec<(Option<&str>, &str)> = schema
+        .table_bang("Cat")
+        .check_constraints
+        .iter()
+        .map(|check_constraint| (check_constraint.name.as_deref(), check_constraint.expression.as_str()))
+        .collect();
+
+    assert_eq!(
+        check_constraints,
+        &[
+            (None, r#""age" >= 0"#),
+            (Some("Cat_name_check"), r#"length("name") > 0"#)
+        ]
+    );
+
+    let insert = api
+        .insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .value("age", -1)
+        .value("color", "orange")
+        .result_raw()
+        .await;
+
+    assert!(insert.is_err());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn without_rowid_tables_round_trip(api: &TestApi) -> TestResult {
+    api.apply_script(
+        r#"
+            CREATE TABLE "Cat" (
+                "id" TEXT NOT NULL PRIMARY KEY,
+                "name" TEXT NOT NULL
+            ) WITHOUT ROWID
+        "#,
+    )
+    .await?;
+
+    assert!(api.describe_database().await?.table_bang("Cat").without_rowid);
+
+    let dm = r#"
+        model Cat {
+            id    String @id
+            name  String
+            color String
+        }
+    "#;
+
+    // Adding a column redefines the table.
+    api.schema_push(dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.table_bang("Cat").without_rowid);
+    assert!(schema.table_bang("Cat").column("color").is_some());
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn expression_indexes_round_trip(api: &TestApi) -> TestResult {
+    let migration = r#"
+        CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+        CREATE INDEX "Cat_lower_name_idx" ON "Cat" (id, lower("name"));
+    "#;
+
+    // Drop the index behind the migration history's back, so the drift
+    // rollback script has to render it from its description.
+    let (schema, rollback) = assert_drift_rollback_contains(
+        api,
+        migration,
+        r#"DROP INDEX "Cat_lower_name_idx""#,
+        &[r#"CREATE INDEX "Cat_lower_name_idx" ON "Cat"("id", (lower("name")))"#],
+    )
+    .await?;
+
+    api.apply_script(rollback).await?;
+
+    api.assert_schema().await?.assert_equals(&schema)?;
+
+    Ok(())
+}
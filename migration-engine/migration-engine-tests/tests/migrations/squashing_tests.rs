@@ -491,3 +491,159 @@ async fn squashing_migrations_history_at_the_end_works(api: &TestApi) -> TestRes
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn squash_migrations_replaces_applied_migrations_with_a_single_one(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let dm3 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Hyena {
+            id Int @id
+            laughterFrequency Float
+        }
+    "#;
+
+    let mut initial_migration_names = Vec::with_capacity(3);
+
+    for (idx, schema) in [dm1, dm2, dm3].iter().enumerate() {
+        let name = api
+            .create_migration(&format!("migration{}", idx), schema, &directory)
+            .send()
+            .await?
+            .into_output()
+            .generated_migration_name
+            .unwrap();
+
+        initial_migration_names.push(name);
+    }
+
+    api.apply_migrations(&directory).send().await?;
+
+    let initial_schema = api.assert_schema().await?.into_schema();
+
+    let output = api
+        .squash_migrations("squashed", dm3, &directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert_eq!(output.squashed_migration_names, initial_migration_names);
+
+    let remaining_directories: Vec<String> = std::fs::read_dir(directory.path())?
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+
+    assert_eq!(remaining_directories, &[output.generated_migration_name]);
+
+    api.assert_schema().await?.assert_equals(&initial_schema)?;
+
+    let DiagnoseMigrationHistoryOutput {
+        drift,
+        history,
+        failed_migration_names,
+        edited_migration_names,
+        has_migrations_table,
+        error_in_unapplied_migration,
+    } = api.diagnose_migration_history(&directory).send().await?.into_output();
+
+    assert!(drift.is_none(), "got: {:#?}", drift);
+    assert!(history.is_none(), "got: {:#?}", history);
+    assert!(failed_migration_names.is_empty());
+    assert!(edited_migration_names.is_empty());
+    assert!(has_migrations_table);
+    assert!(error_in_unapplied_migration.is_none());
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    api.assert_schema().await?.assert_equals(&initial_schema)?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squash_migrations_with_a_schema_not_matching_the_history_changes_nothing(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let migration_name = api
+        .create_migration("initial", dm1, &directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    api.apply_migrations(&directory).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let err = api
+        .squash_migrations("squashed", dm2, &directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.contains(
+            "The Prisma schema does not match the state of the database at the end of the migrations history."
+        ),
+        "{}",
+        err
+    );
+
+    let remaining_directories: Vec<String> = std::fs::read_dir(directory.path())?
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+
+    assert_eq!(remaining_directories, &[migration_name]);
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let DiagnoseMigrationHistoryOutput { drift, history, .. } =
+        api.diagnose_migration_history(&directory).send().await?.into_output();
+
+    assert!(drift.is_none(), "got: {:#?}", drift);
+    assert!(history.is_none(), "got: {:#?}", history);
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use quaint::prelude::Queryable;
 
 #[test_each_connector]
 async fn reset_works(api: &TestApi) -> TestResult {
@@ -111,3 +112,152 @@ async fn reset_then_diagnostics_with_migrations_directory_works(api: &TestApi) -
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn reset_works_with_after_delete_triggers(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+
+        model CatLog {
+            id Int @id @default(autoincrement())
+            catId Int
+        }
+    "#;
+
+    api.schema_push(dm).send().await?;
+
+    let create_trigger = format!(
+        r#"
+        CREATE FUNCTION "{schema}".log_deleted_cat() RETURNS TRIGGER AS $$
+        BEGIN
+            INSERT INTO "{schema}"."CatLog" ("catId") VALUES (OLD.id);
+            RETURN OLD;
+        END;
+        $$ LANGUAGE plpgsql;
+
+        CREATE TRIGGER log_deleted_cat AFTER DELETE ON "{schema}"."Cat"
+            FOR EACH ROW EXECUTE PROCEDURE "{schema}".log_deleted_cat();
+        "#,
+        schema = api.schema_name(),
+    );
+
+    api.database().raw_cmd(&create_trigger).await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    api.reset().send().await?;
+
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    api.schema_push(dm).send().await?;
+
+    api.assert_schema().await?.assert_tables_count(2)?;
+
+    Ok(())
+}
+
+#[test_each_connector_mssql(tags("mssql_2019"))]
+async fn reset_works_with_after_delete_triggers_on_mssql(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+
+        model CatLog {
+            id Int @id @default(autoincrement())
+            catId Int
+        }
+    "#;
+
+    api.schema_push(dm).send().await?;
+
+    let create_trigger = format!(
+        r#"
+        CREATE TRIGGER [{schema}].[log_deleted_cat] ON [{schema}].[Cat] AFTER DELETE AS
+            INSERT INTO [{schema}].[CatLog] ([catId]) SELECT [id] FROM deleted
+        "#,
+        schema = api.schema_name(),
+    );
+
+    api.database().raw_cmd(&create_trigger).await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    api.reset().send().await?;
+
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    api.schema_push(dm).send().await?;
+
+    api.assert_schema().await?.assert_tables_count(2)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn truncate_all_empties_tables_but_keeps_the_schema(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id @default(autoincrement())
+            name String
+            toys Toy[]
+        }
+
+        model Toy {
+            id Int @id
+            catId Int
+            cat Cat @relation(fields: [catId], references: [id])
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let schema_before = api.describe_database().await?;
+
+    api.insert("Cat").value("name", "Garfield").result_raw().await?;
+    api.insert("Toy").value("id", 1).value("catId", 1).result_raw().await?;
+
+    api.truncate_all().await?;
+
+    api.dump_table("Cat").await?.assert_row_count(0)?;
+    api.dump_table("Toy").await?.assert_row_count(0)?;
+    assert_eq!(api.describe_database().await?, schema_before);
+
+    // The identity was restarted.
+    api.insert("Cat").value("name", "Nermal").result_raw().await?;
+    api.dump_table("Cat")
+        .await?
+        .assert_single_row(|row| row.assert_int_value("id", 1))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_reset_preserves_installed_extensions(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd(&format!(
+            "CREATE EXTENSION IF NOT EXISTS citext SCHEMA \"{}\"",
+            api.schema_name()
+        ))
+        .await?;
+
+    assert_eq!(api.installed_extensions().await?, &["citext"]);
+
+    api.reset().send().await?;
+
+    assert_eq!(api.installed_extensions().await?, &["citext"]);
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use migration_connector::CheckerMode;
 use migration_engine_tests::sql::*;
 use sql_schema_describer::ColumnTypeFamily;
 
@@ -103,6 +104,50 @@ async fn schema_push_warns_about_destructive_changes(api: &TestApi) -> TestResul
     Ok(())
 }
 
+#[test_each_connector]
+async fn schema_push_in_offline_checker_mode_warns_without_querying_the_database(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Box {
+            id Int @id
+            material String
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    // The `Box` table is empty, but offline checks cannot know that.
+    let expected_warning =
+        "You are about to drop the `Box` table. If the table is not empty, all the data it contains will be lost.";
+
+    api.schema_push(dm2)
+        .checker_mode(CheckerMode::Offline)
+        .send()
+        .await?
+        .assert_warnings(&[expected_warning.into()])?
+        .assert_no_steps()?;
+
+    api.schema_push(dm2)
+        .checker_mode(CheckerMode::Online)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_tables_count(1)?;
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn schema_push_with_an_unexecutable_migration_returns_a_message_and_aborts(api: &TestApi) -> TestResult {
     api.schema_push(SCHEMA)
@@ -140,6 +185,98 @@ async fn schema_push_with_an_unexecutable_migration_returns_a_message_and_aborts
     Ok(())
 }
 
+#[test_each_connector]
+async fn schema_push_with_accept_data_loss_applies_destructive_changes(api: &TestApi) -> TestResult {
+    api.schema_push(SCHEMA)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.insert("Box")
+        .value("id", 1)
+        .value("material", "cardboard")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let expected_warning = "You are about to drop the `Box` table, which is not empty (1 rows).";
+
+    api.schema_push(dm2)
+        .accept_data_loss(true)
+        .send()
+        .await?
+        .assert_warnings(&[expected_warning.into()])?
+        .assert_executable()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_tables_count(1)?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn schema_push_with_accept_data_loss_still_aborts_unexecutable_migrations(api: &TestApi) -> TestResult {
+    api.schema_push(SCHEMA)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.insert("Box")
+        .value("id", 1)
+        .value("material", "cardboard")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            boxId Int?
+            box Box? @relation(fields: [boxId], references: [id])
+        }
+
+        model Box {
+            id Int @id
+            material String
+            volumeCm3 Int
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .accept_data_loss(true)
+        .send()
+        .await?
+        .assert_unexecutable(&["Added the required column `volumeCm3` to the `Box` table without a default value. There are 1 rows in this table, it is not possible to execute this migration.".into()])?
+        .assert_no_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Box", |table| table.assert_does_not_have_column("volumeCm3"))?;
+
+    // Force goes further and attempts the unexecutable steps. Depending on the
+    // connector, the database refuses them, or fills the new column with an
+    // implicit default.
+    match api.schema_push(dm2).force(true).send().await {
+        Ok(assertion) => {
+            assertion.assert_has_executed_steps()?;
+
+            api.assert_schema()
+                .await?
+                .assert_table("Box", |table| table.assert_has_column("volumeCm3"))?;
+        }
+        // The abort path never errors, so the database refused an attempted step.
+        Err(_) => (),
+    }
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn indexes_and_unique_constraints_on_the_same_field_do_not_collide(api: &TestApi) -> TestResult {
     let dm = r#"
@@ -3,7 +3,7 @@ use bigdecimal::{BigDecimal, FromPrimitive};
 use chrono::{DateTime, NaiveDate, Utc};
 use connector_interface::{AggregationResult, Aggregator};
 use datamodel::FieldArity;
-use prisma_models::{PrismaValue, Record, TypeIdentifier};
+use prisma_models::{PrismaValue, Record, ScalarFieldRef, TypeIdentifier};
 use quaint::{
     ast::{Expression, Value},
     connector::ResultRow,
@@ -46,14 +46,20 @@ impl SqlRow {
                 Aggregator::Min(fields) => fields
                     .iter()
                     .map(|field| {
-                        AggregationResult::Min(field.clone(), coerce_null_to_zero_value(values.pop().unwrap()))
+                        AggregationResult::Min(
+                            field.clone(),
+                            coerce_null_to_zero_value_for(field, values.pop().unwrap()),
+                        )
                     })
                     .collect(),
 
                 Aggregator::Max(fields) => fields
                     .iter()
                     .map(|field| {
-                        AggregationResult::Max(field.clone(), coerce_null_to_zero_value(values.pop().unwrap()))
+                        AggregationResult::Max(
+                            field.clone(),
+                            coerce_null_to_zero_value_for(field, values.pop().unwrap()),
+                        )
                     })
                     .collect(),
             })
@@ -69,6 +75,14 @@ fn coerce_null_to_zero_value(value: PrismaValue) -> PrismaValue {
     }
 }
 
+/// Like `coerce_null_to_zero_value`, but DateTime fields have no zero value, so they stay null.
+fn coerce_null_to_zero_value_for(field: &ScalarFieldRef, value: PrismaValue) -> PrismaValue {
+    match field.type_identifier {
+        TypeIdentifier::DateTime => value,
+        _ => coerce_null_to_zero_value(value),
+    }
+}
+
 impl From<SqlRow> for Record {
     fn from(row: SqlRow) -> Record {
         Record::new(row.values)
@@ -47,7 +47,12 @@ pub fn serialize_internal(
             let mut map: Map = IndexMap::with_capacity(1);
             let mut result = CheckedItemsWithParents::new();
 
-            map.insert("count".into(), Item::Value(PrismaValue::Int(c as i64)));
+            let count = match batch_count_type(field) {
+                Some(ScalarType::BigInt) => PrismaValue::BigInt(c as i64),
+                _ => PrismaValue::Int(c as i64),
+            };
+
+            map.insert("count".into(), Item::Value(count));
             result.insert(None, Item::Map(map));
 
             Ok(result)
@@ -59,6 +64,16 @@ pub fn serialize_internal(
     }
 }
 
+/// The scalar type of the `count` field of a batch payload, which depends on the connector capabilities.
+fn batch_count_type(field: &OutputFieldRef) -> Option<ScalarType> {
+    let count_field = field.field_type.as_object_type()?.find_field("count")?;
+
+    match count_field.field_type.as_ref() {
+        OutputType::Scalar(scalar_type) => Some(scalar_type.clone()),
+        _ => None,
+    }
+}
+
 fn serialize_aggregation(
     output_field: &OutputFieldRef,
     record_aggregation: RecordAggregation,
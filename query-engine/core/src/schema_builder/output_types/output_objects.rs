@@ -1,4 +1,5 @@
 use super::*;
+use datamodel_connector::ConnectorCapability;
 use prisma_models::ScalarFieldRef;
 
 /// Initializes model output object type cache on the context.
@@ -117,9 +118,18 @@ pub(crate) fn batch_payload_object_type(ctx: &mut BuilderContext) -> ObjectTypeW
     let ident = Identifier::new("BatchPayload".to_owned(), PRISMA_NAMESPACE);
     return_cached_output!(ctx, &ident);
 
+    // Exposing the count as BigInt changes the wire format (BigInts are serialized as strings), so
+    // it has to be opted into with the `bigIntBatchCounts` preview feature.
+    let count_type =
+        if ctx.enable_big_int_batch_counts && ctx.capabilities.contains(ConnectorCapability::BigIntAffectedRowCounts) {
+            OutputType::bigint()
+        } else {
+            OutputType::int()
+        };
+
     let object_type = Arc::new(object_type(
         ident.clone(),
-        vec![field("count", vec![], OutputType::int(), None)],
+        vec![field("count", vec![], count_type, None)],
         None,
     ));
 
@@ -135,24 +145,27 @@ pub(crate) fn aggregation_object_type(ctx: &mut BuilderContext, model: &ModelRef
     let object = ObjectTypeStrongRef::new(ObjectType::new(ident.clone(), Some(ModelRef::clone(model))));
     let mut fields = vec![count_field()];
 
+    let numeric_fields = collect_numeric_fields(model);
+    let comparable_fields = collect_comparable_fields(model);
+
     append_opt(
         &mut fields,
-        numeric_aggregation_field(ctx, "avg", &model, field_avg_output_type),
+        field_aggregation_field(ctx, "avg", &model, &numeric_fields, field_avg_output_type),
     );
 
     append_opt(
         &mut fields,
-        numeric_aggregation_field(ctx, "sum", &model, map_scalar_output_type),
+        field_aggregation_field(ctx, "sum", &model, &numeric_fields, map_scalar_output_type),
     );
 
     append_opt(
         &mut fields,
-        numeric_aggregation_field(ctx, "min", &model, map_scalar_output_type),
+        field_aggregation_field(ctx, "min", &model, &comparable_fields, map_scalar_output_type),
     );
 
     append_opt(
         &mut fields,
-        numeric_aggregation_field(ctx, "max", &model, map_scalar_output_type),
+        field_aggregation_field(ctx, "max", &model, &comparable_fields, map_scalar_output_type),
     );
 
     object.set_fields(fields);
@@ -165,37 +178,30 @@ pub(crate) fn count_field() -> OutputField {
     field("count", vec![], OutputType::int(), None)
 }
 
-/// Returns an aggregation field with given name if the model contains any numeric fields.
+/// Returns an aggregation field with given name over the given fields, if there are any.
 /// Fields inside the object type of the field may have a fixed output type.
-pub(crate) fn numeric_aggregation_field<F>(
+pub(crate) fn field_aggregation_field<F>(
     ctx: &mut BuilderContext,
     name: &str,
     model: &ModelRef,
+    fields: &[ScalarFieldRef],
     type_mapper: F,
 ) -> Option<OutputField>
 where
     F: Fn(&ScalarFieldRef) -> OutputType,
 {
-    let numeric_fields = collect_numeric_fields(model);
-
-    if numeric_fields.is_empty() {
+    if fields.is_empty() {
         None
     } else {
-        let object_type = OutputType::object(map_numeric_field_aggregation_object(
-            ctx,
-            model,
-            name,
-            &numeric_fields,
-            type_mapper,
-        ));
+        let object_type = OutputType::object(map_field_aggregation_object(ctx, model, name, fields, type_mapper));
 
         Some(field(name, vec![], object_type, None).optional())
     }
 }
 
-/// Maps the object type for aggregations that operate on a (numeric) field level, rather than the entire model.
+/// Maps the object type for aggregations that operate on a field level, rather than the entire model.
 /// Fields inside the object may have a fixed output type.
-pub(crate) fn map_numeric_field_aggregation_object<F>(
+pub(crate) fn map_field_aggregation_object<F>(
     ctx: &mut BuilderContext,
     model: &ModelRef,
     suffix: &str,
@@ -213,7 +219,13 @@ where
 
     let fields: Vec<OutputField> = fields
         .iter()
-        .map(|sf| field(sf.name.clone(), vec![], type_mapper(sf), None).optional_if(!sf.is_required))
+        .map(|sf| {
+            // There is no zero value to fall back to for the aggregation of non-numeric fields
+            // over an empty set of records.
+            let is_optional = !sf.is_required || !is_numeric(sf);
+
+            field(sf.name.clone(), vec![], type_mapper(sf), None).optional_if(is_optional)
+        })
         .collect();
 
     let object = Arc::new(object_type(ident.clone(), fields, None));
@@ -230,16 +242,24 @@ fn field_avg_output_type(field: &ScalarFieldRef) -> OutputType {
     }
 }
 
+fn is_numeric(field: &ScalarFieldRef) -> bool {
+    matches!(
+        field.type_identifier,
+        TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Decimal
+    )
+}
+
+/// Fields that can be averaged and summed.
 fn collect_numeric_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
+    model.fields().scalar().into_iter().filter(is_numeric).collect()
+}
+
+/// Fields that have a minimum and a maximum: the numeric fields and DateTime fields.
+fn collect_comparable_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
     model
         .fields()
         .scalar()
         .into_iter()
-        .filter(|f| {
-            matches!(
-                f.type_identifier,
-                TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Decimal
-            )
-        })
+        .filter(|f| is_numeric(f) || f.type_identifier == TypeIdentifier::DateTime)
         .collect()
 }
@@ -94,12 +94,18 @@ impl CliCommand {
             None => ConnectorCapabilities::empty(),
         };
 
+        let enable_big_int_batch_counts = request
+            .config
+            .preview_features()
+            .any(|feature| feature == "bigIntBatchCounts");
+
         // temporary code duplication
         let internal_data_model = template.build("".into());
         let query_schema: QuerySchemaRef = Arc::new(schema_builder::build(
             internal_data_model,
             request.build_mode,
             request.enable_raw_queries,
+            enable_big_int_batch_counts,
             capabilities,
         ));
 
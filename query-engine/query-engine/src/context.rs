@@ -57,10 +57,12 @@ impl PrismaContext {
 
         // Construct query schema
         let build_mode = if legacy { BuildMode::Legacy } else { BuildMode::Modern };
+        let enable_big_int_batch_counts = config.preview_features().any(|feature| feature == "bigIntBatchCounts");
         let query_schema: QuerySchemaRef = Arc::new(schema_builder::build(
             internal_data_model,
             build_mode,
             enable_raw_queries,
+            enable_big_int_batch_counts,
             data_source.capabilities(),
         ));
 
@@ -73,6 +73,163 @@ fn must_fail_if_the_schema_is_invalid() {
     assert!(test_dmmf_cli_command(schema).is_err());
 }
 
+#[test]
+#[serial]
+fn datetime_fields_are_only_aggregated_by_min_and_max() {
+    let dm = r#"
+        model Blog {
+            blogId    String   @id
+            viewCount Int
+            createdAt DateTime
+        }
+    "#;
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+    let output_types = &dmmf.schema.output_object_types["prisma"];
+
+    let aggregated_fields = |type_name: &str| -> Vec<String> {
+        output_types
+            .iter()
+            .find(|output_type| output_type.name == type_name)
+            .unwrap_or_else(|| panic!("Could not find the {} output type", type_name))
+            .fields
+            .iter()
+            .map(|field| field.name.clone())
+            .collect()
+    };
+
+    assert_eq!(aggregated_fields("BlogAvgAggregateOutputType"), &["viewCount"]);
+    assert_eq!(aggregated_fields("BlogSumAggregateOutputType"), &["viewCount"]);
+    assert_eq!(
+        aggregated_fields("BlogMinAggregateOutputType"),
+        &["viewCount", "createdAt"]
+    );
+    assert_eq!(
+        aggregated_fields("BlogMaxAggregateOutputType"),
+        &["viewCount", "createdAt"]
+    );
+}
+
+#[test]
+#[serial]
+fn batch_payload_counts_are_big_ints_only_with_the_preview_feature() {
+    let batch_count_type = |provider: &str, preview_features: &str| -> String {
+        let dm = format!(
+            r#"
+            generator client {{
+                provider = "prisma-client-js"
+                previewFeatures = [{}]
+            }}
+
+            datasource db {{
+                provider = "{}"
+                url = "{}://localhost/test"
+            }}
+
+            model Blog {{
+                blogId String @id
+            }}
+            "#,
+            preview_features,
+            provider,
+            if provider == "sqlite" { "file" } else { provider }
+        );
+        let (query_schema, datamodel) = get_query_schema(&dm);
+        let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+        dmmf.schema.output_object_types["prisma"]
+            .iter()
+            .find(|output_type| output_type.name == "BatchPayload")
+            .expect("Could not find the BatchPayload output type")
+            .fields
+            .iter()
+            .find(|field| field.name == "count")
+            .expect("Could not find the count field")
+            .output_type
+            .typ
+            .clone()
+    };
+
+    assert_eq!(batch_count_type("postgresql", ""), "Int");
+    assert_eq!(batch_count_type("mysql", ""), "Int");
+
+    assert_eq!(batch_count_type("postgresql", r#""bigIntBatchCounts""#), "BigInt");
+    assert_eq!(batch_count_type("mysql", r#""bigIntBatchCounts""#), "BigInt");
+    assert_eq!(batch_count_type("sqlite", r#""bigIntBatchCounts""#), "Int");
+}
+
+#[test]
+#[serial]
+fn compound_uniques_can_be_used_to_connect_and_disconnect() {
+    let dm = r#"
+        model User {
+            id        Int    @id
+            firstName String
+            lastName  String
+            posts     Post[]
+
+            @@unique([firstName, lastName])
+        }
+
+        model Post {
+            id       Int    @id
+            title    String
+            authorId Int
+            author   User   @relation(fields: [authorId], references: [id])
+
+            @@unique([authorId, title])
+        }
+    "#;
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+    let input_types = &dmmf.schema.input_object_types["prisma"];
+
+    let input_type = |name: &str| {
+        input_types
+            .iter()
+            .find(|input_type| input_type.name == name)
+            .unwrap_or_else(|| panic!("Could not find the {} input type", name))
+    };
+    let field_names =
+        |name: &str| -> Vec<String> { input_type(name).fields.iter().map(|field| field.name.clone()).collect() };
+    let field_type_names = |type_name: &str, field_name: &str| -> Vec<String> {
+        input_type(type_name)
+            .fields
+            .iter()
+            .find(|field| field.name == field_name)
+            .unwrap_or_else(|| panic!("Could not find the {} field on {}", field_name, type_name))
+            .input_types
+            .iter()
+            .map(|typ| typ.typ.clone())
+            .collect()
+    };
+    let references_where_unique = |field_name: &str, where_unique: &str| {
+        input_types.iter().any(|input_type| {
+            input_type
+                .fields
+                .iter()
+                .any(|field| field.name == field_name && field.input_types.iter().any(|typ| typ.typ == where_unique))
+        })
+    };
+
+    assert_eq!(field_names("UserWhereUniqueInput"), &["id", "firstName_lastName"]);
+    assert_eq!(
+        field_type_names("UserWhereUniqueInput", "firstName_lastName"),
+        &["FirstNameLastNameCompoundUniqueInput"]
+    );
+    assert_eq!(
+        field_names("FirstNameLastNameCompoundUniqueInput"),
+        &["firstName", "lastName"]
+    );
+
+    assert_eq!(field_names("PostWhereUniqueInput"), &["id", "authorId_title"]);
+    assert_eq!(field_names("AuthorIdTitleCompoundUniqueInput"), &["authorId", "title"]);
+
+    assert!(references_where_unique("connect", "UserWhereUniqueInput"));
+    assert!(references_where_unique("connect", "PostWhereUniqueInput"));
+    assert!(references_where_unique("disconnect", "PostWhereUniqueInput"));
+}
+
 fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
     feature_flags::initialize(&[String::from("all")]).unwrap();
 
@@ -111,11 +268,21 @@ fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Dat
         Some(ds) => ds.capabilities(),
         None => ConnectorCapabilities::empty(),
     };
+    let enable_big_int_batch_counts = config
+        .subject
+        .preview_features()
+        .any(|feature| feature == "bigIntBatchCounts");
     let internal_dm_template = DatamodelConverter::convert(&dm);
     let internal_ref = internal_dm_template.build("db".to_owned());
 
     (
-        schema_builder::build(internal_ref, BuildMode::Modern, false, capabilities),
+        schema_builder::build(
+            internal_ref,
+            BuildMode::Modern,
+            false,
+            enable_big_int_batch_counts,
+            capabilities,
+        ),
         dm,
     )
 }
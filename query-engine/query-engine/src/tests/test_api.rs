@@ -54,7 +54,9 @@ impl TestApi {
             .schema_push(&SchemaPushInput {
                 schema: datamodel_string,
                 force: true,
+                accept_data_loss: false,
                 assume_empty: true,
+                checker_mode: Default::default(),
             })
             .await?;
 